@@ -0,0 +1,55 @@
+//! Compare unblocked LU factorization against blocked right-looking LU factorization,
+//! whose trailing updates are matrix products, on large f64 matrices
+//!
+//! Run it with *cargo bench --bench lu*
+
+#![allow(clippy::needless_return)]
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use blarus::{Lu, Matrix};
+
+const NB_REPETITIONS: usize = 3;
+const BLOCK_SIZE: usize = 64;
+
+/// Get the shortest duration of given number of runs of function
+fn best_of<F>(nb_repetitions: usize, mut f: F) -> Duration
+where
+    F: FnMut(),
+{
+    let mut best: Duration = Duration::MAX;
+
+    for _ in 0..nb_repetitions {
+        let start: Instant = Instant::now();
+        f();
+        best = best.min(start.elapsed());
+    }
+
+    return best;
+}
+
+fn main() {
+    for size in [500, 1000, 2000] {
+        // Diagonally dominant matrix, so it is far from singular
+        let matrix: Matrix<f64> = Matrix::from_fn_row_major(size, size, |i, j| {
+            let value: f64 = ((7 * i + 13 * j) % 17) as f64 / 17.0;
+            return if i == j { value + size as f64 } else { value };
+        });
+
+        let unblocked: Duration = best_of(NB_REPETITIONS, || {
+            black_box(Lu::new(&matrix.full_view()).unwrap());
+        });
+
+        let blocked: Duration = best_of(NB_REPETITIONS, || {
+            black_box(Lu::new_blocked(&matrix.full_view(), BLOCK_SIZE).unwrap());
+        });
+
+        println!("{}x{} f64 matrix", size, size);
+        println!("    unblocked LU: {:?}", unblocked);
+        println!(
+            "    blocked LU with block size {}: {:?}",
+            BLOCK_SIZE, blocked
+        );
+    }
+}
@@ -0,0 +1,79 @@
+//! Compare allocation of large f64 matrices filled with default value against
+//! allocation of uninitialized matrices, then the cost of writing every element
+//!
+//! Run it with *cargo bench --bench uninit*
+
+#![allow(clippy::needless_return)]
+
+use std::hint::black_box;
+use std::mem::MaybeUninit;
+use std::time::{Duration, Instant};
+
+use blarus::Matrix;
+
+const NB_REPETITIONS: usize = 20;
+
+/// Get the shortest duration of given number of runs of function
+fn best_of<F>(nb_repetitions: usize, mut f: F) -> Duration
+where
+    F: FnMut(),
+{
+    let mut best: Duration = Duration::MAX;
+
+    for _ in 0..nb_repetitions {
+        let start: Instant = Instant::now();
+        f();
+        best = best.min(start.elapsed());
+    }
+
+    return best;
+}
+
+fn main() {
+    for size in [512, 1024, 2048, 4096] {
+        let allocation_default: Duration = best_of(NB_REPETITIONS, || {
+            black_box(Matrix::<f64>::new_row_major(size, size));
+        });
+
+        let allocation_uninit: Duration = best_of(NB_REPETITIONS, || {
+            black_box(Matrix::<MaybeUninit<f64>>::new_uninit_row_major(size, size));
+        });
+
+        let fill_default: Duration = best_of(NB_REPETITIONS, || {
+            let mut matrix: Matrix<f64> = Matrix::new_row_major(size, size);
+
+            for row_id in 0..size {
+                for col_id in 0..size {
+                    matrix[(row_id, col_id)] = (row_id + col_id) as f64;
+                }
+            }
+
+            black_box(matrix);
+        });
+
+        let fill_uninit: Duration = best_of(NB_REPETITIONS, || {
+            let mut matrix: Matrix<MaybeUninit<f64>> = Matrix::new_uninit_row_major(size, size);
+
+            for row_id in 0..size {
+                for col_id in 0..size {
+                    matrix[(row_id, col_id)].write((row_id + col_id) as f64);
+                }
+            }
+
+            // SAFETY: every element has been written above
+            black_box(unsafe { matrix.assume_init() });
+        });
+
+        println!("{}x{} f64 matrix", size, size);
+        println!("    allocation   new_row_major: {:?}", allocation_default);
+        println!(
+            "    allocation   new_uninit_row_major: {:?}",
+            allocation_uninit
+        );
+        println!("    write all    new_row_major: {:?}", fill_default);
+        println!(
+            "    write all    new_uninit_row_major + assume_init: {:?}",
+            fill_uninit
+        );
+    }
+}
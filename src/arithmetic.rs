@@ -0,0 +1,773 @@
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use super::error::ShapeError;
+use super::matrix::Matrix;
+use super::num::Zero;
+use super::trace::traced;
+use super::view::{StorageOrder, View, ViewMut};
+
+/// Compute matrix product of a by b as a row-major matrix
+/// Operands are read through their accessors, so they can have any storage order
+/// or be transposed views. Number of columns of a must be number of rows of b
+pub fn matmul<T>(a: &View<T>, b: &View<T>) -> Result<Matrix<T>, ShapeError>
+where
+    T: Copy + Zero + Add<Output = T> + Mul<Output = T>,
+{
+    if a.nb_cols() != b.nb_rows() {
+        return Err(ShapeError::DimensionMismatch {
+            expected: (a.nb_cols(), b.nb_cols()),
+            actual: (b.nb_rows(), b.nb_cols()),
+        });
+    }
+
+    let dims: (usize, usize, usize) = (a.nb_rows(), a.nb_cols(), b.nb_cols());
+
+    let nb_cols: usize = b.nb_cols();
+
+    let data: Vec<T> = traced("matmul", dims, "naive", || {
+        let mut data: Vec<T> = vec![T::zero(); a.nb_rows() * nb_cols];
+
+        // Each row of result accumulates rows of b, so b is walked along its rows
+        // and every element still sums its products in increasing order of k
+        for (row_id, row) in data.chunks_mut(nb_cols.max(1)).enumerate() {
+            for k in 0..a.nb_cols() {
+                let factor: T = a[(row_id, k)];
+
+                match b.row_slice(k) {
+                    Some(b_row) => {
+                        for (element, &b_element) in row.iter_mut().zip(b_row.iter()) {
+                            *element = *element + factor * b_element;
+                        }
+                    }
+                    None => {
+                        for (col_id, element) in row.iter_mut().enumerate() {
+                            *element = *element + factor * b[(k, col_id)];
+                        }
+                    }
+                }
+            }
+        }
+
+        return data;
+    });
+
+    return Matrix::from_vec_row_major(a.nb_rows(), nb_cols, data);
+}
+
+/// Panel update
+/// This enumeration defines how each row panel of C receives product of its panel of A by B
+/// - Overwrite replaces elements of C panel by the product
+/// - Accumulate adds the product to elements of C panel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PanelUpdate {
+    Overwrite,
+    Accumulate,
+}
+
+/// Compute C = A * B by row panels, where each panel of A is multiplied by b into the
+/// corresponding panel of C, so panels can be loaded, computed and stored independently.
+/// Both iterators must yield the same number of panels, each panel of C must have
+/// the number of rows of its panel of A and the number of columns of b.
+/// Panels before the first inconsistent one are already computed when an error is returned
+pub fn gemm_row_chunked<'a, 'b, T, A, C>(
+    a_chunks: A,
+    b: &View<T>,
+    c_chunks: C,
+    update: PanelUpdate,
+) -> Result<(), ShapeError>
+where
+    T: Copy + Zero + Add<Output = T> + Mul<Output = T> + 'a + 'b,
+    A: IntoIterator<Item = View<'a, T>>,
+    C: IntoIterator<Item = ViewMut<'b, T>>,
+{
+    let mut a_chunks = a_chunks.into_iter();
+    let mut c_chunks = c_chunks.into_iter();
+    let mut nb_panels: usize = 0;
+
+    loop {
+        let (a, mut c): (View<T>, ViewMut<T>) = match (a_chunks.next(), c_chunks.next()) {
+            (None, None) => return Ok(()),
+            (Some(a), Some(c)) => (a, c),
+            (Some(_), None) => {
+                return Err(ShapeError::LengthMismatch {
+                    expected: nb_panels + 1,
+                    actual: nb_panels,
+                })
+            }
+            (None, Some(_)) => {
+                return Err(ShapeError::LengthMismatch {
+                    expected: nb_panels,
+                    actual: nb_panels + 1,
+                })
+            }
+        };
+
+        if (c.nb_rows(), c.nb_cols()) != (a.nb_rows(), b.nb_cols()) {
+            return Err(ShapeError::DimensionMismatch {
+                expected: (a.nb_rows(), b.nb_cols()),
+                actual: (c.nb_rows(), c.nb_cols()),
+            });
+        }
+
+        let product: Matrix<T> = matmul(&a, b)?;
+
+        match update {
+            PanelUpdate::Overwrite => {
+                for row_id in 0..c.nb_rows() {
+                    for col_id in 0..c.nb_cols() {
+                        c[(row_id, col_id)] = product[(row_id, col_id)];
+                    }
+                }
+            }
+            PanelUpdate::Accumulate => add_assign(&mut c, &product.full_view())?,
+        }
+
+        nb_panels += 1;
+    }
+}
+
+/// Compute element-wise sum of a and b
+/// Operands are read through their accessors, so they are added by logical position.
+/// Result has storage order of operands when both are contiguous in same order, row-major otherwise
+pub fn add<T>(a: &View<T>, b: &View<T>) -> Result<Matrix<T>, ShapeError>
+where
+    T: Copy + Add<Output = T>,
+{
+    return elementwise(a, b, |x, y| x + y);
+}
+
+/// Compute element-wise difference of a and b
+/// Operands are read through their accessors, so they are subtracted by logical position.
+/// Result has storage order of operands when both are contiguous in same order, row-major otherwise
+pub fn sub<T>(a: &View<T>, b: &View<T>) -> Result<Matrix<T>, ShapeError>
+where
+    T: Copy + Sub<Output = T>,
+{
+    return elementwise(a, b, |x, y| x - y);
+}
+
+/// Add b to a in place, element by element, without allocation
+pub fn add_assign<T>(a: &mut ViewMut<T>, b: &View<T>) -> Result<(), ShapeError>
+where
+    T: Copy + Add<Output = T>,
+{
+    check_same_dimensions((a.nb_rows(), a.nb_cols()), b)?;
+
+    for row_id in 0..a.nb_rows() {
+        for col_id in 0..a.nb_cols() {
+            a[(row_id, col_id)] = a[(row_id, col_id)] + b[(row_id, col_id)];
+        }
+    }
+
+    return Ok(());
+}
+
+/// Subtract b from a in place, element by element, without allocation
+pub fn sub_assign<T>(a: &mut ViewMut<T>, b: &View<T>) -> Result<(), ShapeError>
+where
+    T: Copy + Sub<Output = T>,
+{
+    check_same_dimensions((a.nb_rows(), a.nb_cols()), b)?;
+
+    for row_id in 0..a.nb_rows() {
+        for col_id in 0..a.nb_cols() {
+            a[(row_id, col_id)] = a[(row_id, col_id)] - b[(row_id, col_id)];
+        }
+    }
+
+    return Ok(());
+}
+
+/// Create a matrix where each element is f applied on elements of a and b at same position
+/// When both operands are contiguous in same storage order, f runs directly over their slices
+/// and result has this storage order, otherwise elements are read by index into a row-major matrix
+fn elementwise<T, F>(a: &View<T>, b: &View<T>, f: F) -> Result<Matrix<T>, ShapeError>
+where
+    T: Copy,
+    F: Fn(T, T) -> T,
+{
+    check_same_dimensions((a.nb_rows(), a.nb_cols()), b)?;
+
+    if let (Some((a_data, a_order)), Some((b_data, b_order))) =
+        (a.contiguous_slice(), b.contiguous_slice())
+    {
+        if a_order == b_order {
+            let data: Vec<T> = a_data
+                .iter()
+                .zip(b_data.iter())
+                .map(|(&x, &y)| f(x, y))
+                .collect();
+
+            return match a_order {
+                StorageOrder::RowMajor => {
+                    Matrix::from_vec_row_major(a.nb_rows(), a.nb_cols(), data)
+                }
+                StorageOrder::ColumnMajor => {
+                    Matrix::from_vec_column_major(a.nb_rows(), a.nb_cols(), data)
+                }
+            };
+        }
+    }
+
+    return Ok(Matrix::from_fn_row_major(
+        a.nb_rows(),
+        a.nb_cols(),
+        |row_id, col_id| f(a[(row_id, col_id)], b[(row_id, col_id)]),
+    ));
+}
+
+/// Apply f on each element of a in place
+/// When a is contiguous, f runs directly over its slice so the loop can be vectorized
+fn update_elements<T, F>(a: &mut ViewMut<T>, f: F)
+where
+    T: Copy,
+    F: Fn(T) -> T,
+{
+    match a.contiguous_slice_mut() {
+        Some(data) => data.iter_mut().for_each(|x| *x = f(*x)),
+        None => a.apply(|x| *x = f(*x)),
+    }
+}
+
+/// Check that operand b has given dimensions of the other operand
+fn check_same_dimensions<T>(dimensions: (usize, usize), b: &View<T>) -> Result<(), ShapeError> {
+    if dimensions != (b.nb_rows(), b.nb_cols()) {
+        return Err(ShapeError::DimensionMismatch {
+            expected: dimensions,
+            actual: (b.nb_rows(), b.nb_cols()),
+        });
+    }
+
+    return Ok(());
+}
+
+impl<'a, 'b, T> Add<&View<'b, T>> for &View<'a, T>
+where
+    T: Copy + Add<Output = T>,
+{
+    type Output = Matrix<T>;
+
+    /// Compute element-wise sum of views, it panics if they do not have same dimensions
+    fn add(self, other: &View<'b, T>) -> Matrix<T> {
+        return add(self, other).unwrap_or_else(|error| panic!("cannot add views: {}", error));
+    }
+}
+
+impl<'a, 'b, T> Sub<&View<'b, T>> for &View<'a, T>
+where
+    T: Copy + Sub<Output = T>,
+{
+    type Output = Matrix<T>;
+
+    /// Compute element-wise difference of views, it panics if they do not have same dimensions
+    fn sub(self, other: &View<'b, T>) -> Matrix<T> {
+        return sub(self, other).unwrap_or_else(|error| panic!("cannot subtract views: {}", error));
+    }
+}
+
+impl<T> Add<&Matrix<T>> for &Matrix<T>
+where
+    T: Copy + Add<Output = T>,
+{
+    type Output = Matrix<T>;
+
+    /// Compute element-wise sum of matrices, it panics if they do not have same dimensions
+    fn add(self, other: &Matrix<T>) -> Matrix<T> {
+        return &self.full_view() + &other.full_view();
+    }
+}
+
+impl<T> Sub<&Matrix<T>> for &Matrix<T>
+where
+    T: Copy + Sub<Output = T>,
+{
+    type Output = Matrix<T>;
+
+    /// Compute element-wise difference of matrices, it panics if they do not have same dimensions
+    fn sub(self, other: &Matrix<T>) -> Matrix<T> {
+        return &self.full_view() - &other.full_view();
+    }
+}
+
+impl<'a, 'b, T> AddAssign<&View<'b, T>> for ViewMut<'a, T>
+where
+    T: Copy + Add<Output = T>,
+{
+    /// Add view in place, it panics if view does not have same dimensions
+    fn add_assign(&mut self, other: &View<'b, T>) {
+        add_assign(self, other).unwrap_or_else(|error| panic!("cannot add view: {}", error));
+    }
+}
+
+impl<'a, 'b, T> SubAssign<&View<'b, T>> for ViewMut<'a, T>
+where
+    T: Copy + Sub<Output = T>,
+{
+    /// Subtract view in place, it panics if view does not have same dimensions
+    fn sub_assign(&mut self, other: &View<'b, T>) {
+        sub_assign(self, other).unwrap_or_else(|error| panic!("cannot subtract view: {}", error));
+    }
+}
+
+impl<'a, T> Mul<T> for &View<'a, T>
+where
+    T: Copy + Mul<Output = T>,
+{
+    type Output = Matrix<T>;
+
+    /// Multiply each element of view by given factor into a new matrix
+    fn mul(self, factor: T) -> Matrix<T> {
+        return self.map(|&x| x * factor);
+    }
+}
+
+impl<'a, T> Div<T> for &View<'a, T>
+where
+    T: Copy + Div<Output = T>,
+{
+    type Output = Matrix<T>;
+
+    /// Divide each element of view by given divisor into a new matrix
+    fn div(self, divisor: T) -> Matrix<T> {
+        return self.map(|&x| x / divisor);
+    }
+}
+
+impl<T> Mul<T> for &Matrix<T>
+where
+    T: Copy + Mul<Output = T>,
+{
+    type Output = Matrix<T>;
+
+    /// Multiply each element of matrix by given factor into a new matrix
+    fn mul(self, factor: T) -> Matrix<T> {
+        return &self.full_view() * factor;
+    }
+}
+
+impl<T> Div<T> for &Matrix<T>
+where
+    T: Copy + Div<Output = T>,
+{
+    type Output = Matrix<T>;
+
+    /// Divide each element of matrix by given divisor into a new matrix
+    fn div(self, divisor: T) -> Matrix<T> {
+        return &self.full_view() / divisor;
+    }
+}
+
+impl<'a, T> Neg for &View<'a, T>
+where
+    T: Copy + Neg<Output = T>,
+{
+    type Output = Matrix<T>;
+
+    /// Negate each element of view into a new matrix
+    fn neg(self) -> Matrix<T> {
+        return self.map(|&x| -x);
+    }
+}
+
+impl<T> Neg for &Matrix<T>
+where
+    T: Copy + Neg<Output = T>,
+{
+    type Output = Matrix<T>;
+
+    /// Negate each element of matrix into a new matrix
+    fn neg(self) -> Matrix<T> {
+        return -&self.full_view();
+    }
+}
+
+impl<'a, T> MulAssign<T> for ViewMut<'a, T>
+where
+    T: Copy + Mul<Output = T>,
+{
+    /// Multiply each element of view by given factor in place
+    fn mul_assign(&mut self, factor: T) {
+        update_elements(self, |x| x * factor);
+    }
+}
+
+impl<'a, T> DivAssign<T> for ViewMut<'a, T>
+where
+    T: Copy + Div<Output = T>,
+{
+    /// Divide each element of view by given divisor in place
+    fn div_assign(&mut self, divisor: T) {
+        update_elements(self, |x| x / divisor);
+    }
+}
+
+impl<T> MulAssign<T> for Matrix<T>
+where
+    T: Copy + Mul<Output = T>,
+{
+    /// Multiply each element of matrix by given factor in place
+    fn mul_assign(&mut self, factor: T) {
+        let mut view: ViewMut<T> = self.full_view_mut();
+        view *= factor;
+    }
+}
+
+impl<T> DivAssign<T> for Matrix<T>
+where
+    T: Copy + Div<Output = T>,
+{
+    /// Divide each element of matrix by given divisor in place
+    fn div_assign(&mut self, divisor: T) {
+        let mut view: ViewMut<T> = self.full_view_mut();
+        view /= divisor;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::ViewParameters;
+
+    #[test]
+    fn test_matmul() {
+        let a: Matrix<i32> = Matrix::from_rows(&[&[1, 2, 3], &[4, 5, 6]]).unwrap();
+        let b: Matrix<i32> = Matrix::from_cols(&[&[7, 9, 11], &[8, 10, 12]]).unwrap();
+
+        let product: Matrix<i32> = matmul(&a.full_view(), &b.full_view()).unwrap();
+        let expected: Matrix<i32> = Matrix::from_rows(&[&[58, 64], &[139, 154]]).unwrap();
+
+        assert_eq!(product, expected);
+
+        // Same product with a transposed view as left operand
+        let at: Matrix<i32> = Matrix::from_rows(&[&[1, 4], &[2, 5], &[3, 6]]).unwrap();
+        let product: Matrix<i32> = matmul(&at.transpose_view(), &b.full_view()).unwrap();
+        assert_eq!(product, expected);
+    }
+
+    #[test]
+    fn test_matmul_dimension_mismatch() {
+        let a: Matrix<f64> = Matrix::ones(2, 3);
+        let b: Matrix<f64> = Matrix::ones(2, 3);
+
+        assert_eq!(
+            matmul(&a.full_view(), &b.full_view()).err(),
+            Some(ShapeError::DimensionMismatch {
+                expected: (3, 3),
+                actual: (2, 3)
+            })
+        );
+
+        let product: Matrix<f64> = matmul(&a.full_view(), &b.transpose_view()).unwrap();
+        assert_eq!(product, Matrix::filled(2, 2, 3.0));
+    }
+
+    #[test]
+    fn test_add_and_sub() {
+        let a: Matrix<i32> = Matrix::from_rows(&[&[1, 2, 3], &[4, 5, 6]]).unwrap();
+        let b: Matrix<i32> =
+            Matrix::from_vec_column_major(2, 3, vec![10, 40, 20, 50, 30, 60]).unwrap();
+
+        let sum: Matrix<i32> = add(&a.full_view(), &b.full_view()).unwrap();
+        assert_eq!(
+            sum,
+            Matrix::from_rows(&[&[11, 22, 33], &[44, 55, 66]]).unwrap()
+        );
+
+        let difference: Matrix<i32> = sub(&b.full_view(), &a.full_view()).unwrap();
+        assert_eq!(
+            difference,
+            Matrix::from_rows(&[&[9, 18, 27], &[36, 45, 54]]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_add_and_sub_dimension_mismatch() {
+        let a: Matrix<f64> = Matrix::ones(2, 3);
+        let b: Matrix<f64> = Matrix::ones(3, 2);
+
+        let error: ShapeError = ShapeError::DimensionMismatch {
+            expected: (2, 3),
+            actual: (3, 2),
+        };
+
+        assert_eq!(
+            add(&a.full_view(), &b.full_view()).err(),
+            Some(error.clone())
+        );
+        assert_eq!(
+            sub(&a.full_view(), &b.full_view()).err(),
+            Some(error.clone())
+        );
+
+        let mut c: Matrix<f64> = Matrix::zeros(2, 3);
+        assert_eq!(
+            add_assign(&mut c.full_view_mut(), &b.full_view()),
+            Err(error)
+        );
+    }
+
+    #[test]
+    fn test_add_assign() {
+        let mut a: Matrix<i32> = Matrix::from_rows(&[&[1, 2], &[3, 4], &[5, 6]]).unwrap();
+        let b: Matrix<i32> = Matrix::from_cols(&[&[1, 1], &[2, 2]]).unwrap();
+
+        {
+            let mut view: ViewMut<i32> = a.view_mut(ViewParameters::new(1, 0, 2, 2));
+            add_assign(&mut view, &b.full_view()).unwrap();
+        }
+
+        assert_eq!(a, Matrix::from_rows(&[&[1, 2], &[4, 6], &[6, 8]]).unwrap());
+    }
+
+    #[test]
+    fn test_add_and_sub_operators() {
+        let a: Matrix<i32> = Matrix::from_rows(&[&[1, 2, 3], &[4, 5, 6]]).unwrap();
+        let b: Matrix<i32> =
+            Matrix::from_vec_column_major(2, 3, vec![10, 40, 20, 50, 30, 60]).unwrap();
+        let expected_sum: Matrix<i32> = Matrix::from_rows(&[&[11, 22, 33], &[44, 55, 66]]).unwrap();
+
+        // Mixed storage orders are added by index into a row-major matrix
+        let sum: Matrix<i32> = &a + &b;
+        assert_eq!(sum, expected_sum);
+        assert_eq!(sum.storage_order(), StorageOrder::RowMajor);
+
+        // Operands with same storage order keep it
+        let column_major: Matrix<i32> = b.to_column_major();
+        let difference: Matrix<i32> = &b - &column_major;
+        assert_eq!(difference, Matrix::zeros(2, 3));
+        assert_eq!(difference.storage_order(), StorageOrder::ColumnMajor);
+
+        let sum: Matrix<i32> = &b.full_view() + &a.full_view();
+        assert_eq!(sum, expected_sum);
+
+        // Transposed view of a row-major matrix is contiguous in column-major order
+        let at: Matrix<i32> = Matrix::from_rows(&[&[1, 4], &[2, 5], &[3, 6]]).unwrap();
+        let difference: Matrix<i32> = &at.transpose_view() - &column_major.full_view();
+        assert_eq!(
+            difference,
+            Matrix::from_rows(&[&[-9, -18, -27], &[-36, -45, -54]]).unwrap()
+        );
+        assert_eq!(difference.storage_order(), StorageOrder::ColumnMajor);
+
+        let strided: View<i32> = a.view(ViewParameters::new(0, 1, 2, 2));
+        let sum: Matrix<i32> = &strided + &b.view(ViewParameters::new(0, 0, 2, 2));
+        assert_eq!(sum, Matrix::from_rows(&[&[12, 23], &[45, 56]]).unwrap());
+
+        let empty: Matrix<i32> = Matrix::new_row_major(0, 3);
+        assert_eq!((&empty + &empty).nb_cols(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot add views")]
+    fn test_add_operator_dimension_mismatch() {
+        let a: Matrix<f64> = Matrix::ones(2, 3);
+        let b: Matrix<f64> = Matrix::ones(3, 2);
+
+        let _ = &a + &b;
+    }
+
+    #[test]
+    fn test_add_and_sub_assign_operators() {
+        let mut a: Matrix<i32> = Matrix::from_rows(&[&[1, 2], &[3, 4]]).unwrap();
+        let b: Matrix<i32> = Matrix::from_cols(&[&[1, 1], &[2, 2]]).unwrap();
+
+        {
+            let mut view: ViewMut<i32> = a.full_view_mut();
+            view += &b.full_view();
+            view += &b.full_view();
+            view -= &b.transpose_view();
+        }
+
+        assert_eq!(a, Matrix::from_rows(&[&[2, 5], &[3, 6]]).unwrap());
+
+        assert_eq!(
+            sub_assign(&mut a.full_view_mut(), &b.row(0)),
+            Err(ShapeError::DimensionMismatch {
+                expected: (2, 2),
+                actual: (1, 2)
+            })
+        );
+    }
+
+    #[test]
+    fn test_scalar_operators() {
+        let a: Matrix<f64> = Matrix::from_rows(&[&[1.0, -2.0, 3.0], &[4.0, 5.0, -6.0]]).unwrap();
+        let b: Matrix<f64> = a.to_column_major();
+
+        let doubled: Matrix<f64> = &a * 2.0;
+        assert_eq!(
+            doubled,
+            Matrix::from_rows(&[&[2.0, -4.0, 6.0], &[8.0, 10.0, -12.0]]).unwrap()
+        );
+        assert_eq!(&b * 2.0, doubled);
+        assert_eq!((&b * 2.0).storage_order(), StorageOrder::ColumnMajor);
+        assert_eq!(&doubled / 2.0, a);
+
+        // Strided view is read by index
+        let strided: View<f64> = a.view(ViewParameters::new(0, 1, 2, 2));
+        assert_eq!(
+            &strided * -1.0,
+            Matrix::from_rows(&[&[2.0, -3.0], &[-5.0, 6.0]]).unwrap()
+        );
+
+        let empty: Matrix<f64> = Matrix::new_row_major(0, 3);
+        let product: Matrix<f64> = &empty * 2.0;
+        assert_eq!(product.nb_rows(), 0);
+        assert_eq!(product.nb_cols(), 3);
+    }
+
+    #[test]
+    fn test_scalar_assign_operators() {
+        let mut a: Matrix<i32> = Matrix::from_rows(&[&[1, 2, 3], &[4, 5, 6]]).unwrap();
+
+        a *= 4;
+        assert_eq!(a, Matrix::from_rows(&[&[4, 8, 12], &[16, 20, 24]]).unwrap());
+
+        a /= 2;
+        assert_eq!(a, Matrix::from_rows(&[&[2, 4, 6], &[8, 10, 12]]).unwrap());
+
+        // Only elements of strided view are modified
+        {
+            let mut view: ViewMut<i32> = a.view_mut(ViewParameters::new(0, 1, 2, 2));
+            view *= 10;
+            view /= 5;
+        }
+
+        assert_eq!(a, Matrix::from_rows(&[&[2, 8, 12], &[8, 20, 24]]).unwrap());
+
+        let mut empty: Matrix<i32> = Matrix::new_column_major(0, 2);
+        empty *= 3;
+        assert_eq!(empty.nb_cols(), 2);
+    }
+
+    #[test]
+    fn test_neg_operator() {
+        let a: Matrix<i32> = Matrix::from_vec_column_major(2, 2, vec![1, -3, 2, 4]).unwrap();
+
+        let negated: Matrix<i32> = -&a;
+        assert_eq!(negated, Matrix::from_rows(&[&[-1, -2], &[3, -4]]).unwrap());
+        assert_eq!(negated.storage_order(), StorageOrder::ColumnMajor);
+
+        assert_eq!(
+            -&a.transpose_view(),
+            Matrix::from_rows(&[&[-1, 3], &[-2, -4]]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_gemm_row_chunked() {
+        let a: Matrix<i32> = Matrix::from_fn_row_major(5, 3, |i, j| (i * 3 + j) as i32 - 4);
+        let b: Matrix<i32> = Matrix::from_fn_column_major(3, 2, |i, j| (2 * i + j) as i32);
+        let expected: Matrix<i32> = matmul(&a.full_view(), &b.full_view()).unwrap();
+
+        let mut c: Matrix<i32> = Matrix::filled(5, 2, 100);
+
+        {
+            let (top, bottom): (ViewMut<i32>, ViewMut<i32>) = c.split_at_row_mut(3).unwrap();
+            let a_chunks: Vec<View<i32>> = vec![
+                a.view(ViewParameters::new(0, 0, 3, 3)),
+                a.view(ViewParameters::new(3, 0, 2, 3)),
+            ];
+
+            gemm_row_chunked(
+                a_chunks,
+                &b.full_view(),
+                vec![top, bottom],
+                PanelUpdate::Overwrite,
+            )
+            .unwrap();
+        }
+
+        assert_eq!(c, expected);
+
+        {
+            let (top, bottom): (ViewMut<i32>, ViewMut<i32>) = c.split_at_row_mut(1).unwrap();
+            let (a_top, a_bottom): (View<i32>, View<i32>) = a.split_at_row(1);
+
+            gemm_row_chunked(
+                [a_top, a_bottom],
+                &b.full_view(),
+                [top, bottom],
+                PanelUpdate::Accumulate,
+            )
+            .unwrap();
+        }
+
+        assert_eq!(
+            c,
+            Matrix::from_fn_row_major(5, 2, |i, j| 2 * expected[(i, j)])
+        );
+    }
+
+    #[test]
+    fn test_gemm_row_chunked_inconsistent_panels() {
+        let a: Matrix<f64> = Matrix::ones(4, 3);
+        let b: Matrix<f64> = Matrix::ones(3, 2);
+        let mut c: Matrix<f64> = Matrix::zeros(4, 2);
+
+        // Panel of C with 3 rows for a panel of A with 2 rows
+        {
+            let (top, bottom): (ViewMut<f64>, ViewMut<f64>) = c.split_at_row_mut(3).unwrap();
+            let (a_top, a_bottom): (View<f64>, View<f64>) = a.split_at_row(2);
+
+            assert_eq!(
+                gemm_row_chunked(
+                    [a_top, a_bottom],
+                    &b.full_view(),
+                    [top, bottom],
+                    PanelUpdate::Overwrite
+                ),
+                Err(ShapeError::DimensionMismatch {
+                    expected: (2, 2),
+                    actual: (3, 2)
+                })
+            );
+        }
+
+        // Columns of C do not fit b
+        {
+            let (a_top, _): (View<f64>, View<f64>) = a.split_at_row(4);
+            let c_panel: ViewMut<f64> = c.view_mut(ViewParameters::new(0, 0, 4, 1));
+
+            assert_eq!(
+                gemm_row_chunked([a_top], &b.full_view(), [c_panel], PanelUpdate::Overwrite),
+                Err(ShapeError::DimensionMismatch {
+                    expected: (4, 2),
+                    actual: (4, 1)
+                })
+            );
+        }
+
+        // Columns of A panel do not fit b
+        {
+            let a_panel: View<f64> = a.view(ViewParameters::new(0, 0, 4, 2));
+
+            assert_eq!(
+                gemm_row_chunked(
+                    [a_panel],
+                    &b.full_view(),
+                    [c.full_view_mut()],
+                    PanelUpdate::Overwrite
+                ),
+                Err(ShapeError::DimensionMismatch {
+                    expected: (2, 2),
+                    actual: (3, 2)
+                })
+            );
+        }
+
+        // More panels of A than panels of C
+        assert_eq!(
+            gemm_row_chunked(
+                [a.full_view(), a.full_view()],
+                &b.full_view(),
+                [c.full_view_mut()],
+                PanelUpdate::Overwrite
+            ),
+            Err(ShapeError::LengthMismatch {
+                expected: 2,
+                actual: 1
+            })
+        );
+    }
+}
@@ -0,0 +1,175 @@
+use super::error::ShapeError;
+use super::matrix::Matrix;
+
+/// Row stream builder
+/// This structure builds a row-major matrix from rows given one at a time, as produced by
+/// parsers or network readers. Elements are appended in a single vector whose capacity grows
+/// geometrically, so rows do not need to be collected in nested vectors first
+pub struct RowStreamBuilder<T> {
+    nb_rows: usize,
+    nb_cols: usize,
+    data: Vec<T>,
+}
+
+impl<T> RowStreamBuilder<T> {
+    /// Create a builder of matrix with given number of columns
+    pub fn new(nb_cols: usize) -> Self {
+        return Self {
+            nb_rows: 0,
+            nb_cols,
+            data: Vec::new(),
+        };
+    }
+
+    /// Create a builder of matrix with given number of columns
+    /// where memory for given number of rows is reserved
+    pub fn with_capacity(nb_cols: usize, nb_rows: usize) -> Self {
+        return Self {
+            nb_rows: 0,
+            nb_cols,
+            data: Vec::with_capacity(nb_rows * nb_cols),
+        };
+    }
+
+    /// Get number of rows pushed in builder
+    pub fn len_rows(&self) -> usize {
+        return self.nb_rows;
+    }
+
+    /// Append row given as a vector, it must have the number of columns of builder
+    pub fn push_row_vec(&mut self, row: Vec<T>) -> Result<(), ShapeError> {
+        self.check_row_len(row.len())?;
+        self.data.extend(row);
+        self.nb_rows += 1;
+
+        return Ok(());
+    }
+
+    /// Build row-major matrix from rows pushed in builder
+    pub fn finish(self) -> Matrix<T> {
+        return Matrix::from_vec_row_major(self.nb_rows, self.nb_cols, self.data)
+            .expect("builder data always contains nb_rows * nb_cols elements");
+    }
+
+    /// Create a row-major matrix with given number of columns from an iterator of rows
+    pub fn from_row_iter<I>(nb_cols: usize, rows: I) -> Result<Matrix<T>, ShapeError>
+    where
+        I: IntoIterator<Item = Vec<T>>,
+    {
+        let rows: I::IntoIter = rows.into_iter();
+        let mut builder: Self = Self::with_capacity(nb_cols, rows.size_hint().0);
+
+        for row in rows {
+            builder.push_row_vec(row)?;
+        }
+
+        return Ok(builder.finish());
+    }
+
+    /// Check that length of row to push is the number of columns of builder
+    fn check_row_len(&self, row_len: usize) -> Result<(), ShapeError> {
+        if row_len != self.nb_cols {
+            return Err(ShapeError::RaggedRow {
+                row_id: self.nb_rows,
+                expected: self.nb_cols,
+                actual: row_len,
+            });
+        }
+
+        return Ok(());
+    }
+}
+
+impl<T> RowStreamBuilder<T>
+where
+    T: Clone,
+{
+    /// Append row given as a slice, it must have the number of columns of builder
+    pub fn push_row(&mut self, row: &[T]) -> Result<(), ShapeError> {
+        self.check_row_len(row.len())?;
+        self.data.extend_from_slice(row);
+        self.nb_rows += 1;
+
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_row_stream_builder() {
+        let mut builder: RowStreamBuilder<i32> = RowStreamBuilder::new(3);
+        assert_eq!(builder.len_rows(), 0);
+
+        builder.push_row(&[1, 2, 3]).unwrap();
+        builder.push_row_vec(vec![4, 5, 6]).unwrap();
+        assert_eq!(builder.len_rows(), 2);
+
+        assert_eq!(
+            builder.push_row(&[7, 8]),
+            Err(ShapeError::RaggedRow {
+                row_id: 2,
+                expected: 3,
+                actual: 2
+            })
+        );
+        assert_eq!(builder.len_rows(), 2);
+
+        let matrix: Matrix<i32> = builder.finish();
+        assert_eq!(matrix.nb_rows(), 2);
+        assert_eq!(matrix.nb_cols(), 3);
+        assert_eq!(matrix[(0, 2)], 3);
+        assert_eq!(matrix[(1, 0)], 4);
+    }
+
+    #[test]
+    fn test_row_stream_builder_single_buffer() {
+        let nb_rows: usize = 1_000_000;
+        let mut builder: RowStreamBuilder<u32> = RowStreamBuilder::with_capacity(3, nb_rows);
+        let data_ptr: *const u32 = builder.data.as_ptr();
+
+        for row_id in 0..nb_rows {
+            let value: u32 = row_id as u32;
+            builder.push_row(&[value, value + 1, value + 2]).unwrap();
+        }
+
+        assert_eq!(builder.len_rows(), nb_rows);
+        assert_eq!(builder.data.as_ptr(), data_ptr);
+        assert_eq!(builder.data.capacity(), 3 * nb_rows);
+
+        let mut builder: RowStreamBuilder<u32> = RowStreamBuilder::new(3);
+
+        for row_id in 0..nb_rows {
+            let value: u32 = row_id as u32;
+            builder.push_row(&[value, value + 1, value + 2]).unwrap();
+        }
+
+        // Geometric growth never keeps more than twice the needed memory
+        assert!(builder.data.capacity() <= 2 * 3 * nb_rows);
+
+        let matrix: Matrix<u32> = builder.finish();
+        assert_eq!(matrix.nb_rows(), nb_rows);
+        assert_eq!(matrix[(nb_rows - 1, 2)], nb_rows as u32 + 1);
+    }
+
+    #[test]
+    fn test_row_stream_builder_from_row_iter() {
+        let rows: Vec<Vec<f64>> = vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]];
+        let matrix: Matrix<f64> = RowStreamBuilder::from_row_iter(2, rows).unwrap();
+
+        assert_eq!(matrix.nb_rows(), 3);
+        assert_eq!(matrix[(2, 1)], 6.0);
+
+        let rows: Vec<Vec<f64>> = vec![vec![1.0, 2.0], vec![3.0]];
+        assert_eq!(
+            RowStreamBuilder::from_row_iter(2, rows).err(),
+            Some(ShapeError::RaggedRow {
+                row_id: 1,
+                expected: 2,
+                actual: 1
+            })
+        );
+    }
+}
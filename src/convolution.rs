@@ -0,0 +1,277 @@
+use std::ops::{Add, Mul};
+
+use super::error::ShapeError;
+use super::matrix::Matrix;
+use super::num::Zero;
+use super::view::View;
+
+/// Convolution mode
+/// This enumeration defines which part of the full convolution is kept
+/// - Full keeps every position where input and kernel overlap
+/// - Same keeps the central part with the dimensions of input
+/// - Valid keeps only positions where kernel overlaps input entirely
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConvMode {
+    Full,
+    Same,
+    Valid,
+}
+
+/// Compute 2D convolution of input by kernel with the given mode
+/// Result is a row-major matrix, it is empty in Valid mode when kernel is larger than input.
+/// When input or kernel is empty, nothing overlaps so result is filled with zeros,
+/// and it is empty in Valid mode
+pub fn convolve2d<T>(input: &View<T>, kernel: &View<T>, mode: ConvMode) -> Matrix<T>
+where
+    T: Copy + Zero + Add<Output = T> + Mul<Output = T>,
+{
+    let (in_rows, in_cols): (usize, usize) = (input.nb_rows(), input.nb_cols());
+    let (k_rows, k_cols): (usize, usize) = (kernel.nb_rows(), kernel.nb_cols());
+    let is_empty: bool = in_rows * in_cols == 0 || k_rows * k_cols == 0;
+
+    // Position of first element of result and dimensions of result in full convolution
+    let (start_row, start_col, nb_rows, nb_cols): (usize, usize, usize, usize) = match mode {
+        ConvMode::Full => (
+            0,
+            0,
+            (in_rows + k_rows).saturating_sub(1),
+            (in_cols + k_cols).saturating_sub(1),
+        ),
+        ConvMode::Same => (
+            k_rows.saturating_sub(1) / 2,
+            k_cols.saturating_sub(1) / 2,
+            in_rows,
+            in_cols,
+        ),
+        ConvMode::Valid => (
+            k_rows.saturating_sub(1),
+            k_cols.saturating_sub(1),
+            (in_rows + 1).saturating_sub(k_rows),
+            (in_cols + 1).saturating_sub(k_cols),
+        ),
+    };
+
+    if is_empty {
+        return match mode {
+            ConvMode::Valid => Matrix::zeros(0, 0),
+            _ => Matrix::zeros(nb_rows, nb_cols),
+        };
+    }
+
+    return Matrix::from_fn_row_major(nb_rows, nb_cols, |row_id, col_id| {
+        let full_row: usize = start_row + row_id;
+        let full_col: usize = start_col + col_id;
+
+        // Kernel indexes such that input index (full_row - k, full_col - l) is inside input
+        let k_first: usize = (full_row + 1).saturating_sub(in_rows);
+        let k_last: usize = full_row.min(k_rows.saturating_sub(1));
+        let l_first: usize = (full_col + 1).saturating_sub(in_cols);
+        let l_last: usize = full_col.min(k_cols.saturating_sub(1));
+
+        let mut sum: T = T::zero();
+
+        for k in k_first..=k_last {
+            for l in l_first..=l_last {
+                sum = sum + input[(full_row - k, full_col - l)] * kernel[(k, l)];
+            }
+        }
+
+        return sum;
+    });
+}
+
+/// Compute valid 2D cross-correlation of input by kernel, that is without padding
+/// and without flipping kernel. Result is a row-major matrix with dimensions
+/// (in_rows - k_rows + 1, in_cols - k_cols + 1)
+pub fn correlate2d<T>(input: &View<T>, kernel: &View<T>) -> Result<Matrix<T>, ShapeError>
+where
+    T: Copy + Zero + Add<Output = T> + Mul<Output = T>,
+{
+    let (in_rows, in_cols): (usize, usize) = (input.nb_rows(), input.nb_cols());
+    let (k_rows, k_cols): (usize, usize) = (kernel.nb_rows(), kernel.nb_cols());
+
+    if k_rows > in_rows || k_cols > in_cols {
+        return Err(ShapeError::KernelTooLarge {
+            kernel: (k_rows, k_cols),
+            input: (in_rows, in_cols),
+        });
+    }
+
+    return Ok(Matrix::from_fn_row_major(
+        in_rows - k_rows + 1,
+        in_cols - k_cols + 1,
+        |row_id, col_id| {
+            let mut sum: T = T::zero();
+
+            for k in 0..k_rows {
+                for l in 0..k_cols {
+                    sum = sum + input[(row_id + k, col_id + l)] * kernel[(k, l)];
+                }
+            }
+
+            return sum;
+        },
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::view::Accessor;
+
+    #[test]
+    fn test_convolve2d_full() {
+        let input_data: Vec<i32> = vec![1, 2, 3, 4];
+        let kernel_data: Vec<i32> = vec![1, 0, 0, 2];
+
+        let input: View<i32> = View::new(2, 2, Accessor::new(2, 1), input_data.as_slice());
+        let kernel: View<i32> = View::new(2, 2, Accessor::new(2, 1), kernel_data.as_slice());
+
+        let result: Matrix<i32> = convolve2d(&input, &kernel, ConvMode::Full);
+        let result_ref: Vec<i32> = vec![1, 2, 0, 3, 6, 4, 0, 6, 8];
+
+        assert_eq!(result.nb_rows(), 3);
+        assert_eq!(result.nb_cols(), 3);
+
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_eq!(result[(i, j)], result_ref[3 * i + j]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_convolve2d_same() {
+        let input_data: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let kernel_data: Vec<i32> = vec![0, 1, 0, 1, 1, 1, 0, 1, 0];
+
+        let input: View<i32> = View::new(3, 3, Accessor::new(3, 1), input_data.as_slice());
+        let kernel: View<i32> = View::new(3, 3, Accessor::new(3, 1), kernel_data.as_slice());
+
+        let result: Matrix<i32> = convolve2d(&input, &kernel, ConvMode::Same);
+        let result_ref: Vec<i32> = vec![7, 11, 11, 17, 25, 23, 19, 29, 23];
+
+        assert_eq!(result.nb_rows(), input.nb_rows());
+        assert_eq!(result.nb_cols(), input.nb_cols());
+
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_eq!(result[(i, j)], result_ref[3 * i + j]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_convolve2d_valid() {
+        let input_data: Vec<i32> = vec![1, 5, 9, 13, 2, 6, 10, 14, 3, 7, 11, 15, 4, 8, 12, 16];
+        let kernel_data: Vec<i32> = vec![1, 2, 0, 0, 1, 0, 0, 0, -1];
+
+        // Column-major input to check that convolution only relies on logical indexes
+        let input: View<i32> = View::new(4, 4, Accessor::new(1, 4), input_data.as_slice());
+        let kernel: View<i32> = View::new(3, 3, Accessor::new(3, 1), kernel_data.as_slice());
+
+        let result: Matrix<i32> = convolve2d(&input, &kernel, ConvMode::Valid);
+
+        assert_eq!(result.nb_rows(), 2);
+        assert_eq!(result.nb_cols(), 2);
+        assert_eq!(result[(0, 0)], 36);
+        assert_eq!(result[(0, 1)], 39);
+        assert_eq!(result[(1, 0)], 48);
+        assert_eq!(result[(1, 1)], 51);
+    }
+
+    #[test]
+    fn test_convolve2d_valid_kernel_larger_than_input() {
+        let input_data: Vec<i32> = vec![1, 2, 3, 4];
+        let kernel_data: Vec<i32> = vec![1; 9];
+
+        let input: View<i32> = View::new(2, 2, Accessor::new(2, 1), input_data.as_slice());
+        let kernel: View<i32> = View::new(3, 3, Accessor::new(3, 1), kernel_data.as_slice());
+
+        let result: Matrix<i32> = convolve2d(&input, &kernel, ConvMode::Valid);
+
+        assert_eq!(result.nb_rows(), 0);
+        assert_eq!(result.nb_cols(), 0);
+    }
+
+    #[test]
+    fn test_convolve2d_empty_kernel() {
+        let input_data: Vec<i32> = vec![1, 2, 3, 4];
+        let input: View<i32> = View::new(2, 2, Accessor::new(2, 1), input_data.as_slice());
+
+        for kernel in [
+            View::new(0, 0, Accessor::new(0, 1), input_data.as_slice()),
+            View::new(0, 3, Accessor::new(3, 1), input_data.as_slice()),
+        ] {
+            let result: Matrix<i32> = convolve2d(&input, &kernel, ConvMode::Same);
+            assert_eq!(result, Matrix::zeros(2, 2));
+
+            let result: Matrix<i32> = convolve2d(&input, &kernel, ConvMode::Valid);
+            assert_eq!(result.nb_rows(), 0);
+            assert_eq!(result.nb_cols(), 0);
+        }
+    }
+
+    #[test]
+    fn test_convolve2d_empty_input() {
+        let kernel_data: Vec<i32> = vec![1, 2, 3, 4];
+        let kernel: View<i32> = View::new(2, 2, Accessor::new(2, 1), kernel_data.as_slice());
+        let input: View<i32> = View::new(0, 3, Accessor::new(3, 1), kernel_data.as_slice());
+
+        let result: Matrix<i32> = convolve2d(&input, &kernel, ConvMode::Same);
+        assert_eq!(result.nb_rows(), 0);
+        assert_eq!(result.nb_cols(), 3);
+
+        let result: Matrix<i32> = convolve2d(&input, &kernel, ConvMode::Full);
+        assert_eq!(result, Matrix::zeros(1, 4));
+
+        let result: Matrix<i32> = convolve2d(&input, &kernel, ConvMode::Valid);
+        assert_eq!(result.nb_rows(), 0);
+        assert_eq!(result.nb_cols(), 0);
+    }
+
+    #[test]
+    fn test_correlate2d() {
+        let input_data: Vec<i32> = (1..=16).collect();
+        let kernel_data: Vec<i32> = vec![1, 0, 0, -1];
+
+        let input: View<i32> = View::new(4, 4, Accessor::new(4, 1), input_data.as_slice());
+        let kernel: View<i32> = View::new(2, 2, Accessor::new(2, 1), kernel_data.as_slice());
+
+        let result: Matrix<i32> = correlate2d(&input, &kernel).unwrap();
+
+        assert_eq!(result.nb_rows(), 3);
+        assert_eq!(result.nb_cols(), 3);
+
+        for row_id in 0..3 {
+            for col_id in 0..3 {
+                assert_eq!(
+                    result[(row_id, col_id)],
+                    input[(row_id, col_id)] - input[(row_id + 1, col_id + 1)]
+                );
+            }
+        }
+
+        // Kernel is not flipped, unlike in convolution
+        let kernel_data: Vec<i32> = vec![0, 1, 2, 3];
+        let kernel: View<i32> = View::new(2, 2, Accessor::new(2, 1), kernel_data.as_slice());
+        let result: Matrix<i32> = correlate2d(&input, &kernel).unwrap();
+        assert_eq!(result[(0, 0)], 2 + 2 * 5 + 3 * 6);
+        assert_eq!(result[(2, 2)], 12 + 2 * 15 + 3 * 16);
+    }
+
+    #[test]
+    fn test_correlate2d_kernel_too_large() {
+        let input_data: Vec<f64> = vec![1.0, 2.0, 3.0, 4.0];
+        let input: View<f64> = View::new(1, 4, Accessor::new(4, 1), input_data.as_slice());
+        let kernel: View<f64> = View::new(2, 1, Accessor::new(1, 1), input_data.as_slice());
+
+        assert_eq!(
+            correlate2d(&input, &kernel).err(),
+            Some(ShapeError::KernelTooLarge {
+                kernel: (2, 1),
+                input: (1, 4)
+            })
+        );
+    }
+}
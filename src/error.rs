@@ -0,0 +1,338 @@
+use std::fmt;
+
+/// Block position
+/// This enumeration names the four blocks of a matrix made of 2x2 blocks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Block {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl fmt::Display for Block {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            Block::TopLeft => write!(f, "top left"),
+            Block::TopRight => write!(f, "top right"),
+            Block::BottomLeft => write!(f, "bottom left"),
+            Block::BottomRight => write!(f, "bottom right"),
+        };
+    }
+}
+
+/// Shape error
+/// This enumeration describes why dimensions given to an operation are not consistent
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShapeError {
+    /// Input does not contain any element
+    Empty,
+    /// Row at index row_id has not the same length as the first row
+    RaggedRow {
+        row_id: usize,
+        expected: usize,
+        actual: usize,
+    },
+    /// Column at index col_id has not the same length as the first column
+    RaggedColumn {
+        col_id: usize,
+        expected: usize,
+        actual: usize,
+    },
+    /// Number of elements is not the expected one
+    LengthMismatch { expected: usize, actual: usize },
+    /// Number of elements of matrix with nb_rows rows and nb_cols columns overflows usize
+    TooManyElements { nb_rows: usize, nb_cols: usize },
+    /// Row index is not lower than number of rows
+    RowOutOfBounds { row_id: usize, nb_rows: usize },
+    /// Column index is not lower than number of columns
+    ColumnOutOfBounds { col_id: usize, nb_cols: usize },
+    /// Blocks of count elements starting at first and second indexes overlap
+    OverlappingBlocks {
+        first: usize,
+        second: usize,
+        count: usize,
+    },
+    /// Dimensions (number of rows, number of columns) are not the expected ones
+    DimensionMismatch {
+        expected: (usize, usize),
+        actual: (usize, usize),
+    },
+    /// Operation needs a square matrix but it has nb_rows rows and nb_cols columns
+    NotSquare { nb_rows: usize, nb_cols: usize },
+    /// Operation needs a diagonal without zero but element at (index, index) is zero
+    ZeroDiagonal { index: usize },
+    /// First column and first row do not share the same first element
+    CornerMismatch,
+    /// Kernel with dimensions (number of rows, number of columns) does not fit inside input
+    KernelTooLarge {
+        kernel: (usize, usize),
+        input: (usize, usize),
+    },
+    /// Operation needs elements stored contiguously from the start of data, without offset or gap
+    NotContiguous,
+    /// Rows of matrix are interleaved in memory, so they cannot be split into disjoint slices
+    InterleavedRows,
+    /// Columns of matrix are interleaved in memory, so they cannot be split into disjoint slices
+    InterleavedCols,
+    /// Number of observations is not greater than delta degrees of freedom of normalization
+    NotEnoughObservations { nb_obs: usize, ddof: usize },
+    /// Blocks on same row of blocks do not have the same number of rows
+    BlockRowMismatch {
+        first: Block,
+        second: Block,
+        expected: usize,
+        actual: usize,
+    },
+    /// Blocks on same column of blocks do not have the same number of columns
+    BlockColumnMismatch {
+        first: Block,
+        second: Block,
+        expected: usize,
+        actual: usize,
+    },
+}
+
+impl fmt::Display for ShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            ShapeError::Empty => write!(f, "input does not contain any element"),
+            ShapeError::RaggedRow {
+                row_id,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "row {} has {} elements but {} were expected",
+                row_id, actual, expected
+            ),
+            ShapeError::RaggedColumn {
+                col_id,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "column {} has {} elements but {} were expected",
+                col_id, actual, expected
+            ),
+            ShapeError::LengthMismatch { expected, actual } => write!(
+                f,
+                "{} elements were given but {} were expected",
+                actual, expected
+            ),
+            ShapeError::TooManyElements { nb_rows, nb_cols } => write!(
+                f,
+                "number of elements of matrix with dimensions {}x{} overflows",
+                nb_rows, nb_cols
+            ),
+            ShapeError::RowOutOfBounds { row_id, nb_rows } => write!(
+                f,
+                "row index {} is out of bounds of matrix with {} rows",
+                row_id, nb_rows
+            ),
+            ShapeError::ColumnOutOfBounds { col_id, nb_cols } => write!(
+                f,
+                "column index {} is out of bounds of matrix with {} columns",
+                col_id, nb_cols
+            ),
+            ShapeError::OverlappingBlocks {
+                first,
+                second,
+                count,
+            } => write!(
+                f,
+                "blocks of size {} starting at {} and {} overlap",
+                count, first, second
+            ),
+            ShapeError::DimensionMismatch { expected, actual } => write!(
+                f,
+                "dimensions {}x{} were given but {}x{} were expected",
+                actual.0, actual.1, expected.0, expected.1
+            ),
+            ShapeError::NotSquare { nb_rows, nb_cols } => write!(
+                f,
+                "matrix with dimensions {}x{} is not square",
+                nb_rows, nb_cols
+            ),
+            ShapeError::ZeroDiagonal { index } => write!(
+                f,
+                "diagonal element at index ({}, {}) is zero",
+                index, index
+            ),
+            ShapeError::CornerMismatch => write!(
+                f,
+                "first column and first row do not start with the same element"
+            ),
+            ShapeError::KernelTooLarge { kernel, input } => write!(
+                f,
+                "kernel with dimensions {}x{} is larger than input with dimensions {}x{}",
+                kernel.0, kernel.1, input.0, input.1
+            ),
+            ShapeError::NotContiguous => {
+                write!(
+                    f,
+                    "matrix elements are not stored contiguously without offset"
+                )
+            }
+            ShapeError::InterleavedRows => {
+                write!(f, "matrix rows are interleaved in memory")
+            }
+            ShapeError::InterleavedCols => {
+                write!(f, "matrix columns are interleaved in memory")
+            }
+            ShapeError::NotEnoughObservations { nb_obs, ddof } => write!(
+                f,
+                "{} observations are not enough for {} delta degrees of freedom",
+                nb_obs, ddof
+            ),
+            ShapeError::BlockRowMismatch {
+                first,
+                second,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "{} block has {} rows but {} block has {} rows",
+                second, actual, first, expected
+            ),
+            ShapeError::BlockColumnMismatch {
+                first,
+                second,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "{} block has {} columns but {} block has {} columns",
+                second, actual, first, expected
+            ),
+        };
+    }
+}
+
+impl std::error::Error for ShapeError {}
+
+/// Solve error
+/// This enumeration describes why an iterative or direct solver did not produce a result
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    /// Iterative method did not reach tolerance after given number of iterations
+    NotConverged { nb_iterations: usize },
+    /// Operation needs a square matrix but it has dimensions (number of rows, number of columns)
+    NotSquare { nb_rows: usize, nb_cols: usize },
+    /// Matrix is singular up to rounding, so its factorization cannot solve systems
+    Singular,
+    /// Low-rank modification makes factorized matrix singular up to rounding
+    SingularUpdate,
+    /// Dimensions (number of rows, number of columns) of an operand are not the expected ones
+    DimensionMismatch {
+        expected: (usize, usize),
+        actual: (usize, usize),
+    },
+    /// Intermediate matrix cannot be built or multiplied
+    Shape(ShapeError),
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            SolveError::NotConverged { nb_iterations } => write!(
+                f,
+                "method did not converge after {} iterations",
+                nb_iterations
+            ),
+            SolveError::NotSquare { nb_rows, nb_cols } => write!(
+                f,
+                "matrix with dimensions {}x{} is not square",
+                nb_rows, nb_cols
+            ),
+            SolveError::Singular => write!(f, "matrix is singular"),
+            SolveError::SingularUpdate => write!(f, "low-rank update makes matrix singular"),
+            SolveError::DimensionMismatch { expected, actual } => write!(
+                f,
+                "operand has dimensions {}x{} but {}x{} were expected",
+                actual.0, actual.1, expected.0, expected.1
+            ),
+            SolveError::Shape(error) => write!(f, "{}", error),
+        };
+    }
+}
+
+impl std::error::Error for SolveError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        return match self {
+            SolveError::Shape(error) => Some(error),
+            _ => None,
+        };
+    }
+}
+
+impl From<ShapeError> for SolveError {
+    fn from(error: ShapeError) -> Self {
+        return SolveError::Shape(error);
+    }
+}
+
+/// Arithmetic error
+/// This enumeration describes why an element-wise arithmetic operation is not defined
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArithError {
+    /// Element at given row and column is zero and is used as divisor
+    DivisionByZero { row_id: usize, col_id: usize },
+}
+
+impl fmt::Display for ArithError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            ArithError::DivisionByZero { row_id, col_id } => write!(
+                f,
+                "division by zero element at index ({}, {})",
+                row_id, col_id
+            ),
+        };
+    }
+}
+
+impl std::error::Error for ArithError {}
+
+/// Map error
+/// This enumeration describes why a file cannot be mapped in memory as a matrix
+#[derive(Debug)]
+pub enum MapError {
+    /// File cannot be opened or mapped
+    Io(std::io::Error),
+    /// File size in bytes is not the one needed by matrix dimensions
+    SizeMismatch { expected: usize, actual: usize },
+    /// Mapped memory is not aligned for element type
+    Misaligned { alignment: usize },
+}
+
+impl fmt::Display for MapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            MapError::Io(error) => write!(f, "cannot map file: {}", error),
+            MapError::SizeMismatch { expected, actual } => write!(
+                f,
+                "file has {} bytes but {} were expected",
+                actual, expected
+            ),
+            MapError::Misaligned { alignment } => {
+                write!(f, "mapped memory is not aligned on {} bytes", alignment)
+            }
+        };
+    }
+}
+
+impl std::error::Error for MapError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        return match self {
+            MapError::Io(error) => Some(error),
+            _ => None,
+        };
+    }
+}
+
+impl From<std::io::Error> for MapError {
+    fn from(error: std::io::Error) -> Self {
+        return MapError::Io(error);
+    }
+}
@@ -1,3 +1,36 @@
 #![allow(dead_code)]
+#![allow(clippy::needless_return)]
+#![cfg_attr(test, allow(clippy::assertions_on_constants))]
+mod arithmetic;
+mod builder;
+mod convolution;
+mod error;
+mod lu;
+#[cfg(feature = "mmap")]
+mod mapped;
 mod matrix;
+mod num;
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "serde")]
+mod serialization;
+mod sorting;
+mod statistics;
+#[cfg(test)]
+mod testing;
+mod trace;
+#[cfg(feature = "typed")]
+mod typed;
 mod view;
+
+#[cfg(feature = "mmap")]
+pub use error::MapError;
+pub use lu::Lu;
+#[cfg(feature = "mmap")]
+pub use mapped::{MappedMatrix, Pod};
+pub use matrix::Matrix;
+#[cfg(feature = "trace")]
+pub use trace::{clear_trace_hook, set_trace_hook, OpTrace};
+#[cfg(feature = "typed")]
+pub use typed::{Const, Dim, Dyn, TypedMatrix};
+pub use view::StorageOrder;
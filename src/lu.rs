@@ -0,0 +1,573 @@
+use super::arithmetic::matmul;
+use super::error::SolveError;
+use super::matrix::Matrix;
+use super::trace::traced;
+use super::view::{Accessor, View};
+
+/// LU factorization
+/// This structure contains factorization P * A = L * U with partial pivoting of a square matrix A
+/// of size n, where L is unit lower triangular and U is upper triangular.
+/// Both are stored in a row-major vector, L below diagonal without its unit diagonal
+/// and U on and above diagonal, with the permutation of rows of A
+pub struct Lu {
+    n: usize,
+    lu: Vec<f64>,
+    permutation: Vec<usize>,
+}
+
+impl Lu {
+    /// Factorize square view with partial pivoting
+    /// An error is returned if view is not square or if it is singular up to rounding
+    pub fn new<T>(a: &View<T>) -> Result<Self, SolveError>
+    where
+        T: Copy + Into<f64>,
+    {
+        if a.nb_rows() != a.nb_cols() {
+            return Err(SolveError::NotSquare {
+                nb_rows: a.nb_rows(),
+                nb_cols: a.nb_cols(),
+            });
+        }
+
+        let n: usize = a.nb_rows();
+        let mut lu: Vec<f64> = a.to_row_major_f64();
+
+        let permutation: Option<Vec<usize>> =
+            traced("lu_factorize", (n, n, n), "unblocked", || {
+                lu_factorize(&mut lu, n)
+            });
+
+        return match permutation {
+            Some(permutation) => Ok(Self { n, lu, permutation }),
+            None => Err(SolveError::Singular),
+        };
+    }
+
+    /// Factorize square view with partial pivoting by blocks of block_size columns,
+    /// like right-looking blocked LU of LAPACK getrf. Each panel of columns is factorized
+    /// with the unblocked kernel and its row swaps are applied across whole rows,
+    /// then block of U on the right of panel is solved with unit lower triangular block of L
+    /// and trailing matrix is updated by a matrix product. Pivots are the same as the ones of new,
+    /// so factors only differ by rounding. Errors are the ones of new.
+    /// Panic if block_size is zero
+    pub fn new_blocked<T>(a: &View<T>, block_size: usize) -> Result<Self, SolveError>
+    where
+        T: Copy + Into<f64>,
+    {
+        assert!(block_size > 0, "block size of LU must not be zero");
+
+        if a.nb_rows() != a.nb_cols() {
+            return Err(SolveError::NotSquare {
+                nb_rows: a.nb_rows(),
+                nb_cols: a.nb_cols(),
+            });
+        }
+
+        let n: usize = a.nb_rows();
+
+        return traced("lu_factorize", (n, n, n), "blocked", || {
+            let mut lu: Vec<f64> = a.to_row_major_f64();
+            let scale: f64 = lu.iter().fold(0.0, |acc: f64, x: &f64| acc.max(x.abs()));
+            let tolerance: f64 = f64::EPSILON * scale * (n as f64);
+            let mut permutation: Vec<usize> = (0..n).collect();
+
+            for start in (0..n).step_by(block_size) {
+                let end: usize = (start + block_size).min(n);
+
+                // Factorize panel of columns start..end, swapping whole rows
+                for k in start..end {
+                    let pivot_id: usize = pivot_row(&lu, n, k);
+
+                    if lu[pivot_id * n + k].abs() <= tolerance {
+                        return Err(SolveError::Singular);
+                    }
+
+                    if pivot_id != k {
+                        for col_id in 0..n {
+                            lu.swap(k * n + col_id, pivot_id * n + col_id);
+                        }
+
+                        permutation.swap(k, pivot_id);
+                    }
+
+                    for row_id in (k + 1)..n {
+                        let factor: f64 = lu[row_id * n + k] / lu[k * n + k];
+                        lu[row_id * n + k] = factor;
+
+                        for col_id in (k + 1)..end {
+                            lu[row_id * n + col_id] -= factor * lu[k * n + col_id];
+                        }
+                    }
+                }
+
+                if end == n {
+                    break;
+                }
+
+                // Solve U12 = L11^-1 * A12 by forward substitution on rows of panel
+                for row_id in start..end {
+                    for k in start..row_id {
+                        let factor: f64 = lu[row_id * n + k];
+
+                        for col_id in end..n {
+                            lu[row_id * n + col_id] -= factor * lu[k * n + col_id];
+                        }
+                    }
+                }
+
+                // Update trailing matrix A22 = A22 - L21 * U12
+                let l21: View<f64> = View::new(
+                    n - end,
+                    end - start,
+                    Accessor::new_with_offset(n, 1, end, start),
+                    &lu,
+                );
+                let u12: View<f64> = View::new(
+                    end - start,
+                    n - end,
+                    Accessor::new_with_offset(n, 1, start, end),
+                    &lu,
+                );
+                let update: Matrix<f64> = matmul(&l21, &u12)?;
+
+                for row_id in end..n {
+                    for col_id in end..n {
+                        lu[row_id * n + col_id] -= update[(row_id - end, col_id - end)];
+                    }
+                }
+            }
+
+            return Ok(Self { n, lu, permutation });
+        });
+    }
+
+    /// Get size of factorized matrix
+    pub fn size(&self) -> usize {
+        return self.n;
+    }
+
+    /// Get permutation of rows, where row i of P * A is row permutation[i] of A
+    pub fn permutation(&self) -> &[usize] {
+        return &self.permutation;
+    }
+
+    /// Get unit lower triangular factor L as a row-major matrix
+    pub fn l(&self) -> Matrix<f64> {
+        return Matrix::from_fn_row_major(self.n, self.n, |row_id, col_id| {
+            if row_id == col_id {
+                return 1.0;
+            } else if col_id < row_id {
+                return self.lu[row_id * self.n + col_id];
+            } else {
+                return 0.0;
+            }
+        });
+    }
+
+    /// Get upper triangular factor U as a row-major matrix
+    pub fn u(&self) -> Matrix<f64> {
+        return Matrix::from_fn_row_major(self.n, self.n, |row_id, col_id| {
+            if col_id >= row_id {
+                return self.lu[row_id * self.n + col_id];
+            } else {
+                return 0.0;
+            }
+        });
+    }
+
+    /// Solve A * X = B, where each column of B is a right-hand side
+    /// An error is returned if number of rows of B is not size of A
+    pub fn solve<T>(&self, b: &View<T>) -> Result<Matrix<f64>, SolveError>
+    where
+        T: Copy + Into<f64>,
+    {
+        self.check_rows(b)?;
+        return Ok(self.solve_f64(&b.map(|&x| x.into())));
+    }
+
+    /// Solve (A + u * v^T) * X = B from factorization of A with Sherman-Morrison formula,
+    /// where u and v are columns of size n, without factorizing modified matrix.
+    /// An error is returned if 1 + v^T * A^-1 * u is zero up to rounding,
+    /// since modified matrix is then singular
+    pub fn solve_rank_one_modified<T>(
+        &self,
+        u: &View<T>,
+        v: &View<T>,
+        b: &View<T>,
+    ) -> Result<Matrix<f64>, SolveError>
+    where
+        T: Copy + Into<f64>,
+    {
+        for column in [u, v] {
+            if column.nb_cols() != 1 {
+                return Err(SolveError::DimensionMismatch {
+                    expected: (self.n, 1),
+                    actual: (column.nb_rows(), column.nb_cols()),
+                });
+            }
+        }
+
+        return self.solve_low_rank_modified(u, v, b);
+    }
+
+    /// Solve (A + U * V^T) * X = B from factorization of A with Woodbury formula,
+    /// where U and V have n rows and k columns, without factorizing modified matrix.
+    /// Only a system of size k with capacitance matrix I + V^T * A^-1 * U is factorized,
+    /// so it is cheap when k is small. An error is returned if capacitance matrix is singular
+    /// up to rounding, since modified matrix is then singular
+    pub fn solve_low_rank_modified<T>(
+        &self,
+        u: &View<T>,
+        v: &View<T>,
+        b: &View<T>,
+    ) -> Result<Matrix<f64>, SolveError>
+    where
+        T: Copy + Into<f64>,
+    {
+        self.check_rows(u)?;
+        self.check_rows(b)?;
+
+        if (v.nb_rows(), v.nb_cols()) != (u.nb_rows(), u.nb_cols()) {
+            return Err(SolveError::DimensionMismatch {
+                expected: (u.nb_rows(), u.nb_cols()),
+                actual: (v.nb_rows(), v.nb_cols()),
+            });
+        }
+
+        let k: usize = u.nb_cols();
+        let v: Matrix<f64> = v.map(|&x| x.into());
+
+        // Z = A^-1 * U and Y = A^-1 * B
+        let z: Matrix<f64> = self.solve_f64(&u.map(|&x| x.into()));
+        let y: Matrix<f64> = self.solve_f64(&b.map(|&x| x.into()));
+
+        // Capacitance matrix C = I + V^T * Z, whose cancellation is measured against V^T * Z
+        let mut capacitance: Vec<f64> = matmul(&v.transpose_view(), &z.full_view())?
+            .full_view()
+            .to_row_major_f64();
+        let scale: f64 = capacitance
+            .iter()
+            .fold(1.0, |acc: f64, x: &f64| acc.max(x.abs()));
+
+        for index in 0..k {
+            capacitance[index * k + index] += 1.0;
+        }
+
+        let tolerance: f64 = f64::EPSILON * scale * ((self.n * k) as f64);
+        let permutation: Vec<usize> = lu_factorize_with_tolerance(&mut capacitance, k, tolerance)
+            .ok_or(SolveError::SingularUpdate)?;
+
+        // X = Y - Z * C^-1 * V^T * Y
+        let product: Matrix<f64> = matmul(&v.transpose_view(), &y.full_view())?;
+        let mut w: Vec<f64> = Vec::with_capacity(k * product.nb_cols());
+
+        for col_id in 0..product.nb_cols() {
+            let rhs: Vec<f64> = (0..k).map(|row_id| product[(row_id, col_id)]).collect();
+            w.extend(lu_solve(&capacitance, &permutation, k, &rhs));
+        }
+
+        let w: Matrix<f64> = Matrix::from_vec_column_major(k, product.nb_cols(), w)?;
+        let correction: Matrix<f64> = matmul(&z.full_view(), &w.full_view())?;
+
+        return Ok(Matrix::from_fn_column_major(
+            self.n,
+            y.nb_cols(),
+            |row_id, col_id| {
+                return y[(row_id, col_id)] - correction[(row_id, col_id)];
+            },
+        ));
+    }
+
+    /// Check that view has as many rows as factorized matrix
+    fn check_rows<T>(&self, view: &View<T>) -> Result<(), SolveError> {
+        if view.nb_rows() != self.n {
+            return Err(SolveError::DimensionMismatch {
+                expected: (self.n, view.nb_cols()),
+                actual: (view.nb_rows(), view.nb_cols()),
+            });
+        }
+
+        return Ok(());
+    }
+
+    /// Solve A * X = B for float matrix B with n rows, as a column-major matrix
+    fn solve_f64(&self, b: &Matrix<f64>) -> Matrix<f64> {
+        let data: Vec<f64> = traced(
+            "lu_solve",
+            (self.n, self.n, b.nb_cols()),
+            "triangular",
+            || {
+                let mut data: Vec<f64> = Vec::with_capacity(self.n * b.nb_cols());
+
+                for col_id in 0..b.nb_cols() {
+                    let rhs: Vec<f64> = (0..self.n).map(|row_id| b[(row_id, col_id)]).collect();
+                    data.extend(lu_solve(&self.lu, &self.permutation, self.n, &rhs));
+                }
+
+                return data;
+            },
+        );
+
+        return Matrix::from_fn_column_major(self.n, b.nb_cols(), |row_id, col_id| {
+            return data[col_id * self.n + row_id];
+        });
+    }
+}
+
+/// Factorize in place row-major square matrix of size n as P * A = L * U with partial pivoting
+/// Return permutation of rows, or None if matrix is singular up to rounding
+pub(crate) fn lu_factorize(lu: &mut [f64], n: usize) -> Option<Vec<usize>> {
+    let scale: f64 = lu.iter().fold(0.0, |acc: f64, x: &f64| acc.max(x.abs()));
+    return lu_factorize_with_tolerance(lu, n, f64::EPSILON * scale * (n as f64));
+}
+
+/// Factorize in place row-major square matrix of size n like lu_factorize,
+/// where matrix is singular if absolute value of a pivot is at most tolerance
+fn lu_factorize_with_tolerance(lu: &mut [f64], n: usize, tolerance: f64) -> Option<Vec<usize>> {
+    let mut permutation: Vec<usize> = (0..n).collect();
+
+    for k in 0..n {
+        let pivot_id: usize = pivot_row(lu, n, k);
+
+        if lu[pivot_id * n + k].abs() <= tolerance {
+            return None;
+        }
+
+        if pivot_id != k {
+            for col_id in 0..n {
+                lu.swap(k * n + col_id, pivot_id * n + col_id);
+            }
+
+            permutation.swap(k, pivot_id);
+        }
+
+        for row_id in (k + 1)..n {
+            let factor: f64 = lu[row_id * n + k] / lu[k * n + k];
+            lu[row_id * n + k] = factor;
+
+            for col_id in (k + 1)..n {
+                lu[row_id * n + col_id] -= factor * lu[k * n + col_id];
+            }
+        }
+    }
+
+    return Some(permutation);
+}
+
+/// Solve A * x = b from LU factorization of A and its permutation of rows
+pub(crate) fn lu_solve(lu: &[f64], permutation: &[usize], n: usize, b: &[f64]) -> Vec<f64> {
+    let mut x: Vec<f64> = permutation.iter().map(|&row_id| b[row_id]).collect();
+
+    for row_id in 0..n {
+        for col_id in 0..row_id {
+            x[row_id] -= lu[row_id * n + col_id] * x[col_id];
+        }
+    }
+
+    for row_id in (0..n).rev() {
+        for col_id in (row_id + 1)..n {
+            x[row_id] -= lu[row_id * n + col_id] * x[col_id];
+        }
+
+        x[row_id] /= lu[row_id * n + row_id];
+    }
+
+    return x;
+}
+
+/// Find the row index of pivot for column k in row-major square matrix of size n
+/// The pivot is the element of largest absolute value on or below the diagonal
+fn pivot_row(lu: &[f64], n: usize, k: usize) -> usize {
+    let mut pivot_id: usize = k;
+
+    for row_id in (k + 1)..n {
+        if lu[row_id * n + k].abs() > lu[pivot_id * n + k].abs() {
+            pivot_id = row_id;
+        }
+    }
+
+    return pivot_id;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::view::Accessor;
+
+    fn assert_close(a: &Matrix<f64>, b: &Matrix<f64>, tolerance: f64) {
+        assert_eq!((a.nb_rows(), a.nb_cols()), (b.nb_rows(), b.nb_cols()));
+
+        for row_id in 0..a.nb_rows() {
+            for col_id in 0..a.nb_cols() {
+                assert!((a[(row_id, col_id)] - b[(row_id, col_id)]).abs() < tolerance);
+            }
+        }
+    }
+
+    fn system() -> Matrix<f64> {
+        return Matrix::from_rows(&[&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0], &[7.0, 8.0, 10.0]])
+            .unwrap();
+    }
+
+    #[test]
+    fn test_lu_factors() {
+        let a: Matrix<f64> = system();
+        let lu: Lu = Lu::new(&a.full_view()).unwrap();
+
+        assert_eq!(lu.size(), 3);
+        assert_eq!(lu.permutation()[0], 2);
+
+        let product: Matrix<f64> = matmul(&lu.l().full_view(), &lu.u().full_view()).unwrap();
+        let permuted: Matrix<f64> =
+            Matrix::from_fn_row_major(3, 3, |i, j| a[(lu.permutation()[i], j)]);
+        assert_close(&product, &permuted, 1e-12);
+
+        for k in 0..3 {
+            assert_eq!(lu.l()[(k, k)], 1.0);
+        }
+
+        assert_eq!((lu.l()[(0, 2)], lu.u()[(2, 0)]), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_lu_blocked() {
+        let a: Matrix<f64> = Matrix::from_fn_row_major(7, 7, |i, j| {
+            return ((3 * i + 5 * j) % 7) as f64 - 2.0 + if i == j { 0.5 } else { 0.0 };
+        });
+        let unblocked: Lu = Lu::new(&a.full_view()).unwrap();
+
+        for block_size in [1, 2, 3, 7, 10] {
+            let blocked: Lu = Lu::new_blocked(&a.full_view(), block_size).unwrap();
+
+            assert_eq!(blocked.permutation(), unblocked.permutation());
+            assert_close(&blocked.l(), &unblocked.l(), 1e-12);
+            assert_close(&blocked.u(), &unblocked.u(), 1e-12);
+        }
+
+        let singular: Matrix<f64> = Matrix::from_fn_row_major(4, 4, |i, j| (i * j) as f64);
+        assert_eq!(
+            Lu::new_blocked(&singular.full_view(), 2).err(),
+            Some(SolveError::Singular)
+        );
+    }
+
+    #[test]
+    fn test_lu_solve() {
+        let a: Matrix<f64> = system();
+        let b: Matrix<f64> = Matrix::from_rows(&[&[1.0, 0.0], &[2.0, 1.0], &[3.0, -1.0]]).unwrap();
+
+        let x: Matrix<f64> = Lu::new(&a.full_view())
+            .unwrap()
+            .solve(&b.full_view())
+            .unwrap();
+        let product: Matrix<f64> = matmul(&a.full_view(), &x.full_view()).unwrap();
+        assert_close(&product, &b, 1e-12);
+    }
+
+    #[test]
+    fn test_lu_errors() {
+        let data: Vec<f64> = vec![1.0, 2.0, 2.0, 4.0, 0.0, 1.0];
+
+        let view: View<f64> = View::new(2, 3, Accessor::new(3, 1), data.as_slice());
+        assert_eq!(
+            Lu::new(&view).err(),
+            Some(SolveError::NotSquare {
+                nb_rows: 2,
+                nb_cols: 3
+            })
+        );
+
+        let singular: View<f64> = View::new(2, 2, Accessor::new(2, 1), data.as_slice());
+        assert_eq!(Lu::new(&singular).err(), Some(SolveError::Singular));
+
+        let lu: Lu = Lu::new(&system().full_view()).unwrap();
+        assert_eq!(
+            lu.solve(&view).err(),
+            Some(SolveError::DimensionMismatch {
+                expected: (3, 3),
+                actual: (2, 3)
+            })
+        );
+    }
+
+    #[test]
+    fn test_lu_solve_rank_one_modified() {
+        let a: Matrix<f64> = system();
+        let u: Matrix<f64> = Matrix::from_cols(&[&[1.0, -2.0, 0.5]]).unwrap();
+        let v: Matrix<f64> = Matrix::from_cols(&[&[0.0, 3.0, 1.0]]).unwrap();
+        let b: Matrix<f64> = Matrix::from_cols(&[&[1.0, 2.0, 3.0], &[0.0, 1.0, 0.0]]).unwrap();
+
+        let lu: Lu = Lu::new(&a.full_view()).unwrap();
+        let x: Matrix<f64> = lu
+            .solve_rank_one_modified(&u.full_view(), &v.full_view(), &b.full_view())
+            .unwrap();
+
+        let modified: Matrix<f64> =
+            Matrix::from_fn_row_major(3, 3, |i, j| a[(i, j)] + u[(i, 0)] * v[(j, 0)]);
+        let expected: Matrix<f64> = Lu::new(&modified.full_view())
+            .unwrap()
+            .solve(&b.full_view())
+            .unwrap();
+        assert_close(&x, &expected, 1e-10);
+
+        assert_eq!(
+            lu.solve_rank_one_modified(&b.full_view(), &v.full_view(), &b.full_view())
+                .err(),
+            Some(SolveError::DimensionMismatch {
+                expected: (3, 1),
+                actual: (3, 2)
+            })
+        );
+    }
+
+    #[test]
+    fn test_lu_solve_rank_one_modified_singular() {
+        // Modification sends w = (1, 2, 3) to zero, so 1 + v^T * A^-1 * u vanishes up to rounding
+        let a: Matrix<f64> =
+            Matrix::from_rows(&[&[4.0, 1.0, 2.0], &[1.0, 5.0, 3.0], &[2.0, 3.0, 6.0]]).unwrap();
+        let w: Matrix<f64> = Matrix::from_cols(&[&[1.0, 2.0, 3.0]]).unwrap();
+        let v: Matrix<f64> = Matrix::from_cols(&[&[0.3, 0.7, 0.1]]).unwrap();
+
+        let v_dot_w: f64 = (0..3).map(|i| v[(i, 0)] * w[(i, 0)]).sum();
+        let a_w: Matrix<f64> = matmul(&a.full_view(), &w.full_view()).unwrap();
+        let u: Matrix<f64> = a_w.map(|&x| -x / v_dot_w);
+
+        let lu: Lu = Lu::new(&a.full_view()).unwrap();
+        assert_eq!(
+            lu.solve_rank_one_modified(&u.full_view(), &v.full_view(), &w.full_view())
+                .err(),
+            Some(SolveError::SingularUpdate)
+        );
+    }
+
+    #[test]
+    fn test_lu_solve_low_rank_modified() {
+        let a: Matrix<f64> = system();
+        let u: Matrix<f64> = Matrix::from_cols(&[&[1.0, -2.0, 0.5], &[0.0, 1.0, 1.0]]).unwrap();
+        let v: Matrix<f64> = Matrix::from_cols(&[&[0.0, 3.0, 1.0], &[2.0, 0.0, -1.0]]).unwrap();
+        let b: Matrix<f64> = Matrix::from_cols(&[&[1.0, 2.0, 3.0]]).unwrap();
+
+        let lu: Lu = Lu::new(&a.full_view()).unwrap();
+        let x: Matrix<f64> = lu
+            .solve_low_rank_modified(&u.full_view(), &v.full_view(), &b.full_view())
+            .unwrap();
+
+        let update: Matrix<f64> = matmul(&u.full_view(), &v.transpose_view()).unwrap();
+        let modified: Matrix<f64> =
+            Matrix::from_fn_row_major(3, 3, |i, j| a[(i, j)] + update[(i, j)]);
+        let expected: Matrix<f64> = Lu::new(&modified.full_view())
+            .unwrap()
+            .solve(&b.full_view())
+            .unwrap();
+        assert_close(&x, &expected, 1e-10);
+
+        assert_eq!(
+            lu.solve_low_rank_modified(&u.full_view(), &b.full_view(), &b.full_view())
+                .err(),
+            Some(SolveError::DimensionMismatch {
+                expected: (3, 2),
+                actual: (3, 1)
+            })
+        );
+    }
+}
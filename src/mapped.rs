@@ -0,0 +1,208 @@
+use std::fs::File;
+use std::marker::PhantomData;
+use std::path::Path;
+
+use memmap2::Mmap;
+
+use super::error::MapError;
+use super::view::{Accessor, StorageOrder, View};
+
+/// Plain old data
+/// This trait marks element types which can be read directly from raw bytes of a file
+///
+/// # Safety
+/// Implementors must be Copy types without padding bytes for which every bit pattern is a valid value
+pub unsafe trait Pod: Copy {}
+
+macro_rules! impl_pod {
+    ($($t:ty),*) => {
+        $(
+            unsafe impl Pod for $t {}
+        )*
+    };
+}
+
+impl_pod!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
+
+/// Read-only matrix whose elements are stored in a memory-mapped file
+/// Pages of file are loaded by operating system when elements are accessed,
+/// so matrices larger than memory can be explored through a view
+pub struct MappedMatrix<T> {
+    nb_rows: usize,
+    nb_cols: usize,
+    accessor: Accessor,
+    mmap: Mmap,
+    phantom: PhantomData<T>,
+}
+
+impl<T> MappedMatrix<T>
+where
+    T: Pod,
+{
+    /// Map file containing raw elements of matrix without header, in native endianness,
+    /// from number of rows, number of columns and storage order of elements in file
+    /// An error is returned if file size is not the one of matrix elements, including when
+    /// this size overflows usize
+    pub fn open<P>(
+        path: P,
+        nb_rows: usize,
+        nb_cols: usize,
+        order: StorageOrder,
+    ) -> Result<Self, MapError>
+    where
+        P: AsRef<Path>,
+    {
+        let file: File = File::open(path)?;
+
+        // SAFETY: mapping is read-only and file must not be modified while it is mapped,
+        // which is the contract of this read-only matrix for its whole lifetime
+        let mmap: Mmap = unsafe { Mmap::map(&file)? };
+
+        // Size overflowing usize is reported as usize::MAX, which no file can match
+        let expected: usize = nb_rows
+            .checked_mul(nb_cols)
+            .and_then(|len| len.checked_mul(std::mem::size_of::<T>()))
+            .unwrap_or(usize::MAX);
+
+        if mmap.len() != expected {
+            return Err(MapError::SizeMismatch {
+                expected,
+                actual: mmap.len(),
+            });
+        }
+
+        let alignment: usize = std::mem::align_of::<T>();
+
+        if expected > 0 && !(mmap.as_ptr() as usize).is_multiple_of(alignment) {
+            return Err(MapError::Misaligned { alignment });
+        }
+
+        let accessor: Accessor = match order {
+            StorageOrder::RowMajor => Accessor::new(nb_cols, 1),
+            StorageOrder::ColumnMajor => Accessor::new(1, nb_rows),
+        };
+
+        return Ok(Self {
+            nb_rows,
+            nb_cols,
+            accessor,
+            mmap,
+            phantom: PhantomData,
+        });
+    }
+
+    /// Get number of rows of matrix
+    pub fn nb_rows(&self) -> usize {
+        return self.nb_rows;
+    }
+
+    /// Get number of columns of matrix
+    pub fn nb_cols(&self) -> usize {
+        return self.nb_cols;
+    }
+
+    /// Get full view of matrix on mapped memory
+    pub fn view(&self) -> View<'_, T> {
+        let len: usize = self.nb_rows * self.nb_cols;
+
+        let data: &[T] = if len == 0 {
+            &[]
+        } else {
+            // SAFETY: open checked without overflow that mapping has exactly len elements of T
+            // and is aligned for T, T is Pod so any bytes are a valid value,
+            // and slice borrows mapping owned by self
+            unsafe { std::slice::from_raw_parts(self.mmap.as_ptr() as *const T, len) }
+        };
+
+        return View::new(self.nb_rows, self.nb_cols, self.accessor, data);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn write_temporary_file(name: &str, values: &[f64]) -> PathBuf {
+        let path: PathBuf =
+            std::env::temp_dir().join(format!("blarus_mapped_{}_{}.bin", name, std::process::id()));
+
+        let bytes: Vec<u8> = values.iter().flat_map(|x| x.to_ne_bytes()).collect();
+        std::fs::write(&path, bytes).unwrap();
+
+        return path;
+    }
+
+    #[test]
+    fn test_mapped_matrix_row_major() {
+        let values: Vec<f64> = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let path: PathBuf = write_temporary_file("row_major", &values);
+
+        let matrix: MappedMatrix<f64> =
+            MappedMatrix::open(&path, 2, 3, StorageOrder::RowMajor).unwrap();
+        let view: View<f64> = matrix.view();
+
+        assert_eq!(matrix.nb_rows(), 2);
+        assert_eq!(matrix.nb_cols(), 3);
+        assert_eq!(view[(0, 2)], 3.0);
+        assert_eq!(view[(1, 0)], 4.0);
+
+        let row_sums: Vec<f64> = view
+            .rows()
+            .map(|row| row.iter().copied().sum::<f64>())
+            .collect();
+        assert_eq!(row_sums, vec![6.0, 15.0]);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_mapped_matrix_column_major() {
+        let values: Vec<f64> = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let path: PathBuf = write_temporary_file("column_major", &values);
+
+        let matrix: MappedMatrix<f64> =
+            MappedMatrix::open(&path, 2, 3, StorageOrder::ColumnMajor).unwrap();
+        let view: View<f64> = matrix.view();
+
+        assert_eq!(view[(0, 1)], 3.0);
+        assert_eq!(view[(1, 2)], 6.0);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_mapped_matrix_errors() {
+        let values: Vec<f64> = vec![1.0, 2.0, 3.0];
+        let path: PathBuf = write_temporary_file("errors", &values);
+
+        match MappedMatrix::<f64>::open(&path, 2, 2, StorageOrder::RowMajor) {
+            Err(MapError::SizeMismatch { expected, actual }) => {
+                assert_eq!(expected, 32);
+                assert_eq!(actual, 24);
+            }
+            _ => unreachable!(),
+        }
+
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(matches!(
+            MappedMatrix::<f64>::open(&path, 1, 3, StorageOrder::RowMajor),
+            Err(MapError::Io(_))
+        ));
+
+        // Size of 2^61 + 1 elements of 8 bytes would wrap around to the size of file
+        let path: PathBuf = write_temporary_file("overflow", &[1.0]);
+        let nb_rows: usize = (1 << (usize::BITS - 3)) + 1;
+
+        match MappedMatrix::<f64>::open(&path, nb_rows, 1, StorageOrder::RowMajor) {
+            Err(MapError::SizeMismatch { expected, actual }) => {
+                assert_eq!(expected, usize::MAX);
+                assert_eq!(actual, 8);
+            }
+            _ => unreachable!(),
+        }
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -1,6 +1,15 @@
-use std::ops::{Index, IndexMut};
-
-use super::view::{Accessor, View, ViewMut};
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::mem::{ManuallyDrop, MaybeUninit};
+use std::ops::{Index, IndexMut, Mul};
+use std::{ptr, slice, vec};
+
+use super::error::{Block, ShapeError};
+use super::num::{Float, One, Zero};
+use super::sorting::{apply_permutation, compare_nan_last};
+use super::view::{Accessor, ColIter, Layout, RowIter, StorageOrder, View, ViewIter, ViewMut};
 
 /// Matrix
 /// This structure contains number of rows and number of columns of matrix, an accessor
@@ -9,6 +18,7 @@ pub struct Matrix<T> {
     nb_rows: usize,
     nb_cols: usize,
     accessor: Accessor,
+    storage_order: StorageOrder,
     data: Vec<T>,
 }
 
@@ -25,6 +35,7 @@ where
             nb_rows,
             nb_cols,
             accessor: Accessor::new(nb_cols, 1),
+            storage_order: StorageOrder::RowMajor,
             data,
         };
     }
@@ -38,10 +49,66 @@ where
             nb_rows,
             nb_cols,
             accessor: Accessor::new(1, nb_rows),
+            storage_order: StorageOrder::ColumnMajor,
+            data,
+        };
+    }
+}
+
+impl<T> Matrix<MaybeUninit<T>> {
+    /// Create a row-major matrix of uninitialized elements from number of rows and columns of matrix
+    /// Allocation is not followed by a fill, elements must be written before calling assume_init
+    pub fn new_uninit_row_major(nb_rows: usize, nb_cols: usize) -> Self {
+        let mut data: Vec<MaybeUninit<T>> = Vec::new();
+        data.resize_with(nb_rows * nb_cols, MaybeUninit::uninit);
+
+        return Self {
+            nb_rows,
+            nb_cols,
+            accessor: Accessor::new(nb_cols, 1),
+            storage_order: StorageOrder::RowMajor,
+            data,
+        };
+    }
+
+    /// Create a column-major matrix of uninitialized elements from number of rows and columns of matrix
+    /// Allocation is not followed by a fill, elements must be written before calling assume_init
+    pub fn new_uninit_column_major(nb_rows: usize, nb_cols: usize) -> Self {
+        let mut data: Vec<MaybeUninit<T>> = Vec::new();
+        data.resize_with(nb_rows * nb_cols, MaybeUninit::uninit);
+
+        return Self {
+            nb_rows,
+            nb_cols,
+            accessor: Accessor::new(1, nb_rows),
+            storage_order: StorageOrder::ColumnMajor,
+            data,
+        };
+    }
+
+    /// Convert matrix of written elements to matrix of initialized elements, without copy
+    ///
+    /// # Safety
+    /// Every element of matrix must have been initialized
+    pub unsafe fn assume_init(self) -> Matrix<T> {
+        let mut data: ManuallyDrop<Vec<MaybeUninit<T>>> = ManuallyDrop::new(self.data);
+
+        // SAFETY: MaybeUninit<T> has same layout as T and caller guarantees all elements are initialized
+        let data: Vec<T> = unsafe {
+            Vec::from_raw_parts(data.as_mut_ptr() as *mut T, data.len(), data.capacity())
+        };
+
+        return Matrix {
+            nb_rows: self.nb_rows,
+            nb_cols: self.nb_cols,
+            accessor: self.accessor,
+            storage_order: self.storage_order,
             data,
         };
     }
+}
 
+impl<T> Matrix<T> {
     /// Get number of rows
     pub fn nb_rows(&self) -> usize {
         return self.nb_rows;
@@ -51,225 +118,2357 @@ where
     pub fn nb_cols(&self) -> usize {
         return self.nb_cols;
     }
-}
 
-/// View parameters
-/// This structure contains this indexes of first element of view
-/// and number of rows and number of colunm that we want
-pub struct ViewParameters {
-    start_row: usize,
-    start_col: usize,
-    nb_rows: usize,
-    nb_cols: usize,
-}
+    /// Create a row-major matrix from number of rows and columns of matrix
+    /// where element at (i, j) position is given by f(i, j)
+    pub fn from_fn_row_major<F>(nb_rows: usize, nb_cols: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        let mut data: Vec<T> = Vec::with_capacity(nb_rows * nb_cols);
+
+        for row_id in 0..nb_rows {
+            for col_id in 0..nb_cols {
+                data.push(f(row_id, col_id));
+            }
+        }
 
-impl ViewParameters {
-    pub fn new(start_row: usize, start_col: usize, nb_rows: usize, nb_cols: usize) -> Self {
-        return ViewParameters {
-            start_row,
-            start_col,
+        return Self {
             nb_rows,
             nb_cols,
+            accessor: Accessor::new(nb_cols, 1),
+            storage_order: StorageOrder::RowMajor,
+            data,
         };
     }
-}
 
-impl<'a, T> Matrix<T> {
-    /// Get full view of matrix
-    pub fn full_view(&'a self) -> View<'a, T> {
-        return View::new(
-            self.nb_rows,
-            self.nb_cols,
-            self.accessor,
-            self.data.as_slice(),
-        );
+    /// Create a column-major matrix from number of rows and columns of matrix
+    /// where element at (i, j) position is given by f(i, j)
+    pub fn from_fn_column_major<F>(nb_rows: usize, nb_cols: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        let mut data: Vec<T> = Vec::with_capacity(nb_rows * nb_cols);
+
+        for col_id in 0..nb_cols {
+            for row_id in 0..nb_rows {
+                data.push(f(row_id, col_id));
+            }
+        }
+
+        return Self {
+            nb_rows,
+            nb_cols,
+            accessor: Accessor::new(1, nb_rows),
+            storage_order: StorageOrder::ColumnMajor,
+            data,
+        };
     }
 
-    /// Get full mutable view of matrix
-    pub fn full_view_mut(&'a mut self) -> ViewMut<'a, T> {
-        return ViewMut::new(
-            self.nb_rows,
-            self.nb_cols,
-            self.accessor,
-            self.data.as_mut_slice(),
-        );
+    /// Create a row-major matrix from number of rows and columns of matrix
+    /// and a vector containing matrix data in row-major order
+    pub fn from_vec_row_major(
+        nb_rows: usize,
+        nb_cols: usize,
+        data: Vec<T>,
+    ) -> Result<Self, ShapeError> {
+        check_length(nb_rows, nb_cols, &data)?;
+
+        return Ok(Self {
+            nb_rows,
+            nb_cols,
+            accessor: Accessor::new(nb_cols, 1),
+            storage_order: StorageOrder::RowMajor,
+            data,
+        });
     }
 
-    /// Get view on part of matrix
-    pub fn view(&'a self, params: ViewParameters) -> View<'a, T> {
-        return View::new(
-            params.nb_rows,
-            params.nb_cols,
-            Accessor::new_with_offset(
-                self.accessor.stride_row,
-                self.accessor.stride_col,
-                params.start_row,
-                params.start_col,
-            ),
-            self.data.as_slice(),
-        );
+    /// Create a column-major matrix from number of rows and columns of matrix
+    /// and a vector containing matrix data in column-major order
+    pub fn from_vec_column_major(
+        nb_rows: usize,
+        nb_cols: usize,
+        data: Vec<T>,
+    ) -> Result<Self, ShapeError> {
+        check_length(nb_rows, nb_cols, &data)?;
+
+        return Ok(Self {
+            nb_rows,
+            nb_cols,
+            accessor: Accessor::new(1, nb_rows),
+            storage_order: StorageOrder::ColumnMajor,
+            data,
+        });
     }
 
-    /// Get mutable view on part of matrix
-    pub fn view_mut(&'a mut self, params: ViewParameters) -> ViewMut<'a, T> {
-        return ViewMut::new(
-            params.nb_rows,
-            params.nb_cols,
-            Accessor::new_with_offset(
-                self.accessor.stride_row,
-                self.accessor.stride_col,
-                params.start_row,
-                params.start_col,
-            ),
-            self.data.as_mut_slice(),
-        );
+    /// Create a matrix from number of rows and columns of matrix, an accessor and a vector,
+    /// without copy. The accessor can have any strides and offset but it must address
+    /// only elements inside the vector, and memory locations must not overflow usize
+    pub fn from_raw_parts(
+        nb_rows: usize,
+        nb_cols: usize,
+        accessor: Accessor,
+        data: Vec<T>,
+    ) -> Result<Self, ShapeError> {
+        let too_many_elements: ShapeError = ShapeError::TooManyElements { nb_rows, nb_cols };
+        nb_rows
+            .checked_mul(nb_cols)
+            .ok_or(too_many_elements.clone())?;
+
+        if nb_rows > 0 && nb_cols > 0 {
+            let last_index: usize = accessor
+                .checked_index(nb_rows - 1, nb_cols - 1)
+                .ok_or(too_many_elements)?;
+
+            if last_index >= data.len() {
+                return Err(ShapeError::LengthMismatch {
+                    expected: last_index + 1,
+                    actual: data.len(),
+                });
+            }
+        }
+
+        let storage_order: StorageOrder = if accessor.stride_col <= accessor.stride_row {
+            StorageOrder::RowMajor
+        } else {
+            StorageOrder::ColumnMajor
+        };
+
+        return Ok(Self {
+            nb_rows,
+            nb_cols,
+            accessor,
+            storage_order,
+            data,
+        });
     }
-}
 
-impl<T> Index<(usize, usize)> for Matrix<T> {
-    type Output = T;
+    /// Release number of rows, number of columns, accessor and vector of matrix, without copy
+    pub fn into_raw_parts(self) -> (usize, usize, Accessor, Vec<T>) {
+        return (self.nb_rows, self.nb_cols, self.accessor, self.data);
+    }
 
-    /// This allows to read the matrix element at (index of row, index of column) position
-    /// like this let element: f32 = matrix[(0, 2)];
-    fn index(&self, index: (usize, usize)) -> &Self::Output {
-        let id: usize = self.accessor.index(index.0, index.1);
-        return self.data.index(id);
+    /// Create a row-major matrix from number of rows and columns of matrix
+    /// and an iterator giving elements in row-major order.
+    /// Exactly nb_rows * nb_cols elements are consumed from iterator
+    pub fn from_row_iterator<I>(nb_rows: usize, nb_cols: usize, iter: I) -> Result<Self, ShapeError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let data: Vec<T> = iter.into_iter().take(nb_rows * nb_cols).collect();
+        return Self::from_vec_row_major(nb_rows, nb_cols, data);
     }
-}
 
-impl<T> IndexMut<(usize, usize)> for Matrix<T> {
-    /// This allows to write an value in matrix at (index of row, index of column) position
-    /// like this matrix[(0, 2)] = 3.1415;
-    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
-        let id: usize = self.accessor.index(index.0, index.1);
-        return self.data.index_mut(id);
+    /// Create a column-major matrix from number of rows and columns of matrix
+    /// and an iterator giving elements in column-major order.
+    /// Exactly nb_rows * nb_cols elements are consumed from iterator
+    pub fn from_column_iterator<I>(
+        nb_rows: usize,
+        nb_cols: usize,
+        iter: I,
+    ) -> Result<Self, ShapeError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let data: Vec<T> = iter.into_iter().take(nb_rows * nb_cols).collect();
+        return Self::from_vec_column_major(nb_rows, nb_cols, data);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Create a row-major matrix from an iterator of rows, each row being itself an iterator
+    /// Number of columns is given by the first row, so all rows must have this length
+    pub fn from_row_iters<R, I>(iters: I) -> Result<Self, ShapeError>
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+    {
+        let mut data: Vec<T> = Vec::new();
+        let mut nb_rows: usize = 0;
+        let mut nb_cols: usize = 0;
+
+        for row in iters {
+            let row_start: usize = data.len();
+            data.extend(row);
+            let row_len: usize = data.len() - row_start;
+
+            if nb_rows == 0 {
+                nb_cols = row_len;
+            } else if row_len != nb_cols {
+                return Err(ShapeError::RaggedRow {
+                    row_id: nb_rows,
+                    expected: nb_cols,
+                    actual: row_len,
+                });
+            }
+
+            nb_rows += 1;
+        }
 
-    #[test]
-    fn test_matrix_new_row_major() {
-        let nb_rows: usize = 3;
-        let nb_cols: usize = 4;
+        if nb_rows == 0 || nb_cols == 0 {
+            return Err(ShapeError::Empty);
+        }
 
-        let matrix: Matrix<i32> = Matrix::new_row_major(nb_rows, nb_cols);
+        return Ok(Self {
+            nb_rows,
+            nb_cols,
+            accessor: Accessor::new(nb_cols, 1),
+            storage_order: StorageOrder::RowMajor,
+            data,
+        });
+    }
+}
 
-        assert_eq!(matrix.nb_rows, nb_rows);
-        assert_eq!(matrix.nb_cols, nb_cols);
-        assert_eq!(matrix.data.len(), nb_rows * nb_cols);
+impl<T> Matrix<T> {
+    /// Get storage order of matrix
+    /// This is the order given at construction, even when matrix has one row or one column
+    pub fn storage_order(&self) -> StorageOrder {
+        return self.storage_order;
     }
 
-    #[test]
-    fn test_matrix_new_column_major() {
-        let nb_rows: usize = 4;
-        let nb_cols: usize = 3;
+    /// Check that matrix is contiguous without offset and that it has
+    /// the number of elements of a matrix with given dimensions
+    fn check_reshape(&self, nb_rows: usize, nb_cols: usize) -> Result<(), ShapeError> {
+        if self.full_view().layout() != Layout::Contiguous
+            || self.accessor.index(0, 0) != 0
+            || self.data.len() != self.nb_rows * self.nb_cols
+        {
+            return Err(ShapeError::NotContiguous);
+        }
 
-        let matrix: Matrix<i32> = Matrix::new_column_major(nb_rows, nb_cols);
+        if nb_rows * nb_cols != self.nb_rows * self.nb_cols {
+            return Err(ShapeError::LengthMismatch {
+                expected: self.nb_rows * self.nb_cols,
+                actual: nb_rows * nb_cols,
+            });
+        }
 
-        assert_eq!(matrix.nb_rows, nb_rows);
-        assert_eq!(matrix.nb_cols, nb_cols);
-        assert_eq!(matrix.data.len(), nb_rows * nb_cols);
+        return Ok(());
     }
 
-    #[test]
-    fn test_matrix_dimensions_access() {
-        let nb_rows: usize = 5;
-        let nb_cols: usize = 3;
+    /// Reshape contiguous matrix without offset to given dimensions, without copy
+    /// Data is read in its current storage order as a matrix with new dimensions.
+    /// Strided or offset matrix is rejected since reshaping it would reorder elements
+    pub fn reshape(&mut self, nb_rows: usize, nb_cols: usize) -> Result<(), ShapeError> {
+        self.check_reshape(nb_rows, nb_cols)?;
 
-        let matrix: Matrix<i32> = Matrix::new_row_major(nb_rows, nb_cols);
+        self.nb_rows = nb_rows;
+        self.nb_cols = nb_cols;
+        self.accessor = storage_accessor(self.storage_order, nb_rows, nb_cols);
 
-        assert_eq!(matrix.nb_rows(), nb_rows);
-        assert_eq!(matrix.nb_cols(), nb_cols);
+        return Ok(());
     }
 
-    #[test]
-    fn test_matrix_row_major_data_access() {
-        let nb_rows: usize = 3;
-        let nb_cols: usize = 3;
+    /// Move row at given index to the first position,
+    /// rows before it are shifted down by one position
+    pub fn move_row_to_front(&mut self, row_id: usize) -> Result<(), ShapeError> {
+        if row_id >= self.nb_rows {
+            return Err(ShapeError::RowOutOfBounds {
+                row_id,
+                nb_rows: self.nb_rows,
+            });
+        }
 
-        let mut matrix: Matrix<i32> = Matrix::new_row_major(nb_rows, nb_cols);
+        for current_row in (1..=row_id).rev() {
+            for col_id in 0..self.nb_cols {
+                self.data.swap(
+                    self.accessor.index(current_row, col_id),
+                    self.accessor.index(current_row - 1, col_id),
+                );
+            }
+        }
 
-        let data_ref: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
-        matrix.data = data_ref.clone();
+        return Ok(());
+    }
 
-        assert_eq!(matrix[(0, 0)], data_ref[0]);
-        assert_eq!(matrix[(0, 1)], data_ref[1]);
-        assert_eq!(matrix[(0, 2)], data_ref[2]);
-        assert_eq!(matrix[(1, 0)], data_ref[3]);
-        assert_eq!(matrix[(1, 1)], data_ref[4]);
-        assert_eq!(matrix[(1, 2)], data_ref[5]);
-        assert_eq!(matrix[(2, 0)], data_ref[6]);
-        assert_eq!(matrix[(2, 1)], data_ref[7]);
-        assert_eq!(matrix[(2, 2)], data_ref[8]);
+    /// Move column at given index to the first position,
+    /// columns before it are shifted right by one position
+    pub fn move_col_to_front(&mut self, col_id: usize) -> Result<(), ShapeError> {
+        if col_id >= self.nb_cols {
+            return Err(ShapeError::ColumnOutOfBounds {
+                col_id,
+                nb_cols: self.nb_cols,
+            });
+        }
 
-        matrix[(2, 1)] = 43;
-        assert_eq!(matrix[(2, 1)], 43);
+        for current_col in (1..=col_id).rev() {
+            for row_id in 0..self.nb_rows {
+                self.data.swap(
+                    self.accessor.index(row_id, current_col),
+                    self.accessor.index(row_id, current_col - 1),
+                );
+            }
+        }
+
+        return Ok(());
     }
 
-    #[test]
-    fn test_matrix_column_major_data_access() {
-        let nb_rows: usize = 3;
-        let nb_cols: usize = 3;
+    /// Swap two blocks of count consecutive columns starting at a_start and b_start
+    /// Blocks must not overlap and must be inside matrix
+    pub fn swap_col_blocks(
+        &mut self,
+        a_start: usize,
+        b_start: usize,
+        count: usize,
+    ) -> Result<(), ShapeError> {
+        for start in [a_start, b_start] {
+            if count > 0
+                && start
+                    .checked_add(count)
+                    .is_none_or(|end| end > self.nb_cols)
+            {
+                return Err(ShapeError::ColumnOutOfBounds {
+                    col_id: start.saturating_add(count - 1),
+                    nb_cols: self.nb_cols,
+                });
+            }
+        }
 
-        let mut matrix: Matrix<i32> = Matrix::new_column_major(nb_rows, nb_cols);
+        // Check that column index is before the end of block starting at given column
+        let before_end = |col_id: usize, start: usize| -> bool {
+            return start.checked_add(count).is_none_or(|end| col_id < end);
+        };
 
-        let data_ref: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
-        matrix.data = data_ref.clone();
+        if count > 0 && before_end(a_start, b_start) && before_end(b_start, a_start) {
+            return Err(ShapeError::OverlappingBlocks {
+                first: a_start,
+                second: b_start,
+                count,
+            });
+        }
 
-        assert_eq!(matrix[(0, 0)], data_ref[0]);
-        assert_eq!(matrix[(1, 0)], data_ref[1]);
-        assert_eq!(matrix[(2, 0)], data_ref[2]);
-        assert_eq!(matrix[(0, 1)], data_ref[3]);
-        assert_eq!(matrix[(1, 1)], data_ref[4]);
-        assert_eq!(matrix[(2, 1)], data_ref[5]);
-        assert_eq!(matrix[(0, 2)], data_ref[6]);
-        assert_eq!(matrix[(1, 2)], data_ref[7]);
-        assert_eq!(matrix[(2, 2)], data_ref[8]);
+        for offset in 0..count {
+            for row_id in 0..self.nb_rows {
+                self.data.swap(
+                    self.accessor.index(row_id, a_start + offset),
+                    self.accessor.index(row_id, b_start + offset),
+                );
+            }
+        }
 
-        matrix[(2, 1)] = 43;
-        assert_eq!(matrix[(2, 1)], 43);
+        return Ok(());
     }
+}
 
-    #[test]
-    fn test_matrix_row_major_full_view() {
-        let nb_rows: usize = 3;
-        let nb_cols: usize = 3;
+impl<T> Matrix<T> {
+    /// Create a new matrix with same dimensions where each element is f applied on
+    /// the element at same position. Only elements of matrix are visited, through its accessor,
+    /// and the result is a compact matrix with the same storage order
+    pub fn map<U, F>(&self, f: F) -> Matrix<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        return self.full_view().map(f);
+    }
+}
 
-        let mut matrix: Matrix<i32> = Matrix::new_row_major(nb_rows, nb_cols);
+impl<T> Matrix<T>
+where
+    T: Clone,
+{
+    /// Set all elements of matrix to given value
+    pub fn fill(&mut self, value: T) {
+        self.full_view_mut().fill(value);
+    }
 
-        let data_ref: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
-        matrix.data = data_ref.clone();
+    /// Create a row-major matrix by copying elements of view, which can be part of another matrix
+    pub fn clone_from_view(view: &View<T>) -> Self {
+        return Self::from_fn_row_major(view.nb_rows(), view.nb_cols(), |row_id, col_id| {
+            return view[(row_id, col_id)].clone();
+        });
+    }
 
-        let view: View<i32> = matrix.full_view();
+    /// Copy matrix into a new row-major matrix with same elements
+    /// A new matrix is created even when matrix is already row-major
+    pub fn to_row_major(&self) -> Self {
+        return Self::from_fn_row_major(self.nb_rows, self.nb_cols, |row_id, col_id| {
+            return self[(row_id, col_id)].clone();
+        });
+    }
 
-        assert_eq!(view[(0, 0)], data_ref[0]);
-        assert_eq!(view[(0, 1)], data_ref[1]);
-        assert_eq!(view[(0, 2)], data_ref[2]);
-        assert_eq!(view[(1, 0)], data_ref[3]);
-        assert_eq!(view[(1, 1)], data_ref[4]);
-        assert_eq!(view[(1, 2)], data_ref[5]);
-        assert_eq!(view[(2, 0)], data_ref[6]);
-        assert_eq!(view[(2, 1)], data_ref[7]);
-        assert_eq!(view[(2, 2)], data_ref[8]);
+    /// Copy matrix into a new column-major matrix with same elements
+    /// A new matrix is created even when matrix is already column-major
+    pub fn to_column_major(&self) -> Self {
+        return Self::from_fn_column_major(self.nb_rows, self.nb_cols, |row_id, col_id| {
+            return self[(row_id, col_id)].clone();
+        });
     }
 
-    #[test]
-    fn test_matrix_column_major_full_view() {
-        let nb_rows: usize = 3;
-        let nb_cols: usize = 3;
+    /// Create a column-major matrix from views with same dimensions by taking first column
+    /// of each view, then second column of each view and so on, as done to convert
+    /// planar channels to interleaved channels
+    pub fn interleave_cols(matrices: &[View<T>]) -> Result<Self, ShapeError> {
+        let first: &View<T> = matrices.first().ok_or(ShapeError::Empty)?;
+        let dimensions: (usize, usize) = (first.nb_rows(), first.nb_cols());
+
+        for matrix in matrices {
+            if (matrix.nb_rows(), matrix.nb_cols()) != dimensions {
+                return Err(ShapeError::DimensionMismatch {
+                    expected: dimensions,
+                    actual: (matrix.nb_rows(), matrix.nb_cols()),
+                });
+            }
+        }
 
-        let mut matrix: Matrix<i32> = Matrix::new_column_major(nb_rows, nb_cols);
+        let nb_matrices: usize = matrices.len();
 
-        let data_ref: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
-        matrix.data = data_ref.clone();
+        return Ok(Self::from_fn_column_major(
+            dimensions.0,
+            dimensions.1 * nb_matrices,
+            |row_id, col_id| {
+                return matrices[col_id % nb_matrices][(row_id, col_id / nb_matrices)].clone();
+            },
+        ));
+    }
 
-        let view: View<i32> = matrix.full_view();
+    /// Copy transpose of matrix into a new matrix with same storage order
+    /// Element at (j, i) of the result is element at (i, j) of matrix
+    pub fn transpose(&self) -> Self {
+        let element = |row_id: usize, col_id: usize| {
+            return self[(col_id, row_id)].clone();
+        };
 
-        assert_eq!(view[(0, 0)], data_ref[0]);
-        assert_eq!(view[(1, 0)], data_ref[1]);
+        return match self.storage_order {
+            StorageOrder::RowMajor => Self::from_fn_row_major(self.nb_cols, self.nb_rows, element),
+            StorageOrder::ColumnMajor => {
+                Self::from_fn_column_major(self.nb_cols, self.nb_rows, element)
+            }
+        };
+    }
+
+    /// Create a row-major matrix from number of rows and columns of matrix
+    /// where all elements are equal to given value
+    pub fn filled(nb_rows: usize, nb_cols: usize, value: T) -> Self {
+        return Self {
+            nb_rows,
+            nb_cols,
+            accessor: Accessor::new(nb_cols, 1),
+            storage_order: StorageOrder::RowMajor,
+            data: vec![value; nb_rows * nb_cols],
+        };
+    }
+
+    /// Create a row-major matrix from number of rows and columns of matrix
+    /// where all elements are equal to zero
+    pub fn zeros(nb_rows: usize, nb_cols: usize) -> Self
+    where
+        T: Zero,
+    {
+        return Self::filled(nb_rows, nb_cols, T::zero());
+    }
+
+    /// Create a row-major matrix from number of rows and columns of matrix
+    /// where all elements are equal to one
+    pub fn ones(nb_rows: usize, nb_cols: usize) -> Self
+    where
+        T: One,
+    {
+        return Self::filled(nb_rows, nb_cols, T::one());
+    }
+
+    /// Create a row-major identity matrix of size n,
+    /// with ones on the main diagonal and zeros elsewhere
+    pub fn identity(n: usize) -> Self
+    where
+        T: Zero + One,
+    {
+        let mut matrix: Self = Self::zeros(n, n);
+        let mut view: ViewMut<T> = matrix.full_view_mut();
+
+        for index in 0..n {
+            view[(index, index)] = T::one();
+        }
+
+        return matrix;
+    }
+
+    /// Create a row-major one-hot encoding matrix with one row per label and nb_classes columns,
+    /// where row i has one in column labels[i] and zeros elsewhere
+    pub fn one_hot(labels: &[usize], nb_classes: usize) -> Result<Self, ShapeError>
+    where
+        T: Zero + One,
+    {
+        if let Some(&label) = labels.iter().find(|&&label| label >= nb_classes) {
+            return Err(ShapeError::ColumnOutOfBounds {
+                col_id: label,
+                nb_cols: nb_classes,
+            });
+        }
+
+        let mut matrix: Self = Self::zeros(labels.len(), nb_classes);
+
+        for (row_id, &label) in labels.iter().enumerate() {
+            matrix[(row_id, label)] = T::one();
+        }
+
+        return Ok(matrix);
+    }
+
+    /// Create a square column-major matrix of size n from its kl subdiagonals and ku superdiagonals
+    /// given in LAPACK band storage, that is a column-major matrix with kl + ku + 1 rows and n columns
+    /// where element (i, j) is stored at row ku + i - j of column j. Elements outside the band
+    /// are set to default value
+    pub fn from_banded(n: usize, kl: usize, ku: usize, data: &[T]) -> Result<Self, ShapeError>
+    where
+        T: Default,
+    {
+        let nb_band_rows: usize = kl + ku + 1;
+        check_length(nb_band_rows, n, data)?;
+
+        return Ok(Self::from_fn_column_major(n, n, |row_id, col_id| {
+            if row_id + ku >= col_id && row_id <= col_id + kl {
+                return data[col_id * nb_band_rows + ku + row_id - col_id].clone();
+            } else {
+                return T::default();
+            }
+        }));
+    }
+
+    /// Create a row-major matrix from slices of its rows
+    /// All rows must have the same length
+    pub fn from_rows(rows: &[&[T]]) -> Result<Self, ShapeError> {
+        return Self::from_row_iters(rows.iter().map(|row| row.iter().cloned()));
+    }
+
+    /// Create a column-major matrix from slices of its columns
+    /// All columns must have the same length
+    pub fn from_cols(cols: &[&[T]]) -> Result<Self, ShapeError> {
+        // Columns are read as rows of the transposed matrix stored in row-major order,
+        // which is the same memory layout as the column-major matrix
+        let transposed: Self =
+            match Self::from_row_iters(cols.iter().map(|col| col.iter().cloned())) {
+                Ok(matrix) => matrix,
+                Err(ShapeError::RaggedRow {
+                    row_id,
+                    expected,
+                    actual,
+                }) => {
+                    return Err(ShapeError::RaggedColumn {
+                        col_id: row_id,
+                        expected,
+                        actual,
+                    })
+                }
+                Err(error) => return Err(error),
+            };
+
+        return Ok(Self {
+            nb_rows: transposed.nb_cols,
+            nb_cols: transposed.nb_rows,
+            accessor: Accessor::new(1, transposed.nb_cols),
+            storage_order: StorageOrder::ColumnMajor,
+            data: transposed.data,
+        });
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Clone + PartialEq,
+{
+    /// Create a row-major Toeplitz matrix, where each descending diagonal is constant,
+    /// from its first column and its first row which must start with the same element
+    pub fn toeplitz(first_col: &[T], first_row: &[T]) -> Result<Self, ShapeError> {
+        if first_col.is_empty() || first_row.is_empty() {
+            return Err(ShapeError::Empty);
+        }
+
+        if first_col[0] != first_row[0] {
+            return Err(ShapeError::CornerMismatch);
+        }
+
+        return Ok(Self::from_fn_row_major(
+            first_col.len(),
+            first_row.len(),
+            |row_id, col_id| {
+                if row_id >= col_id {
+                    return first_col[row_id - col_id].clone();
+                } else {
+                    return first_row[col_id - row_id].clone();
+                }
+            },
+        ));
+    }
+
+    /// Create a row-major square circulant matrix from its first column,
+    /// each column being the previous one rotated down by one element
+    pub fn circulant(first_col: &[T]) -> Result<Self, ShapeError> {
+        if first_col.is_empty() {
+            return Err(ShapeError::Empty);
+        }
+
+        let n: usize = first_col.len();
+
+        return Ok(Self::from_fn_row_major(n, n, |row_id, col_id| {
+            return first_col[(n + row_id - col_id) % n].clone();
+        }));
+    }
+}
+
+impl Matrix<f64> {
+    /// Create a row-major matrix where each row is a linear ramp of nb_cols points
+    /// from start to end included. With one column, the single point is start
+    pub fn linspace_rows(start: f64, end: f64, nb_rows: usize, nb_cols: usize) -> Self {
+        return Self::from_fn_row_major(nb_rows, nb_cols, |_, col_id| {
+            return linspace_point(start, end, nb_cols, col_id);
+        });
+    }
+
+    /// Create a column-major matrix where each column is a linear ramp of nb_rows points
+    /// from start to end included. With one row, the single point is start
+    pub fn linspace_cols(start: f64, end: f64, nb_rows: usize, nb_cols: usize) -> Self {
+        return Self::from_fn_column_major(nb_rows, nb_cols, |row_id, _| {
+            return linspace_point(start, end, nb_rows, row_id);
+        });
+    }
+}
+
+/// Compute point at given index of linear ramp of nb_points points from start to end included
+fn linspace_point(start: f64, end: f64, nb_points: usize, index: usize) -> f64 {
+    if nb_points <= 1 {
+        return start;
+    }
+
+    if index == nb_points - 1 {
+        return end;
+    }
+
+    return start + (end - start) * (index as f64) / ((nb_points - 1) as f64);
+}
+
+/// Size of square blocks of elements moved together when storage order of matrix is changed
+const STORAGE_BLOCK_SIZE: usize = 32;
+
+impl<T> Matrix<T>
+where
+    T: Default,
+{
+    /// Convert matrix to given storage order by reordering its data
+    /// Matrix already in given order is returned unchanged without copy.
+    /// Elements are moved by square blocks so reads and writes both stay in cache
+    pub fn into_storage_order(mut self, order: StorageOrder) -> Self {
+        if self.storage_order() == order {
+            return self;
+        }
+
+        let accessor: Accessor = storage_accessor(order, self.nb_rows, self.nb_cols);
+
+        let mut data: Vec<T> = Vec::new();
+        data.resize_with(self.nb_rows * self.nb_cols, Default::default);
+
+        for block_row in (0..self.nb_rows).step_by(STORAGE_BLOCK_SIZE) {
+            for block_col in (0..self.nb_cols).step_by(STORAGE_BLOCK_SIZE) {
+                let last_row: usize = (block_row + STORAGE_BLOCK_SIZE).min(self.nb_rows);
+                let last_col: usize = (block_col + STORAGE_BLOCK_SIZE).min(self.nb_cols);
+
+                for row_id in block_row..last_row {
+                    for col_id in block_col..last_col {
+                        data[accessor.index(row_id, col_id)] =
+                            std::mem::take(&mut self.data[self.accessor.index(row_id, col_id)]);
+                    }
+                }
+            }
+        }
+
+        return Self {
+            nb_rows: self.nb_rows,
+            nb_cols: self.nb_cols,
+            accessor,
+            storage_order: order,
+            data,
+        };
+    }
+
+    /// Reshape contiguous matrix without offset to given dimensions and storage order
+    /// Data is read in its current storage order as a matrix with new dimensions,
+    /// then it is reordered if given storage order is not the current one
+    pub fn reshape_to(
+        &mut self,
+        nb_rows: usize,
+        nb_cols: usize,
+        order: StorageOrder,
+    ) -> Result<(), ShapeError> {
+        self.check_reshape(nb_rows, nb_cols)?;
+
+        let reshaped: Self = Self {
+            nb_rows,
+            nb_cols,
+            accessor: storage_accessor(self.storage_order, nb_rows, nb_cols),
+            storage_order: self.storage_order,
+            data: std::mem::take(&mut self.data),
+        };
+
+        *self = reshaped.into_storage_order(order);
+
+        return Ok(());
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Clone + Default,
+{
+    /// Create a row-major matrix from four blocks [[top_left, top_right], [bottom_left, bottom_right]]
+    /// Blocks on same row of blocks must have same number of rows and blocks
+    /// on same column of blocks must have same number of columns
+    pub fn from_blocks(
+        top_left: &View<T>,
+        top_right: &View<T>,
+        bottom_left: &View<T>,
+        bottom_right: &View<T>,
+    ) -> Result<Self, ShapeError> {
+        check_block_rows(Block::TopLeft, top_left, Block::TopRight, top_right)?;
+        check_block_rows(
+            Block::BottomLeft,
+            bottom_left,
+            Block::BottomRight,
+            bottom_right,
+        )?;
+        check_block_cols(Block::TopLeft, top_left, Block::BottomLeft, bottom_left)?;
+        check_block_cols(Block::TopRight, top_right, Block::BottomRight, bottom_right)?;
+
+        let nb_top_rows: usize = top_left.nb_rows();
+        let nb_left_cols: usize = top_left.nb_cols();
+
+        let mut matrix: Self = Self::new_row_major(
+            nb_top_rows + bottom_left.nb_rows(),
+            nb_left_cols + top_right.nb_cols(),
+        );
+
+        let blocks: [(&View<T>, usize, usize); 4] = [
+            (top_left, 0, 0),
+            (top_right, 0, nb_left_cols),
+            (bottom_left, nb_top_rows, 0),
+            (bottom_right, nb_top_rows, nb_left_cols),
+        ];
+
+        for (block, start_row, start_col) in blocks {
+            let mut destination: ViewMut<T> = matrix.view_mut(ViewParameters::new(
+                start_row,
+                start_col,
+                block.nb_rows(),
+                block.nb_cols(),
+            ));
+
+            for row_id in 0..block.nb_rows() {
+                for col_id in 0..block.nb_cols() {
+                    destination[(row_id, col_id)] = block[(row_id, col_id)].clone();
+                }
+            }
+        }
+
+        return Ok(matrix);
+    }
+}
+
+/// Check that two blocks on same row of blocks have same number of rows
+fn check_block_rows<T>(
+    first: Block,
+    first_view: &View<T>,
+    second: Block,
+    second_view: &View<T>,
+) -> Result<(), ShapeError> {
+    if first_view.nb_rows() != second_view.nb_rows() {
+        return Err(ShapeError::BlockRowMismatch {
+            first,
+            second,
+            expected: first_view.nb_rows(),
+            actual: second_view.nb_rows(),
+        });
+    }
+
+    return Ok(());
+}
+
+/// Check that two blocks on same column of blocks have same number of columns
+fn check_block_cols<T>(
+    first: Block,
+    first_view: &View<T>,
+    second: Block,
+    second_view: &View<T>,
+) -> Result<(), ShapeError> {
+    if first_view.nb_cols() != second_view.nb_cols() {
+        return Err(ShapeError::BlockColumnMismatch {
+            first,
+            second,
+            expected: first_view.nb_cols(),
+            actual: second_view.nb_cols(),
+        });
+    }
+
+    return Ok(());
+}
+
+impl<T> Matrix<T>
+where
+    T: Copy + Mul<Output = T>,
+{
+    /// Multiply each element of matrix by given factor in place, same as matrix *= factor
+    pub fn scale(&mut self, factor: T) {
+        self.full_view_mut().scale(factor);
+    }
+
+    /// Create a row-major Vandermonde matrix with one row per point and degree + 1 columns,
+    /// where element at (i, j) is x[i] to the power j, as design matrix of polynomial regression
+    pub fn vandermonde(x: &[T], degree: usize) -> Self
+    where
+        T: One,
+    {
+        let mut data: Vec<T> = Vec::with_capacity(x.len() * (degree + 1));
+
+        for &point in x {
+            let mut power: T = T::one();
+
+            for _ in 0..=degree {
+                data.push(power);
+                power = power * point;
+            }
+        }
+
+        return Self::from_vec_row_major(x.len(), degree + 1, data)
+            .expect("data has degree + 1 elements per point");
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Copy,
+{
+    /// Copy strictly lower triangle of square matrix into its strictly upper triangle
+    /// so matrix becomes symmetric, when only its lower half was computed
+    pub fn mirror_lower_to_upper(&mut self) -> Result<(), ShapeError> {
+        if self.nb_rows != self.nb_cols {
+            return Err(ShapeError::NotSquare {
+                nb_rows: self.nb_rows,
+                nb_cols: self.nb_cols,
+            });
+        }
+
+        for row_id in 0..self.nb_rows {
+            for col_id in 0..row_id {
+                self[(col_id, row_id)] = self[(row_id, col_id)];
+            }
+        }
+
+        return Ok(());
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: PartialOrd,
+{
+    /// Reorder rows of matrix in ascending order of their values in the key column
+    /// The sort is stable, so rows with equal keys keep their relative order,
+    /// and keys not comparable with themselves, like NaN, are put at the end.
+    /// It returns the applied permutation, that is for each new row position
+    /// the index of the row in the original matrix
+    pub fn sort_rows_by_col(&mut self, key_col: usize) -> Vec<usize> {
+        self.check_col(key_col);
+
+        let mut permutation: Vec<usize> = (0..self.nb_rows).collect();
+
+        permutation.sort_by(|&a, &b| {
+            return compare_nan_last(&self[(a, key_col)], &self[(b, key_col)]);
+        });
+
+        let nb_cols: usize = self.nb_cols;
+        let accessor: Accessor = self.accessor;
+        let data: &mut Vec<T> = &mut self.data;
+
+        apply_permutation(&permutation, |a, b| {
+            for col_id in 0..nb_cols {
+                data.swap(accessor.index(a, col_id), accessor.index(b, col_id));
+            }
+        });
+
+        return permutation;
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Clone,
+{
+    /// Get distinct rows of matrix, in order of their first occurrence, as a row-major matrix
+    /// with the mapping from each row of matrix to its row in the matrix of distinct rows.
+    /// Rows are hashed, as slices of data when matrix is contiguous and row-major,
+    /// so the cost is linear in number of elements
+    pub fn unique_rows(&self) -> (Matrix<T>, Vec<usize>)
+    where
+        T: Eq + Hash,
+    {
+        let (unique_ids, mapping): (Vec<usize>, Vec<usize>) =
+            match self.accessor.contiguous_start(self.nb_rows, self.nb_cols) {
+                Some((start, StorageOrder::RowMajor)) => dedup_by_key(self.nb_rows, |row_id| {
+                    let row_start: usize = start + row_id * self.nb_cols;
+                    return &self.data[row_start..row_start + self.nb_cols];
+                }),
+                _ => dedup_by_key(self.nb_rows, |row_id| {
+                    return (0..self.nb_cols)
+                        .map(|col_id| &self[(row_id, col_id)])
+                        .collect::<Vec<&T>>();
+                }),
+            };
+
+        return (self.select_rows(&unique_ids), mapping);
+    }
+
+    /// Get distinct rows of matrix like unique_rows, where two rows are equal
+    /// if the absolute difference between their elements is at most tolerance.
+    /// A row is mapped to the first distinct row which is equal to it.
+    /// Such equality cannot be hashed, so the cost is quadratic in number of rows
+    pub fn unique_rows_with_tolerance(&self, tolerance: T) -> (Matrix<T>, Vec<usize>)
+    where
+        T: Float,
+    {
+        let mut unique_ids: Vec<usize> = Vec::new();
+        let mut mapping: Vec<usize> = Vec::with_capacity(self.nb_rows);
+
+        for row_id in 0..self.nb_rows {
+            let found: Option<usize> = unique_ids.iter().position(|&unique_id| {
+                return (0..self.nb_cols).all(|col_id| {
+                    return (self[(row_id, col_id)] - self[(unique_id, col_id)]).abs() <= tolerance;
+                });
+            });
+
+            match found {
+                Some(position) => mapping.push(position),
+                None => {
+                    mapping.push(unique_ids.len());
+                    unique_ids.push(row_id);
+                }
+            }
+        }
+
+        return (self.select_rows(&unique_ids), mapping);
+    }
+
+    /// Copy rows of matrix with given indexes into a row-major matrix
+    fn select_rows(&self, row_ids: &[usize]) -> Matrix<T> {
+        return Matrix::from_fn_row_major(row_ids.len(), self.nb_cols, |row_id, col_id| {
+            return self[(row_ids[row_id], col_id)].clone();
+        });
+    }
+}
+
+/// Get indexes of first occurrences of distinct keys of nb_rows rows, in order,
+/// with the mapping from each row to the position of its key among distinct ones
+fn dedup_by_key<K, F>(nb_rows: usize, key: F) -> (Vec<usize>, Vec<usize>)
+where
+    K: Eq + Hash,
+    F: Fn(usize) -> K,
+{
+    let mut positions: HashMap<K, usize> = HashMap::new();
+    let mut unique_ids: Vec<usize> = Vec::new();
+    let mut mapping: Vec<usize> = Vec::with_capacity(nb_rows);
+
+    for row_id in 0..nb_rows {
+        let position: usize = *positions.entry(key(row_id)).or_insert_with(|| {
+            unique_ids.push(row_id);
+            return unique_ids.len() - 1;
+        });
+
+        mapping.push(position);
+    }
+
+    return (unique_ids, mapping);
+}
+
+/// Build accessor of contiguous matrix with given storage order and dimensions
+fn storage_accessor(order: StorageOrder, nb_rows: usize, nb_cols: usize) -> Accessor {
+    return match order {
+        StorageOrder::RowMajor => Accessor::new(nb_cols, 1),
+        StorageOrder::ColumnMajor => Accessor::new(1, nb_rows),
+    };
+}
+
+/// Check that data contains exactly the number of elements of a matrix
+/// with nb_rows rows and nb_cols columns
+fn check_length<T>(nb_rows: usize, nb_cols: usize, data: &[T]) -> Result<(), ShapeError> {
+    let expected: usize = nb_rows
+        .checked_mul(nb_cols)
+        .ok_or(ShapeError::TooManyElements { nb_rows, nb_cols })?;
+
+    if data.len() != expected {
+        return Err(ShapeError::LengthMismatch {
+            expected,
+            actual: data.len(),
+        });
+    }
+
+    return Ok(());
+}
+
+/// Get number of elements of a matrix with nb_rows rows and nb_cols columns
+/// after checking that accessor gives memory locations of all of them inside data
+/// Panic if number of elements or a memory location overflows, or if it is out of data
+fn addressable_len<T>(nb_rows: usize, nb_cols: usize, accessor: &Accessor, data: &[T]) -> usize {
+    let len: usize = nb_rows
+        .checked_mul(nb_cols)
+        .expect("number of elements of matrix overflows");
+
+    if len > 0 {
+        let last_index: Option<usize> = accessor.checked_index(nb_rows - 1, nb_cols - 1);
+
+        assert!(
+            last_index.is_some_and(|index| index < data.len()),
+            "elements of matrix are outside of its data"
+        );
+    }
+
+    return len;
+}
+
+/// View parameters
+/// This structure contains this indexes of first element of view
+/// and number of rows and number of colunm that we want
+pub struct ViewParameters {
+    start_row: usize,
+    start_col: usize,
+    nb_rows: usize,
+    nb_cols: usize,
+}
+
+impl ViewParameters {
+    pub fn new(start_row: usize, start_col: usize, nb_rows: usize, nb_cols: usize) -> Self {
+        return ViewParameters {
+            start_row,
+            start_col,
+            nb_rows,
+            nb_cols,
+        };
+    }
+}
+
+impl<'a, T> Matrix<T> {
+    /// Get full view of matrix
+    pub fn full_view(&'a self) -> View<'a, T> {
+        return View::new(
+            self.nb_rows,
+            self.nb_cols,
+            self.accessor,
+            self.data.as_slice(),
+        );
+    }
+
+    /// Get transposed view of matrix sharing same data, without any copy
+    pub fn transpose_view(&'a self) -> View<'a, T> {
+        return self.full_view().transpose();
+    }
+
+    /// Get full mutable view of matrix
+    pub fn full_view_mut(&'a mut self) -> ViewMut<'a, T> {
+        return ViewMut::new(
+            self.nb_rows,
+            self.nb_cols,
+            self.accessor,
+            self.data.as_mut_slice(),
+        );
+    }
+
+    /// Get view on part of matrix
+    pub fn view(&'a self, params: ViewParameters) -> View<'a, T> {
+        return View::new(
+            params.nb_rows,
+            params.nb_cols,
+            Accessor::new_with_offset(
+                self.accessor.stride_row,
+                self.accessor.stride_col,
+                params.start_row,
+                params.start_col,
+            ),
+            self.data.as_slice(),
+        );
+    }
+
+    /// Get mutable view on part of matrix
+    pub fn view_mut(&'a mut self, params: ViewParameters) -> ViewMut<'a, T> {
+        return ViewMut::new(
+            params.nb_rows,
+            params.nb_cols,
+            Accessor::new_with_offset(
+                self.accessor.stride_row,
+                self.accessor.stride_col,
+                params.start_row,
+                params.start_col,
+            ),
+            self.data.as_mut_slice(),
+        );
+    }
+
+    /// Get view on row of matrix at given index
+    pub fn row(&'a self, row_id: usize) -> View<'a, T> {
+        self.check_row(row_id);
+        return self.view(ViewParameters::new(row_id, 0, 1, self.nb_cols));
+    }
+
+    /// Get mutable view on row of matrix at given index
+    pub fn row_mut(&'a mut self, row_id: usize) -> ViewMut<'a, T> {
+        self.check_row(row_id);
+        return self.view_mut(ViewParameters::new(row_id, 0, 1, self.nb_cols));
+    }
+
+    /// Get view on column of matrix at given index
+    pub fn col(&'a self, col_id: usize) -> View<'a, T> {
+        self.check_col(col_id);
+        return self.view(ViewParameters::new(0, col_id, self.nb_rows, 1));
+    }
+
+    /// Get mutable view on column of matrix at given index
+    pub fn col_mut(&'a mut self, col_id: usize) -> ViewMut<'a, T> {
+        self.check_col(col_id);
+        return self.view_mut(ViewParameters::new(0, col_id, self.nb_rows, 1));
+    }
+
+    /// Split matrix into a top view on rows before given index and a bottom view
+    /// on remaining rows, both sharing data of matrix. It panics if index is greater
+    /// than number of rows
+    pub fn split_at_row(&'a self, row_id: usize) -> (View<'a, T>, View<'a, T>) {
+        self.check_split_row(row_id);
+
+        let top: View<'a, T> = View::new(row_id, self.nb_cols, self.accessor, self.data.as_slice());
+        let bottom: View<'a, T> = View::new(
+            self.nb_rows - row_id,
+            self.nb_cols,
+            self.accessor.shift(row_id, 0),
+            self.data.as_slice(),
+        );
+
+        return (top, bottom);
+    }
+
+    /// Split matrix into mutable top view on rows before given index and mutable bottom view
+    /// on remaining rows. Data is split into two disjoint slices, so rows must not be
+    /// interleaved in memory, which is the case of a row-major matrix or a column-major matrix
+    /// with one column. It panics if index is greater than number of rows
+    pub fn split_at_row_mut(
+        &'a mut self,
+        row_id: usize,
+    ) -> Result<(ViewMut<'a, T>, ViewMut<'a, T>), ShapeError> {
+        self.check_split_row(row_id);
+
+        if self.nb_rows > 1
+            && self.nb_cols > 1
+            && self.accessor.stride_row <= (self.nb_cols - 1) * self.accessor.stride_col
+        {
+            return Err(ShapeError::InterleavedRows);
+        }
+
+        let split: usize = self.accessor.index(row_id, 0).min(self.data.len());
+        let (top_data, bottom_data): (&'a mut [T], &'a mut [T]) = self.data.split_at_mut(split);
+
+        let top: ViewMut<'a, T> = ViewMut::new(row_id, self.nb_cols, self.accessor, top_data);
+        let bottom: ViewMut<'a, T> = ViewMut::new(
+            self.nb_rows - row_id,
+            self.nb_cols,
+            Accessor::new(self.accessor.stride_row, self.accessor.stride_col),
+            bottom_data,
+        );
+
+        return Ok((top, bottom));
+    }
+
+    /// Split matrix into a left view on columns before given index and a right view
+    /// on remaining columns, both sharing data of matrix. It panics if index is greater
+    /// than number of columns
+    pub fn split_at_col(&'a self, col_id: usize) -> (View<'a, T>, View<'a, T>) {
+        self.check_split_col(col_id);
+
+        let left: View<'a, T> =
+            View::new(self.nb_rows, col_id, self.accessor, self.data.as_slice());
+        let right: View<'a, T> = View::new(
+            self.nb_rows,
+            self.nb_cols - col_id,
+            self.accessor.shift(0, col_id),
+            self.data.as_slice(),
+        );
+
+        return (left, right);
+    }
+
+    /// Split matrix into mutable left view on columns before given index and mutable right view
+    /// on remaining columns. Data is split into two disjoint slices, so columns must not be
+    /// interleaved in memory, which is the case of a column-major matrix or a row-major matrix
+    /// with one row. It panics if index is greater than number of columns
+    pub fn split_at_col_mut(
+        &'a mut self,
+        col_id: usize,
+    ) -> Result<(ViewMut<'a, T>, ViewMut<'a, T>), ShapeError> {
+        self.check_split_col(col_id);
+
+        if self.nb_rows > 1
+            && self.nb_cols > 1
+            && self.accessor.stride_col <= (self.nb_rows - 1) * self.accessor.stride_row
+        {
+            return Err(ShapeError::InterleavedCols);
+        }
+
+        let split: usize = self.accessor.index(0, col_id).min(self.data.len());
+        let (left_data, right_data): (&'a mut [T], &'a mut [T]) = self.data.split_at_mut(split);
+
+        let left: ViewMut<'a, T> = ViewMut::new(self.nb_rows, col_id, self.accessor, left_data);
+        let right: ViewMut<'a, T> = ViewMut::new(
+            self.nb_rows,
+            self.nb_cols - col_id,
+            Accessor::new(self.accessor.stride_row, self.accessor.stride_col),
+            right_data,
+        );
+
+        return Ok((left, right));
+    }
+
+    /// Get view with one row on main diagonal of matrix, its length is the minimum
+    /// of number of rows and number of columns
+    pub fn diagonal(&'a self) -> View<'a, T> {
+        return self.diagonal_offset(0);
+    }
+
+    /// Get mutable view with one row on main diagonal of matrix, its length is the minimum
+    /// of number of rows and number of columns
+    pub fn diagonal_mut(&'a mut self) -> ViewMut<'a, T> {
+        return self.diagonal_offset_mut(0);
+    }
+
+    /// Get view with one row on diagonal of matrix at given offset from main diagonal,
+    /// above it for positive offset and below it for negative offset
+    /// Diagonal outside of matrix gives an empty view
+    pub fn diagonal_offset(&'a self, k: isize) -> View<'a, T> {
+        let (length, accessor): (usize, Accessor) = self.diagonal_accessor(k);
+        return View::new(1, length, accessor, self.data.as_slice());
+    }
+
+    /// Get mutable view with one row on diagonal of matrix at given offset from main diagonal,
+    /// above it for positive offset and below it for negative offset
+    /// Diagonal outside of matrix gives an empty view
+    pub fn diagonal_offset_mut(&'a mut self, k: isize) -> ViewMut<'a, T> {
+        let (length, accessor): (usize, Accessor) = self.diagonal_accessor(k);
+        return ViewMut::new(1, length, accessor, self.data.as_mut_slice());
+    }
+
+    /// Compute length of diagonal at given offset from main diagonal and accessor
+    /// where consecutive column indexes step along it
+    fn diagonal_accessor(&self, k: isize) -> (usize, Accessor) {
+        let (start_row, start_col): (usize, usize) = if k >= 0 {
+            (0, k.unsigned_abs())
+        } else {
+            (k.unsigned_abs(), 0)
+        };
+
+        if start_row >= self.nb_rows || start_col >= self.nb_cols {
+            return (0, self.accessor.diagonal(0, 0));
+        }
+
+        let length: usize = (self.nb_rows - start_row).min(self.nb_cols - start_col);
+        return (length, self.accessor.diagonal(start_row, start_col));
+    }
+
+    /// Get an iterator on elements of matrix in row-major logical order,
+    /// whatever the storage order of matrix
+    pub fn iter(&'a self) -> ViewIter<'a, T> {
+        return self.full_view().iter();
+    }
+
+    /// Get an iterator on mutable elements of matrix in row-major logical order,
+    /// whatever the storage order of matrix
+    /// Panic if accessor gives same memory location to several elements
+    pub fn iter_mut(&'a mut self) -> MatrixIterMut<'a, T> {
+        let len: usize = addressable_len(self.nb_rows, self.nb_cols, &self.accessor, &self.data);
+
+        if let Some((start, StorageOrder::RowMajor)) =
+            self.accessor.contiguous_start(self.nb_rows, self.nb_cols)
+        {
+            return MatrixIterMut {
+                inner: IterMutInner::Slice(self.data[start..start + len].iter_mut()),
+            };
+        }
+
+        assert!(
+            self.accessor.is_injective(self.nb_rows, self.nb_cols),
+            "cannot iterate mutably over matrix whose elements share memory locations"
+        );
+
+        return MatrixIterMut {
+            inner: IterMutInner::Strided {
+                nb_rows: self.nb_rows,
+                nb_cols: self.nb_cols,
+                accessor: self.accessor,
+                data: self.data.as_mut_ptr(),
+                position: 0,
+                marker: PhantomData,
+            },
+        };
+    }
+
+    /// Get an iterator on rows of matrix, each row being a view with one row
+    pub fn rows(&'a self) -> RowIter<'a, T> {
+        return self.full_view().rows();
+    }
+
+    /// Get an iterator on columns of matrix, each column being a view with one column
+    pub fn cols(&'a self) -> ColIter<'a, T> {
+        return self.full_view().cols();
+    }
+
+    /// Check that row index is inside matrix, it panics otherwise
+    fn check_row(&self, row_id: usize) {
+        assert!(
+            row_id < self.nb_rows,
+            "row index {} is out of bounds of matrix with {} rows",
+            row_id,
+            self.nb_rows
+        );
+    }
+
+    /// Check that index of split row is not greater than number of rows, it panics otherwise
+    fn check_split_row(&self, row_id: usize) {
+        assert!(
+            row_id <= self.nb_rows,
+            "split row index {} is out of bounds of matrix with {} rows",
+            row_id,
+            self.nb_rows
+        );
+    }
+
+    /// Check that index of split column is not greater than number of columns, it panics otherwise
+    fn check_split_col(&self, col_id: usize) {
+        assert!(
+            col_id <= self.nb_cols,
+            "split column index {} is out of bounds of matrix with {} columns",
+            col_id,
+            self.nb_cols
+        );
+    }
+
+    /// Check that column index is inside matrix, it panics otherwise
+    fn check_col(&self, col_id: usize) {
+        assert!(
+            col_id < self.nb_cols,
+            "column index {} is out of bounds of matrix with {} columns",
+            col_id,
+            self.nb_cols
+        );
+    }
+}
+
+impl<T> Clone for Matrix<T>
+where
+    T: Clone,
+{
+    /// Deep copy of matrix, the clone keeps the same accessor
+    /// so it has the same storage order as the source
+    fn clone(&self) -> Self {
+        return Self {
+            nb_rows: self.nb_rows,
+            nb_cols: self.nb_cols,
+            accessor: self.accessor,
+            storage_order: self.storage_order,
+            data: self.data.clone(),
+        };
+    }
+}
+
+impl<T> TryFrom<Vec<Vec<T>>> for Matrix<T> {
+    type Error = ShapeError;
+
+    /// Create a row-major matrix from a vector of rows, elements are moved in matrix
+    /// All rows must have the same length
+    fn try_from(rows: Vec<Vec<T>>) -> Result<Self, Self::Error> {
+        return Self::from_row_iters(rows);
+    }
+}
+
+impl<T> PartialEq for Matrix<T>
+where
+    T: PartialEq,
+{
+    /// Matrices are equal when they have same dimensions and same elements
+    /// at each logical position, whatever their storage order
+    fn eq(&self, other: &Self) -> bool {
+        return self.full_view() == other.full_view();
+    }
+}
+
+impl<'a, T> PartialEq<View<'a, T>> for Matrix<T>
+where
+    T: PartialEq,
+{
+    /// Matrix and view are equal when they have same dimensions and same elements at each logical position
+    fn eq(&self, other: &View<'a, T>) -> bool {
+        return self.full_view() == *other;
+    }
+}
+
+impl<'a, T> PartialEq<Matrix<T>> for View<'a, T>
+where
+    T: PartialEq,
+{
+    /// View and matrix are equal when they have same dimensions and same elements at each logical position
+    fn eq(&self, other: &Matrix<T>) -> bool {
+        return *self == other.full_view();
+    }
+}
+
+impl<T> fmt::Display for Matrix<T>
+where
+    T: fmt::Display,
+{
+    /// Print matrix through its full view
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return self.full_view().fmt(f);
+    }
+}
+
+impl<T> fmt::Debug for Matrix<T>
+where
+    T: fmt::Debug,
+{
+    /// Print dimensions, storage order, strides and elements of matrix in logical order
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let elements: Vec<Vec<&T>> = (0..self.nb_rows)
+            .map(|row_id| {
+                return (0..self.nb_cols)
+                    .map(|col_id| &self[(row_id, col_id)])
+                    .collect();
+            })
+            .collect();
+
+        return f
+            .debug_struct("Matrix")
+            .field("nb_rows", &self.nb_rows)
+            .field("nb_cols", &self.nb_cols)
+            .field("storage_order", &self.storage_order())
+            .field(
+                "strides",
+                &(self.accessor.stride_row, self.accessor.stride_col),
+            )
+            .field("elements", &elements)
+            .finish();
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    /// This allows to read the matrix element at (index of row, index of column) position
+    /// like this let element: f32 = matrix[(0, 2)];
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        let id: usize = self.accessor.index(index.0, index.1);
+        return self.data.index(id);
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    /// This allows to write an value in matrix at (index of row, index of column) position
+    /// like this matrix[(0, 2)] = 3.1415;
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        let id: usize = self.accessor.index(index.0, index.1);
+        return self.data.index_mut(id);
+    }
+}
+
+impl<T> IntoIterator for Matrix<T> {
+    type Item = T;
+    type IntoIter = MatrixIntoIter<T>;
+
+    /// Consume matrix into an iterator on its elements in row-major logical order
+    /// Panic if accessor gives same memory location to several elements
+    fn into_iter(self) -> Self::IntoIter {
+        let len: usize = addressable_len(self.nb_rows, self.nb_cols, &self.accessor, &self.data);
+        let mut data: Vec<T> = self.data;
+
+        if let Some((start, StorageOrder::RowMajor)) =
+            self.accessor.contiguous_start(self.nb_rows, self.nb_cols)
+        {
+            data.truncate(start + len);
+            data.drain(..start);
+
+            return MatrixIntoIter {
+                inner: IntoIterInner::Vec(data.into_iter()),
+            };
+        }
+
+        assert!(
+            self.accessor.is_injective(self.nb_rows, self.nb_cols),
+            "cannot move out of matrix whose elements share memory locations"
+        );
+
+        let data_len: usize = data.len();
+        // SAFETY: elements are now owned by iterator, which reads or drops each of them once
+        unsafe {
+            data.set_len(0);
+        }
+
+        return MatrixIntoIter {
+            inner: IntoIterInner::Strided {
+                nb_rows: self.nb_rows,
+                nb_cols: self.nb_cols,
+                accessor: self.accessor,
+                data,
+                data_len,
+                position: 0,
+            },
+        };
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Matrix<T> {
+    type Item = &'a T;
+    type IntoIter = ViewIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        return self.iter();
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Matrix<T> {
+    type Item = &'a mut T;
+    type IntoIter = MatrixIterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        return self.iter_mut();
+    }
+}
+
+/// Matrix mutable iterator
+/// This structure iterates over mutable elements of a matrix in row-major logical order.
+/// Elements of a contiguous row-major matrix are walked through a slice iterator,
+/// others are reached at memory location given by accessor
+pub struct MatrixIterMut<'a, T> {
+    inner: IterMutInner<'a, T>,
+}
+
+enum IterMutInner<'a, T> {
+    Slice(slice::IterMut<'a, T>),
+    Strided {
+        nb_rows: usize,
+        nb_cols: usize,
+        accessor: Accessor,
+        data: *mut T,
+        position: usize,
+        marker: PhantomData<&'a mut T>,
+    },
+}
+
+impl<'a, T> Iterator for MatrixIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        return match &mut self.inner {
+            IterMutInner::Slice(iter) => iter.next(),
+            IterMutInner::Strided {
+                nb_rows,
+                nb_cols,
+                accessor,
+                data,
+                position,
+                ..
+            } => {
+                if *position >= *nb_rows * *nb_cols {
+                    return None;
+                }
+
+                let row_id: usize = *position / *nb_cols;
+                let col_id: usize = *position % *nb_cols;
+                *position += 1;
+
+                // SAFETY: accessor is injective and its last memory location was checked
+                // to be in data, so each element is yielded once while matrix stays mutably borrowed
+                Some(unsafe { &mut *data.add(accessor.index(row_id, col_id)) })
+            }
+        };
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return match &self.inner {
+            IterMutInner::Slice(iter) => iter.size_hint(),
+            IterMutInner::Strided {
+                nb_rows,
+                nb_cols,
+                position,
+                ..
+            } => {
+                let remaining: usize = nb_rows * nb_cols - position;
+                (remaining, Some(remaining))
+            }
+        };
+    }
+}
+
+impl<'a, T> ExactSizeIterator for MatrixIterMut<'a, T> {}
+
+/// Matrix consuming iterator
+/// This structure iterates over owned elements of a matrix in row-major logical order.
+/// Elements of a contiguous row-major matrix are moved out through a vector iterator,
+/// others are read at memory location given by accessor
+pub struct MatrixIntoIter<T> {
+    inner: IntoIterInner<T>,
+}
+
+enum IntoIterInner<T> {
+    Vec(vec::IntoIter<T>),
+    Strided {
+        nb_rows: usize,
+        nb_cols: usize,
+        accessor: Accessor,
+        // Vector has length 0, its first data_len slots are owned by iterator
+        data: Vec<T>,
+        data_len: usize,
+        position: usize,
+    },
+}
+
+impl<T> Iterator for MatrixIntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        return match &mut self.inner {
+            IntoIterInner::Vec(iter) => iter.next(),
+            IntoIterInner::Strided {
+                nb_rows,
+                nb_cols,
+                accessor,
+                data,
+                position,
+                ..
+            } => {
+                if *position >= *nb_rows * *nb_cols {
+                    return None;
+                }
+
+                let row_id: usize = *position / *nb_cols;
+                let col_id: usize = *position % *nb_cols;
+                *position += 1;
+
+                // SAFETY: accessor is injective and its last memory location was checked
+                // to be in data, so each element is moved out once and never dropped afterwards
+                Some(unsafe { ptr::read(data.as_ptr().add(accessor.index(row_id, col_id))) })
+            }
+        };
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return match &self.inner {
+            IntoIterInner::Vec(iter) => iter.size_hint(),
+            IntoIterInner::Strided {
+                nb_rows,
+                nb_cols,
+                position,
+                ..
+            } => {
+                let remaining: usize = nb_rows * nb_cols - position;
+                (remaining, Some(remaining))
+            }
+        };
+    }
+}
+
+impl<T> Drop for MatrixIntoIter<T> {
+    fn drop(&mut self) {
+        if let IntoIterInner::Strided {
+            nb_rows,
+            nb_cols,
+            accessor,
+            data,
+            data_len,
+            position,
+        } = &mut self.inner
+        {
+            let base: *mut T = data.as_mut_ptr();
+
+            // SAFETY: padding slots and elements not yielded yet are still owned by iterator
+            // and are dropped once, then vector of length 0 only frees its buffer
+            unsafe {
+                for index in 0..*data_len {
+                    if !accessor.contains(*nb_rows, *nb_cols, index) {
+                        ptr::drop_in_place(base.add(index));
+                    }
+                }
+
+                for remaining in *position..*nb_rows * *nb_cols {
+                    let index: usize = accessor.index(remaining / *nb_cols, remaining % *nb_cols);
+                    ptr::drop_in_place(base.add(index));
+                }
+            }
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for MatrixIntoIter<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matrix_new_row_major() {
+        let nb_rows: usize = 3;
+        let nb_cols: usize = 4;
+
+        let matrix: Matrix<i32> = Matrix::new_row_major(nb_rows, nb_cols);
+
+        assert_eq!(matrix.nb_rows, nb_rows);
+        assert_eq!(matrix.nb_cols, nb_cols);
+        assert_eq!(matrix.data.len(), nb_rows * nb_cols);
+    }
+
+    #[test]
+    fn test_matrix_new_column_major() {
+        let nb_rows: usize = 4;
+        let nb_cols: usize = 3;
+
+        let matrix: Matrix<i32> = Matrix::new_column_major(nb_rows, nb_cols);
+
+        assert_eq!(matrix.nb_rows, nb_rows);
+        assert_eq!(matrix.nb_cols, nb_cols);
+        assert_eq!(matrix.data.len(), nb_rows * nb_cols);
+    }
+
+    #[test]
+    fn test_matrix_storage_order() {
+        let row_major: Matrix<i32> = Matrix::new_row_major(3, 4);
+        assert_eq!(row_major.storage_order(), StorageOrder::RowMajor);
+
+        let column_major: Matrix<i32> = Matrix::new_column_major(3, 4);
+        assert_eq!(column_major.storage_order(), StorageOrder::ColumnMajor);
+
+        let single_row: Matrix<i32> = Matrix::new_column_major(1, 4);
+        assert_eq!(single_row.storage_order(), StorageOrder::ColumnMajor);
+
+        let copy: Matrix<i32> = column_major.clone();
+        assert_eq!(copy.storage_order(), StorageOrder::ColumnMajor);
+    }
+
+    #[test]
+    fn test_matrix_new_uninit() {
+        let mut matrix: Matrix<MaybeUninit<f64>> = Matrix::new_uninit_column_major(2, 3);
+
+        for row_id in 0..2 {
+            for col_id in 0..3 {
+                matrix[(row_id, col_id)].write((10 * row_id + col_id) as f64);
+            }
+        }
+
+        let matrix: Matrix<f64> = unsafe { matrix.assume_init() };
+
+        assert_eq!(matrix.nb_rows(), 2);
+        assert_eq!(matrix.nb_cols(), 3);
+        assert_eq!(matrix.data, vec![0.0, 10.0, 1.0, 11.0, 2.0, 12.0]);
+
+        let mut matrix: Matrix<MaybeUninit<String>> = Matrix::new_uninit_row_major(1, 2);
+        matrix[(0, 0)].write(String::from("a"));
+        matrix[(0, 1)].write(String::from("b"));
+
+        let matrix: Matrix<String> = unsafe { matrix.assume_init() };
+        assert_eq!(matrix.data, vec![String::from("a"), String::from("b")]);
+    }
+
+    #[test]
+    fn test_matrix_filled() {
+        let matrix: Matrix<f64> = Matrix::filled(3, 4, 0.5);
+
+        assert_eq!(matrix.nb_rows(), 3);
+        assert_eq!(matrix.nb_cols(), 4);
+        assert_eq!(matrix.data, vec![0.5; 12]);
+    }
+
+    #[test]
+    fn test_matrix_zeros_and_ones() {
+        let zeros: Matrix<i32> = Matrix::zeros(2, 3);
+
+        assert_eq!(zeros.nb_rows(), 2);
+        assert_eq!(zeros.nb_cols(), 3);
+        assert_eq!(zeros.data, vec![0; 6]);
+
+        let ones: Matrix<f32> = Matrix::ones(3, 2);
+
+        assert_eq!(ones.nb_rows(), 3);
+        assert_eq!(ones.nb_cols(), 2);
+        assert_eq!(ones.data, vec![1.0; 6]);
+    }
+
+    #[test]
+    fn test_matrix_identity() {
+        let matrix: Matrix<i32> = Matrix::identity(4);
+
+        assert_eq!(matrix.nb_rows(), 4);
+        assert_eq!(matrix.nb_cols(), 4);
+
+        for i in 0..4 {
+            for j in 0..4 {
+                assert_eq!(matrix[(i, j)], if i == j { 1 } else { 0 });
+            }
+        }
+
+        assert_eq!(Matrix::<f64>::identity(0).nb_rows(), 0);
+    }
+
+    #[test]
+    fn test_matrix_linspace_rows() {
+        let matrix: Matrix<f64> = Matrix::linspace_rows(-1.0, 1.0, 2, 5);
+        let expected: Matrix<f64> =
+            Matrix::from_rows(&[&[-1.0, -0.5, 0.0, 0.5, 1.0], &[-1.0, -0.5, 0.0, 0.5, 1.0]])
+                .unwrap();
+
+        assert_eq!(matrix, expected);
+
+        let matrix: Matrix<f64> = Matrix::linspace_rows(3.0, 7.0, 2, 1);
+        assert_eq!(matrix, Matrix::filled(2, 1, 3.0));
+    }
+
+    #[test]
+    fn test_matrix_linspace_cols() {
+        let matrix: Matrix<f64> = Matrix::linspace_cols(0.0, 0.3, 4, 2);
+        assert_eq!(matrix[(3, 0)], 0.3);
+        assert_eq!(matrix[(3, 1)], 0.3);
+        assert!((matrix[(1, 1)] - 0.1).abs() < 1e-15);
+
+        let matrix: Matrix<f64> = Matrix::linspace_cols(3.0, 7.0, 1, 3);
+        assert_eq!(matrix, Matrix::filled(1, 3, 3.0));
+    }
+
+    #[test]
+    fn test_matrix_one_hot() {
+        let matrix: Matrix<f64> = Matrix::one_hot(&[0, 2, 1], 3).unwrap();
+        let expected: Matrix<f64> =
+            Matrix::from_rows(&[&[1.0, 0.0, 0.0], &[0.0, 0.0, 1.0], &[0.0, 1.0, 0.0]]).unwrap();
+
+        assert_eq!(matrix, expected);
+
+        assert_eq!(
+            Matrix::<i32>::one_hot(&[0, 3, 1], 3).err(),
+            Some(ShapeError::ColumnOutOfBounds {
+                col_id: 3,
+                nb_cols: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_matrix_from_rows() {
+        let matrix: Matrix<f64> = Matrix::from_rows(&[&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]]).unwrap();
+
+        assert_eq!(matrix.nb_rows(), 2);
+        assert_eq!(matrix.nb_cols(), 3);
+        assert_eq!(matrix.data, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(matrix[(1, 0)], 4.0);
+
+        match Matrix::from_rows(&[&[1.0, 2.0], &[3.0, 4.0], &[5.0]]) {
+            Err(error) => assert_eq!(
+                error,
+                ShapeError::RaggedRow {
+                    row_id: 2,
+                    expected: 2,
+                    actual: 1
+                }
+            ),
+            Ok(_) => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_matrix_from_cols() {
+        let matrix: Matrix<f64> = Matrix::from_cols(&[&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]]).unwrap();
+
+        assert_eq!(matrix.nb_rows(), 3);
+        assert_eq!(matrix.nb_cols(), 2);
+        assert_eq!(matrix.data, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(matrix[(0, 1)], 4.0);
+        assert_eq!(matrix[(2, 0)], 3.0);
+
+        match Matrix::from_cols(&[&[1.0, 2.0], &[3.0, 4.0, 5.0]]) {
+            Err(error) => assert_eq!(
+                error,
+                ShapeError::RaggedColumn {
+                    col_id: 1,
+                    expected: 2,
+                    actual: 3
+                }
+            ),
+            Ok(_) => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_matrix_toeplitz() {
+        let matrix: Matrix<i32> = Matrix::toeplitz(&[1, 2, 3, 4], &[1, 5, 6, 7, 8]).unwrap();
+
+        let expected: Matrix<i32> = Matrix::from_rows(&[
+            &[1, 5, 6, 7, 8],
+            &[2, 1, 5, 6, 7],
+            &[3, 2, 1, 5, 6],
+            &[4, 3, 2, 1, 5],
+        ])
+        .unwrap();
+
+        assert_eq!(matrix, expected);
+
+        assert_eq!(
+            Matrix::toeplitz(&[1, 2], &[3, 4]).err(),
+            Some(ShapeError::CornerMismatch)
+        );
+        assert_eq!(
+            Matrix::<i32>::toeplitz(&[], &[1]).err(),
+            Some(ShapeError::Empty)
+        );
+    }
+
+    #[test]
+    fn test_matrix_circulant() {
+        let matrix: Matrix<i32> = Matrix::circulant(&[1, 2, 3]).unwrap();
+        let expected: Matrix<i32> =
+            Matrix::from_rows(&[&[1, 3, 2], &[2, 1, 3], &[3, 2, 1]]).unwrap();
+
+        assert_eq!(matrix, expected);
+        assert_eq!(Matrix::<i32>::circulant(&[]).err(), Some(ShapeError::Empty));
+    }
+
+    #[test]
+    fn test_matrix_from_blocks() {
+        let a: Matrix<i32> = Matrix::from_vec_column_major(2, 2, vec![1, 3, 2, 4]).unwrap();
+        let b: Matrix<i32> = Matrix::from_rows(&[&[5], &[6]]).unwrap();
+        let c: Matrix<i32> = Matrix::from_rows(&[&[7, 8]]).unwrap();
+        let d: Matrix<i32> = Matrix::filled(1, 1, 9);
+
+        let matrix: Matrix<i32> = Matrix::from_blocks(
+            &a.full_view(),
+            &b.full_view(),
+            &c.full_view(),
+            &d.full_view(),
+        )
+        .unwrap();
+
+        let expected: Matrix<i32> =
+            Matrix::from_rows(&[&[1, 2, 5], &[3, 4, 6], &[7, 8, 9]]).unwrap();
+
+        assert_eq!(matrix, expected);
+    }
+
+    #[test]
+    fn test_matrix_from_blocks_errors() {
+        let a: Matrix<i32> = Matrix::zeros(2, 2);
+        let b: Matrix<i32> = Matrix::zeros(3, 1);
+        let c: Matrix<i32> = Matrix::zeros(1, 2);
+        let d: Matrix<i32> = Matrix::zeros(1, 3);
+
+        assert_eq!(
+            Matrix::from_blocks(
+                &a.full_view(),
+                &b.full_view(),
+                &c.full_view(),
+                &c.full_view()
+            )
+            .err(),
+            Some(ShapeError::BlockRowMismatch {
+                first: Block::TopLeft,
+                second: Block::TopRight,
+                expected: 2,
+                actual: 3
+            })
+        );
+
+        assert_eq!(
+            Matrix::from_blocks(
+                &a.full_view(),
+                &a.full_view(),
+                &c.full_view(),
+                &b.full_view()
+            )
+            .err(),
+            Some(ShapeError::BlockRowMismatch {
+                first: Block::BottomLeft,
+                second: Block::BottomRight,
+                expected: 1,
+                actual: 3
+            })
+        );
+
+        assert_eq!(
+            Matrix::from_blocks(
+                &a.full_view(),
+                &a.full_view(),
+                &c.full_view(),
+                &d.full_view()
+            )
+            .err(),
+            Some(ShapeError::BlockColumnMismatch {
+                first: Block::TopRight,
+                second: Block::BottomRight,
+                expected: 2,
+                actual: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_matrix_banded_round_trip() {
+        let matrix: Matrix<f64> = Matrix::from_rows(&[
+            &[2.0, -1.0, 0.0, 0.0],
+            &[-3.0, 4.0, -5.0, 0.0],
+            &[0.0, -6.0, 7.0, -8.0],
+            &[0.0, 0.0, -9.0, 10.0],
+        ])
+        .unwrap();
+
+        let banded: Vec<f64> = matrix.full_view().to_banded(1, 1);
+
+        assert_eq!(
+            banded,
+            vec![0.0, 2.0, -3.0, -1.0, 4.0, -6.0, -5.0, 7.0, -9.0, -8.0, 10.0, 0.0]
+        );
+
+        let unpacked: Matrix<f64> = Matrix::from_banded(4, 1, 1, &banded).unwrap();
+
+        for i in 0..4 {
+            for j in 0..4 {
+                assert_eq!(unpacked[(i, j)], matrix[(i, j)]);
+            }
+        }
+
+        assert_eq!(
+            Matrix::from_banded(4, 1, 0, &banded).map(|_| ()),
+            Err(ShapeError::LengthMismatch {
+                expected: 8,
+                actual: 12
+            })
+        );
+    }
+
+    #[test]
+    fn test_matrix_from_fn_row_major() {
+        let mut nb_calls: usize = 0;
+
+        let matrix: Matrix<usize> = Matrix::from_fn_row_major(2, 3, |i, j| {
+            nb_calls += 1;
+            return 10 * i + j;
+        });
+
+        assert_eq!(nb_calls, 6);
+        assert_eq!(matrix.data, vec![0, 1, 2, 10, 11, 12]);
+
+        let view: View<usize> = matrix.full_view();
+
+        for i in 0..2 {
+            for j in 0..3 {
+                assert_eq!(view[(i, j)], 10 * i + j);
+            }
+        }
+    }
+
+    #[test]
+    fn test_matrix_from_fn_column_major() {
+        let mut nb_calls: usize = 0;
+
+        let matrix: Matrix<usize> = Matrix::from_fn_column_major(2, 3, |i, j| {
+            nb_calls += 1;
+            return 10 * i + j;
+        });
+
+        assert_eq!(nb_calls, 6);
+        assert_eq!(matrix.data, vec![0, 10, 1, 11, 2, 12]);
+
+        let view: View<usize> = matrix.full_view();
+
+        for i in 0..2 {
+            for j in 0..3 {
+                assert_eq!(view[(i, j)], 10 * i + j);
+            }
+        }
+    }
+
+    #[test]
+    fn test_matrix_from_vec_row_major() {
+        let data_ref: Vec<i32> = vec![1, 2, 3, 4, 5, 6];
+        let matrix: Matrix<i32> = Matrix::from_vec_row_major(2, 3, data_ref.clone()).unwrap();
+
+        assert_eq!(matrix.nb_rows(), 2);
+        assert_eq!(matrix.nb_cols(), 3);
+        assert_eq!(matrix[(0, 0)], data_ref[0]);
+        assert_eq!(matrix[(0, 2)], data_ref[2]);
+        assert_eq!(matrix[(1, 0)], data_ref[3]);
+        assert_eq!(matrix[(1, 2)], data_ref[5]);
+
+        match Matrix::from_vec_row_major(2, 2, data_ref) {
+            Err(error) => assert_eq!(
+                error,
+                ShapeError::LengthMismatch {
+                    expected: 4,
+                    actual: 6
+                }
+            ),
+            Ok(_) => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_matrix_from_vec_column_major() {
+        let data_ref: Vec<i32> = vec![1, 2, 3, 4, 5, 6];
+        let matrix: Matrix<i32> = Matrix::from_vec_column_major(2, 3, data_ref.clone()).unwrap();
+
+        assert_eq!(matrix.nb_rows(), 2);
+        assert_eq!(matrix.nb_cols(), 3);
+        assert_eq!(matrix[(0, 0)], data_ref[0]);
+        assert_eq!(matrix[(1, 0)], data_ref[1]);
+        assert_eq!(matrix[(0, 2)], data_ref[4]);
+        assert_eq!(matrix[(1, 2)], data_ref[5]);
+
+        match Matrix::from_vec_column_major(4, 2, data_ref) {
+            Err(error) => assert_eq!(
+                error,
+                ShapeError::LengthMismatch {
+                    expected: 8,
+                    actual: 6
+                }
+            ),
+            Ok(_) => unreachable!(),
+        }
+
+        assert_eq!(
+            Matrix::from_vec_column_major(2, (1 << (usize::BITS - 1)) + 1, vec![1, 2]).err(),
+            Some(ShapeError::TooManyElements {
+                nb_rows: 2,
+                nb_cols: (1 << (usize::BITS - 1)) + 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_matrix_raw_parts() {
+        // Column-major 2x3 matrix stored with a leading dimension of 3, last row being padding
+        let data: Vec<f64> = vec![1.0, 2.0, -1.0, 3.0, 4.0, -1.0, 5.0, 6.0, -1.0];
+        let data_ptr: *const f64 = data.as_ptr();
+
+        let matrix: Matrix<f64> =
+            Matrix::from_raw_parts(2, 3, Accessor::new(1, 3), data.clone()).unwrap();
+
+        assert_eq!(matrix[(0, 1)], 3.0);
+        assert_eq!(matrix[(1, 2)], 6.0);
+
+        let (nb_rows, nb_cols, accessor, raw_data): (usize, usize, Accessor, Vec<f64>) =
+            matrix.into_raw_parts();
+
+        assert_eq!((nb_rows, nb_cols), (2, 3));
+        assert_eq!((accessor.stride_row, accessor.stride_col), (1, 3));
+        assert_eq!(raw_data, data);
+
+        let matrix: Matrix<f64> = Matrix::from_raw_parts(2, 3, Accessor::new(1, 3), data).unwrap();
+        assert_eq!(matrix.into_raw_parts().3.as_ptr(), data_ptr);
+
+        assert_eq!(
+            Matrix::from_raw_parts(3, 3, Accessor::new_with_offset(1, 3, 1, 0), vec![0; 9]).err(),
+            Some(ShapeError::LengthMismatch {
+                expected: 10,
+                actual: 9
+            })
+        );
+
+        // Memory location of last element would wrap around to the first one
+        assert_eq!(
+            Matrix::from_raw_parts(3, 1, Accessor::new(1 << (usize::BITS - 1), 1), vec![7]).err(),
+            Some(ShapeError::TooManyElements {
+                nb_rows: 3,
+                nb_cols: 1
+            })
+        );
+
+        assert_eq!(
+            Matrix::from_raw_parts(
+                1 << (usize::BITS / 2),
+                1 << (usize::BITS / 2),
+                Accessor::new(0, 0),
+                vec![7]
+            )
+            .err(),
+            Some(ShapeError::TooManyElements {
+                nb_rows: 1 << (usize::BITS / 2),
+                nb_cols: 1 << (usize::BITS / 2)
+            })
+        );
+    }
+
+    #[test]
+    fn test_matrix_from_row_iterator() {
+        let matrix: Matrix<usize> = Matrix::from_row_iterator(2, 3, (0..).map(|x| 2 * x)).unwrap();
+
+        assert_eq!(matrix[(0, 0)], 0);
+        assert_eq!(matrix[(0, 1)], 2);
+        assert_eq!(matrix[(0, 2)], 4);
+        assert_eq!(matrix[(1, 0)], 6);
+        assert_eq!(matrix[(1, 1)], 8);
+        assert_eq!(matrix[(1, 2)], 10);
+
+        match Matrix::from_row_iterator(2, 3, 0..5) {
+            Err(error) => assert_eq!(
+                error,
+                ShapeError::LengthMismatch {
+                    expected: 6,
+                    actual: 5
+                }
+            ),
+            Ok(_) => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_matrix_from_column_iterator() {
+        let mut iter = 0..10;
+        let matrix: Matrix<usize> = Matrix::from_column_iterator(2, 3, &mut iter).unwrap();
+
+        assert_eq!(matrix[(0, 0)], 0);
+        assert_eq!(matrix[(1, 0)], 1);
+        assert_eq!(matrix[(0, 1)], 2);
+        assert_eq!(matrix[(1, 1)], 3);
+        assert_eq!(matrix[(0, 2)], 4);
+        assert_eq!(matrix[(1, 2)], 5);
+
+        // Only needed elements are consumed
+        assert_eq!(iter.next(), Some(6));
+
+        match Matrix::from_column_iterator(3, 3, 0..2) {
+            Err(error) => assert_eq!(
+                error,
+                ShapeError::LengthMismatch {
+                    expected: 9,
+                    actual: 2
+                }
+            ),
+            Ok(_) => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_matrix_from_row_iters() {
+        let matrix: Matrix<i32> =
+            Matrix::from_row_iters((0..2).map(|i| (0..3).map(move |j| 10 * i + j))).unwrap();
+
+        assert_eq!(matrix.nb_rows(), 2);
+        assert_eq!(matrix.nb_cols(), 3);
+        assert_eq!(matrix[(0, 0)], 0);
+        assert_eq!(matrix[(0, 1)], 1);
+        assert_eq!(matrix[(0, 2)], 2);
+        assert_eq!(matrix[(1, 0)], 10);
+        assert_eq!(matrix[(1, 1)], 11);
+        assert_eq!(matrix[(1, 2)], 12);
+    }
+
+    #[test]
+    fn test_matrix_from_row_iters_errors() {
+        let rows: Vec<Vec<i32>> = vec![vec![1, 2, 3], vec![4, 5], vec![6, 7, 8]];
+
+        match Matrix::from_row_iters(rows) {
+            Err(error) => assert_eq!(
+                error,
+                ShapeError::RaggedRow {
+                    row_id: 1,
+                    expected: 3,
+                    actual: 2
+                }
+            ),
+            Ok(_) => unreachable!(),
+        }
+
+        let empty: Vec<Vec<i32>> = Vec::new();
+
+        match Matrix::from_row_iters(empty) {
+            Err(error) => assert_eq!(error, ShapeError::Empty),
+            Ok(_) => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_matrix_dimensions_access() {
+        let nb_rows: usize = 5;
+        let nb_cols: usize = 3;
+
+        let matrix: Matrix<i32> = Matrix::new_row_major(nb_rows, nb_cols);
+
+        assert_eq!(matrix.nb_rows(), nb_rows);
+        assert_eq!(matrix.nb_cols(), nb_cols);
+    }
+
+    #[test]
+    fn test_matrix_row_major_data_access() {
+        let nb_rows: usize = 3;
+        let nb_cols: usize = 3;
+
+        let mut matrix: Matrix<i32> = Matrix::new_row_major(nb_rows, nb_cols);
+
+        let data_ref: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+        matrix.data = data_ref.clone();
+
+        assert_eq!(matrix[(0, 0)], data_ref[0]);
+        assert_eq!(matrix[(0, 1)], data_ref[1]);
+        assert_eq!(matrix[(0, 2)], data_ref[2]);
+        assert_eq!(matrix[(1, 0)], data_ref[3]);
+        assert_eq!(matrix[(1, 1)], data_ref[4]);
+        assert_eq!(matrix[(1, 2)], data_ref[5]);
+        assert_eq!(matrix[(2, 0)], data_ref[6]);
+        assert_eq!(matrix[(2, 1)], data_ref[7]);
+        assert_eq!(matrix[(2, 2)], data_ref[8]);
+
+        matrix[(2, 1)] = 43;
+        assert_eq!(matrix[(2, 1)], 43);
+    }
+
+    #[test]
+    fn test_matrix_column_major_data_access() {
+        let nb_rows: usize = 3;
+        let nb_cols: usize = 3;
+
+        let mut matrix: Matrix<i32> = Matrix::new_column_major(nb_rows, nb_cols);
+
+        let data_ref: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+        matrix.data = data_ref.clone();
+
+        assert_eq!(matrix[(0, 0)], data_ref[0]);
+        assert_eq!(matrix[(1, 0)], data_ref[1]);
+        assert_eq!(matrix[(2, 0)], data_ref[2]);
+        assert_eq!(matrix[(0, 1)], data_ref[3]);
+        assert_eq!(matrix[(1, 1)], data_ref[4]);
+        assert_eq!(matrix[(2, 1)], data_ref[5]);
+        assert_eq!(matrix[(0, 2)], data_ref[6]);
+        assert_eq!(matrix[(1, 2)], data_ref[7]);
+        assert_eq!(matrix[(2, 2)], data_ref[8]);
+
+        matrix[(2, 1)] = 43;
+        assert_eq!(matrix[(2, 1)], 43);
+    }
+
+    #[test]
+    fn test_matrix_row_major_full_view() {
+        let nb_rows: usize = 3;
+        let nb_cols: usize = 3;
+
+        let mut matrix: Matrix<i32> = Matrix::new_row_major(nb_rows, nb_cols);
+
+        let data_ref: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+        matrix.data = data_ref.clone();
+
+        let view: View<i32> = matrix.full_view();
+
+        assert_eq!(view[(0, 0)], data_ref[0]);
+        assert_eq!(view[(0, 1)], data_ref[1]);
+        assert_eq!(view[(0, 2)], data_ref[2]);
+        assert_eq!(view[(1, 0)], data_ref[3]);
+        assert_eq!(view[(1, 1)], data_ref[4]);
+        assert_eq!(view[(1, 2)], data_ref[5]);
+        assert_eq!(view[(2, 0)], data_ref[6]);
+        assert_eq!(view[(2, 1)], data_ref[7]);
+        assert_eq!(view[(2, 2)], data_ref[8]);
+    }
+
+    #[test]
+    fn test_matrix_column_major_full_view() {
+        let nb_rows: usize = 3;
+        let nb_cols: usize = 3;
+
+        let mut matrix: Matrix<i32> = Matrix::new_column_major(nb_rows, nb_cols);
+
+        let data_ref: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+        matrix.data = data_ref.clone();
+
+        let view: View<i32> = matrix.full_view();
+
+        assert_eq!(view[(0, 0)], data_ref[0]);
+        assert_eq!(view[(1, 0)], data_ref[1]);
         assert_eq!(view[(2, 0)], data_ref[2]);
         assert_eq!(view[(0, 1)], data_ref[3]);
         assert_eq!(view[(1, 1)], data_ref[4]);
@@ -280,176 +2479,1325 @@ mod tests {
     }
 
     #[test]
-    fn test_matrix_row_major_full_mutable_view() {
-        let nb_rows: usize = 3;
-        let nb_cols: usize = 3;
+    fn test_matrix_transpose_view() {
+        let matrix: Matrix<usize> = Matrix::from_fn_row_major(2, 3, |i, j| 10 * i + j);
+        let transposed: View<usize> = matrix.transpose_view();
+
+        assert_eq!(transposed.nb_rows(), 3);
+        assert_eq!(transposed.nb_cols(), 2);
+
+        for row_id in 0..transposed.nb_rows() {
+            for col_id in 0..transposed.nb_cols() {
+                assert_eq!(transposed[(row_id, col_id)], matrix[(col_id, row_id)]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_matrix_row_major_full_mutable_view() {
+        let nb_rows: usize = 3;
+        let nb_cols: usize = 3;
+
+        let mut matrix: Matrix<i32> = Matrix::new_row_major(nb_rows, nb_cols);
+
+        let data_ref: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+        matrix.data = data_ref.clone();
+
+        let factor: i32 = 3;
+
+        {
+            let mut view: ViewMut<i32> = matrix.full_view_mut();
+
+            view[(1, 2)] *= factor;
+            view[(2, 1)] *= factor;
+        }
+
+        assert_eq!(matrix[(0, 0)], data_ref[0]);
+        assert_eq!(matrix[(0, 1)], data_ref[1]);
+        assert_eq!(matrix[(0, 2)], data_ref[2]);
+        assert_eq!(matrix[(1, 0)], data_ref[3]);
+        assert_eq!(matrix[(1, 1)], data_ref[4]);
+        assert_eq!(matrix[(1, 2)], factor * data_ref[5]);
+        assert_eq!(matrix[(2, 0)], data_ref[6]);
+        assert_eq!(matrix[(2, 1)], factor * data_ref[7]);
+        assert_eq!(matrix[(2, 2)], data_ref[8]);
+    }
+
+    #[test]
+    fn test_matrix_column_major_full_view_mut() {
+        let nb_rows: usize = 3;
+        let nb_cols: usize = 3;
+
+        let mut matrix: Matrix<i32> = Matrix::new_column_major(nb_rows, nb_cols);
+
+        let data_ref: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+        matrix.data = data_ref.clone();
+
+        let factor: i32 = 3;
+
+        {
+            let mut view: ViewMut<i32> = matrix.full_view_mut();
+
+            view[(1, 0)] *= factor;
+            view[(2, 1)] *= factor;
+        }
+
+        assert_eq!(matrix[(0, 0)], data_ref[0]);
+        assert_eq!(matrix[(1, 0)], factor * data_ref[1]);
+        assert_eq!(matrix[(2, 0)], data_ref[2]);
+        assert_eq!(matrix[(0, 1)], data_ref[3]);
+        assert_eq!(matrix[(1, 1)], data_ref[4]);
+        assert_eq!(matrix[(2, 1)], factor * data_ref[5]);
+        assert_eq!(matrix[(0, 2)], data_ref[6]);
+        assert_eq!(matrix[(1, 2)], data_ref[7]);
+        assert_eq!(matrix[(2, 2)], data_ref[8]);
+    }
+
+    #[test]
+    fn test_matrix_row_major_view() {
+        let nb_rows: usize = 4;
+        let nb_cols: usize = 4;
+
+        let mut matrix: Matrix<i32> = Matrix::new_row_major(nb_rows, nb_cols);
+
+        let data_ref: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+        matrix.data = data_ref.clone();
+
+        let view: View<i32> = matrix.view(ViewParameters::new(1, 1, 2, 2));
+
+        assert_eq!(view[(0, 0)], data_ref[5]);
+        assert_eq!(view[(0, 1)], data_ref[6]);
+        assert_eq!(view[(1, 0)], data_ref[9]);
+        assert_eq!(view[(1, 1)], data_ref[10]);
+    }
+
+    #[test]
+    fn test_matrix_column_major_view() {
+        let nb_rows: usize = 4;
+        let nb_cols: usize = 4;
+
+        let mut matrix: Matrix<i32> = Matrix::new_column_major(nb_rows, nb_cols);
+
+        let data_ref: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+        matrix.data = data_ref.clone();
+
+        let view: View<i32> = matrix.view(ViewParameters::new(1, 1, 2, 2));
+
+        assert_eq!(view[(0, 0)], data_ref[5]);
+        assert_eq!(view[(0, 1)], data_ref[9]);
+        assert_eq!(view[(1, 0)], data_ref[6]);
+        assert_eq!(view[(1, 1)], data_ref[10]);
+    }
+
+    #[test]
+    fn test_matrix_row_major_view_mut() {
+        let nb_rows: usize = 4;
+        let nb_cols: usize = 4;
+
+        let mut matrix: Matrix<i32> = Matrix::new_row_major(nb_rows, nb_cols);
+
+        let data_ref: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+        matrix.data = data_ref.clone();
+
+        let factor: i32 = 3;
+
+        {
+            let mut view: ViewMut<i32> = matrix.view_mut(ViewParameters::new(1, 1, 2, 2));
+
+            view[(0, 0)] *= factor;
+            view[(0, 1)] *= factor;
+            view[(1, 0)] *= factor;
+            view[(1, 1)] *= factor;
+        }
+
+        assert_eq!(matrix[(0, 0)], data_ref[0]);
+        assert_eq!(matrix[(0, 1)], data_ref[1]);
+        assert_eq!(matrix[(0, 2)], data_ref[2]);
+        assert_eq!(matrix[(0, 3)], data_ref[3]);
+        assert_eq!(matrix[(1, 0)], data_ref[4]);
+        assert_eq!(matrix[(1, 1)], factor * data_ref[5]);
+        assert_eq!(matrix[(1, 2)], factor * data_ref[6]);
+        assert_eq!(matrix[(1, 3)], data_ref[7]);
+        assert_eq!(matrix[(2, 0)], data_ref[8]);
+        assert_eq!(matrix[(2, 1)], factor * data_ref[9]);
+        assert_eq!(matrix[(2, 2)], factor * data_ref[10]);
+        assert_eq!(matrix[(2, 3)], data_ref[11]);
+        assert_eq!(matrix[(3, 0)], data_ref[12]);
+        assert_eq!(matrix[(3, 1)], data_ref[13]);
+        assert_eq!(matrix[(3, 2)], data_ref[14]);
+        assert_eq!(matrix[(3, 3)], data_ref[15]);
+    }
+
+    #[test]
+    fn test_matrix_row_major_view_mut_apply() {
+        let nb_rows: usize = 4;
+        let nb_cols: usize = 4;
+
+        let mut matrix: Matrix<i32> = Matrix::new_row_major(nb_rows, nb_cols);
+
+        let data_ref: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+        matrix.data = data_ref.clone();
+
+        let factor: i32 = 3;
+
+        {
+            let mut view: ViewMut<i32> = matrix.view_mut(ViewParameters::new(1, 1, 2, 2));
+            view.apply(|x| *x *= factor);
+        }
+
+        assert_eq!(matrix[(0, 0)], data_ref[0]);
+        assert_eq!(matrix[(0, 1)], data_ref[1]);
+        assert_eq!(matrix[(0, 2)], data_ref[2]);
+        assert_eq!(matrix[(0, 3)], data_ref[3]);
+        assert_eq!(matrix[(1, 0)], data_ref[4]);
+        assert_eq!(matrix[(1, 1)], factor * data_ref[5]);
+        assert_eq!(matrix[(1, 2)], factor * data_ref[6]);
+        assert_eq!(matrix[(1, 3)], data_ref[7]);
+        assert_eq!(matrix[(2, 0)], data_ref[8]);
+        assert_eq!(matrix[(2, 1)], factor * data_ref[9]);
+        assert_eq!(matrix[(2, 2)], factor * data_ref[10]);
+        assert_eq!(matrix[(2, 3)], data_ref[11]);
+        assert_eq!(matrix[(3, 0)], data_ref[12]);
+        assert_eq!(matrix[(3, 1)], data_ref[13]);
+        assert_eq!(matrix[(3, 2)], data_ref[14]);
+        assert_eq!(matrix[(3, 3)], data_ref[15]);
+    }
+
+    #[test]
+    fn test_matrix_column_major_view_mut_scale() {
+        let mut matrix: Matrix<i32> = Matrix::new_column_major(4, 4);
+
+        let data_ref: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+        matrix.data = data_ref.clone();
+
+        let factor: i32 = 3;
+        matrix
+            .view_mut(ViewParameters::new(1, 1, 2, 2))
+            .scale(factor);
+
+        for row_id in 0..4 {
+            for col_id in 0..4 {
+                let expected: i32 = data_ref[col_id * 4 + row_id];
+
+                if (1..3).contains(&row_id) && (1..3).contains(&col_id) {
+                    assert_eq!(matrix[(row_id, col_id)], factor * expected);
+                } else {
+                    assert_eq!(matrix[(row_id, col_id)], expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_matrix_fill() {
+        let mut matrix: Matrix<i32> = Matrix::from_fn_column_major(4, 4, |i, j| (4 * i + j) as i32);
+        matrix.view_mut(ViewParameters::new(1, 2, 2, 2)).fill(-1);
+
+        for row_id in 0..4 {
+            for col_id in 0..4 {
+                if (1..3).contains(&row_id) && (2..4).contains(&col_id) {
+                    assert_eq!(matrix[(row_id, col_id)], -1);
+                } else {
+                    assert_eq!(matrix[(row_id, col_id)], (4 * row_id + col_id) as i32);
+                }
+            }
+        }
+
+        matrix.fill(7);
+        assert_eq!(matrix, Matrix::filled(4, 4, 7));
+    }
+
+    #[test]
+    fn test_matrix_view_mut_swap_rows_and_cols() {
+        let mut matrix: Matrix<i32> =
+            Matrix::from_fn_column_major(3, 3, |i, j| (10 * i + j) as i32);
+
+        matrix.full_view_mut().swap_rows(0, 2);
+        assert_eq!(
+            matrix.full_view(),
+            Matrix::from_rows(&[&[20, 21, 22], &[10, 11, 12], &[0, 1, 2]]).unwrap()
+        );
+
+        matrix.full_view_mut().swap_cols(2, 1);
+        assert_eq!(
+            matrix.full_view(),
+            Matrix::from_rows(&[&[20, 22, 21], &[10, 12, 11], &[0, 2, 1]]).unwrap()
+        );
+
+        // Swap inside a sub-view leaves other elements untouched
+        matrix
+            .view_mut(ViewParameters::new(1, 1, 2, 2))
+            .swap_rows(0, 1);
+        assert_eq!(
+            matrix.full_view(),
+            Matrix::from_rows(&[&[20, 22, 21], &[10, 2, 1], &[0, 12, 11]]).unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "row index 3 is out of bounds of view with 3 rows")]
+    fn test_matrix_view_mut_swap_rows_out_of_bounds() {
+        let mut matrix: Matrix<i32> = Matrix::zeros(3, 3);
+        matrix.full_view_mut().swap_rows(0, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "column index 2 is out of bounds of view with 2 columns")]
+    fn test_matrix_view_mut_swap_cols_out_of_bounds() {
+        let mut matrix: Matrix<i32> = Matrix::zeros(3, 2);
+        matrix.full_view_mut().swap_cols(2, 0);
+    }
+
+    #[test]
+    fn test_matrix_scale() {
+        let mut matrix: Matrix<f64> = Matrix::from_rows(&[&[1.0, -2.0], &[0.5, 4.0]]).unwrap();
+        matrix.scale(2.0);
+
+        let expected: Matrix<f64> = Matrix::from_rows(&[&[2.0, -4.0], &[1.0, 8.0]]).unwrap();
+        assert_eq!(matrix, expected);
+    }
+
+    #[test]
+    fn test_matrix_vandermonde() {
+        let matrix: Matrix<f64> = Matrix::vandermonde(&[2.0, -1.0, 0.5], 2);
+
+        assert_eq!(matrix.nb_rows(), 3);
+        assert_eq!(matrix.nb_cols(), 3);
+        assert_eq!(
+            matrix,
+            Matrix::from_rows(&[&[1.0, 2.0, 4.0], &[1.0, -1.0, 1.0], &[1.0, 0.5, 0.25]]).unwrap()
+        );
+
+        let constant: Matrix<i32> = Matrix::vandermonde(&[3, 4], 0);
+        assert_eq!(constant, Matrix::ones(2, 1));
+    }
+
+    #[test]
+    fn test_matrix_mirror_lower_to_upper() {
+        let mut matrix: Matrix<i32> =
+            Matrix::from_rows(&[&[1, 0, 0], &[2, 3, 0], &[4, 5, 6]]).unwrap();
+        matrix.mirror_lower_to_upper().unwrap();
+
+        let expected: Matrix<i32> =
+            Matrix::from_rows(&[&[1, 2, 4], &[2, 3, 5], &[4, 5, 6]]).unwrap();
+        assert_eq!(matrix, expected);
+        assert_eq!(matrix.full_view(), matrix.transpose_view());
+
+        let mut rectangular: Matrix<i32> = Matrix::new_row_major(2, 3);
+        assert_eq!(
+            rectangular.mirror_lower_to_upper(),
+            Err(ShapeError::NotSquare {
+                nb_rows: 2,
+                nb_cols: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_matrix_column_major_view_mut() {
+        let nb_rows: usize = 4;
+        let nb_cols: usize = 4;
+
+        let mut matrix: Matrix<i32> = Matrix::new_column_major(nb_rows, nb_cols);
+
+        let data_ref: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+        matrix.data = data_ref.clone();
+
+        let factor: i32 = 3;
+
+        {
+            let mut view: ViewMut<i32> = matrix.view_mut(ViewParameters::new(1, 1, 2, 2));
+
+            view[(0, 0)] *= factor;
+            view[(0, 1)] *= factor;
+            view[(1, 0)] *= factor;
+            view[(1, 1)] *= factor;
+        }
+
+        assert_eq!(matrix[(0, 0)], data_ref[0]);
+        assert_eq!(matrix[(1, 0)], data_ref[1]);
+        assert_eq!(matrix[(2, 0)], data_ref[2]);
+        assert_eq!(matrix[(3, 0)], data_ref[3]);
+        assert_eq!(matrix[(0, 1)], data_ref[4]);
+        assert_eq!(matrix[(1, 1)], factor * data_ref[5]);
+        assert_eq!(matrix[(2, 1)], factor * data_ref[6]);
+        assert_eq!(matrix[(3, 1)], data_ref[7]);
+        assert_eq!(matrix[(0, 2)], data_ref[8]);
+        assert_eq!(matrix[(1, 2)], factor * data_ref[9]);
+        assert_eq!(matrix[(2, 2)], factor * data_ref[10]);
+        assert_eq!(matrix[(3, 2)], data_ref[11]);
+        assert_eq!(matrix[(0, 3)], data_ref[12]);
+        assert_eq!(matrix[(1, 3)], data_ref[13]);
+        assert_eq!(matrix[(2, 3)], data_ref[14]);
+        assert_eq!(matrix[(3, 3)], data_ref[15]);
+    }
+
+    #[test]
+    fn test_matrix_clone() {
+        let data_ref: Vec<i32> = vec![1, 2, 3, 4, 5, 6];
+
+        let matrix: Matrix<i32> = Matrix::from_vec_column_major(2, 3, data_ref.clone()).unwrap();
+        let mut clone: Matrix<i32> = matrix.clone();
+
+        assert_eq!(clone.nb_rows(), matrix.nb_rows());
+        assert_eq!(clone.nb_cols(), matrix.nb_cols());
+        assert_eq!(clone.data, data_ref);
+
+        let view: View<i32> = matrix.full_view();
+        let clone_view: View<i32> = clone.full_view();
+
+        for i in 0..2 {
+            for j in 0..3 {
+                assert_eq!(clone_view[(i, j)], view[(i, j)]);
+            }
+        }
+
+        clone[(1, 2)] = 60;
+        assert_eq!(matrix[(1, 2)], data_ref[5]);
+        assert_eq!(clone.storage_order(), StorageOrder::ColumnMajor);
+    }
+
+    #[test]
+    fn test_matrix_clone_from_view() {
+        let matrix: Matrix<String> =
+            Matrix::from_fn_column_major(3, 4, |i, j| format!("{}{}", i, j));
+        let mut clone: Matrix<String> =
+            Matrix::clone_from_view(&matrix.view(ViewParameters::new(1, 2, 2, 2)));
+
+        assert_eq!(clone.nb_rows(), 2);
+        assert_eq!(clone.nb_cols(), 2);
+        assert_eq!(clone, matrix.view(ViewParameters::new(1, 2, 2, 2)));
+
+        clone[(0, 0)] = String::from("modified");
+        assert_eq!(matrix[(1, 2)], "12");
+    }
+
+    #[test]
+    fn test_matrix_try_from_nested_vectors() {
+        let rows: Vec<Vec<String>> = vec![
+            vec![String::from("a"), String::from("b")],
+            vec![String::from("c"), String::from("d")],
+            vec![String::from("e"), String::from("f")],
+        ];
+
+        let matrix: Matrix<String> = Matrix::try_from(rows).unwrap();
+
+        assert_eq!(matrix.nb_rows(), 3);
+        assert_eq!(matrix.nb_cols(), 2);
+        assert_eq!(matrix[(0, 1)], "b");
+        assert_eq!(matrix[(2, 0)], "e");
+
+        let ragged: Vec<Vec<i32>> = vec![vec![1, 2], vec![3, 4], vec![5, 6, 7]];
+        let result: Result<Matrix<i32>, ShapeError> = Matrix::try_from(ragged);
+
+        match result {
+            Err(error) => assert_eq!(
+                error,
+                ShapeError::RaggedRow {
+                    row_id: 2,
+                    expected: 2,
+                    actual: 3
+                }
+            ),
+            Ok(_) => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_matrix_row() {
+        let data_ref: Vec<i32> = vec![1, 2, 3, 4, 5, 6];
+
+        let matrix: Matrix<i32> = Matrix::from_vec_row_major(2, 3, data_ref.clone()).unwrap();
+        let row: View<i32> = matrix.row(1);
+
+        assert_eq!(row.nb_rows(), 1);
+        assert_eq!(row.nb_cols(), 3);
+        assert_eq!(row[(0, 0)], data_ref[3]);
+        assert_eq!(row[(0, 1)], data_ref[4]);
+        assert_eq!(row[(0, 2)], data_ref[5]);
+
+        let matrix: Matrix<i32> = Matrix::from_vec_column_major(2, 3, data_ref.clone()).unwrap();
+        let row: View<i32> = matrix.row(1);
+
+        assert_eq!(row.nb_rows(), 1);
+        assert_eq!(row.nb_cols(), 3);
+        assert_eq!(row[(0, 0)], data_ref[1]);
+        assert_eq!(row[(0, 1)], data_ref[3]);
+        assert_eq!(row[(0, 2)], data_ref[5]);
+    }
+
+    #[test]
+    fn test_matrix_row_mut() {
+        let mut matrix: Matrix<i32> =
+            Matrix::from_vec_column_major(3, 2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+
+        {
+            let mut row: ViewMut<i32> = matrix.row_mut(2);
+
+            row[(0, 0)] = 30;
+            row[(0, 1)] = 60;
+        }
+
+        assert_eq!(matrix.data, vec![1, 2, 30, 4, 5, 60]);
+    }
+
+    #[test]
+    #[should_panic(expected = "row index 2 is out of bounds of matrix with 2 rows")]
+    fn test_matrix_row_out_of_bounds() {
+        let matrix: Matrix<i32> = Matrix::new_row_major(2, 3);
+        let _ = matrix.row(2);
+    }
+
+    #[test]
+    fn test_matrix_split_at_row() {
+        let matrix: Matrix<i32> =
+            Matrix::from_fn_column_major(4, 3, |i, j| 10 * i as i32 + j as i32);
+
+        let (top, bottom): (View<i32>, View<i32>) = matrix.split_at_row(1);
+        assert_eq!(top.nb_rows(), 1);
+        assert_eq!(bottom.nb_rows(), 3);
+        assert_eq!(top, matrix.row(0));
+        assert_eq!(bottom[(0, 2)], 12);
+        assert_eq!(bottom[(2, 0)], 30);
+
+        let (top, bottom): (View<i32>, View<i32>) = matrix.split_at_row(4);
+        assert_eq!(top, matrix.full_view());
+        assert_eq!(bottom.nb_rows(), 0);
+    }
+
+    #[test]
+    fn test_matrix_split_at_row_mut() {
+        let mut matrix: Matrix<i32> = Matrix::zeros(4, 3);
+
+        {
+            let (mut top, mut bottom): (ViewMut<i32>, ViewMut<i32>) =
+                matrix.split_at_row_mut(3).unwrap();
+            assert_eq!(top.nb_rows(), 3);
+            assert_eq!(bottom.nb_rows(), 1);
+
+            top.fill(1);
+            bottom.fill(2);
+            top[(0, 1)] = 5;
+            bottom[(0, 2)] = 7;
+        }
+
+        let expected: Matrix<i32> =
+            Matrix::from_rows(&[&[1, 5, 1], &[1, 1, 1], &[1, 1, 1], &[2, 2, 7]]).unwrap();
+        assert_eq!(matrix, expected);
+
+        let mut column_major: Matrix<i32> = Matrix::new_column_major(4, 3);
+        assert_eq!(
+            column_major.split_at_row_mut(2).err(),
+            Some(ShapeError::InterleavedRows)
+        );
+
+        // Column-major matrix with one column has rows one after another
+        let mut column: Matrix<i32> = Matrix::from_vec_column_major(3, 1, vec![1, 2, 3]).unwrap();
+        let (top, bottom): (ViewMut<i32>, ViewMut<i32>) = column.split_at_row_mut(1).unwrap();
+        assert_eq!(top[(0, 0)], 1);
+        assert_eq!(bottom[(1, 0)], 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "split row index 5 is out of bounds of matrix with 4 rows")]
+    fn test_matrix_split_at_row_out_of_bounds() {
+        let matrix: Matrix<i32> = Matrix::zeros(4, 3);
+        matrix.split_at_row(5);
+    }
+
+    #[test]
+    fn test_matrix_split_at_col() {
+        for matrix in [
+            Matrix::from_fn_row_major(3, 4, |i, j| 10 * i as i32 + j as i32),
+            Matrix::from_fn_column_major(3, 4, |i, j| 10 * i as i32 + j as i32),
+        ] {
+            let (left, right): (View<i32>, View<i32>) = matrix.split_at_col(1);
+            assert_eq!(left.nb_cols(), 1);
+            assert_eq!(right.nb_cols(), 3);
+            assert_eq!(left, matrix.col(0));
+            assert_eq!(right[(0, 2)], 3);
+            assert_eq!(right[(2, 0)], 21);
+
+            let (left, right): (View<i32>, View<i32>) = matrix.split_at_col(0);
+            assert_eq!(left.nb_cols(), 0);
+            assert_eq!(right, matrix.full_view());
+        }
+    }
+
+    #[test]
+    fn test_matrix_split_at_col_mut() {
+        let mut matrix: Matrix<i32> = Matrix::new_column_major(3, 4);
+
+        {
+            let (mut left, mut right): (ViewMut<i32>, ViewMut<i32>) =
+                matrix.split_at_col_mut(1).unwrap();
+            assert_eq!(left.nb_cols(), 1);
+            assert_eq!(right.nb_cols(), 3);
+
+            left.fill(1);
+            right.fill(2);
+            left[(2, 0)] = 5;
+            right[(0, 2)] = 7;
+        }
+
+        let expected: Matrix<i32> =
+            Matrix::from_rows(&[&[1, 2, 2, 7], &[1, 2, 2, 2], &[5, 2, 2, 2]]).unwrap();
+        assert_eq!(matrix, expected);
+
+        let mut row_major: Matrix<i32> = Matrix::new_row_major(3, 4);
+        assert_eq!(
+            row_major.split_at_col_mut(2).err(),
+            Some(ShapeError::InterleavedCols)
+        );
+
+        // Row-major matrix with one row has columns one after another
+        let mut row: Matrix<i32> = Matrix::from_vec_row_major(1, 3, vec![1, 2, 3]).unwrap();
+        let (left, right): (ViewMut<i32>, ViewMut<i32>) = row.split_at_col_mut(2).unwrap();
+        assert_eq!(left[(0, 1)], 2);
+        assert_eq!(right[(0, 0)], 3);
+    }
+
+    #[test]
+    fn test_matrix_diagonal() {
+        let matrix: Matrix<usize> = Matrix::from_fn_row_major(3, 3, |i, j| 10 * i + j);
+        let diag: View<usize> = matrix.diagonal();
+
+        assert_eq!(diag.nb_rows(), 1);
+        assert_eq!(diag.nb_cols(), 3);
+
+        for k in 0..3 {
+            assert_eq!(diag[(0, k)], 11 * k);
+        }
+
+        let matrix: Matrix<usize> = Matrix::from_fn_column_major(4, 2, |i, j| 10 * i + j);
+        let diag: View<usize> = matrix.diagonal();
+
+        assert_eq!(diag.nb_cols(), 2);
+        assert_eq!(diag[(0, 0)], 0);
+        assert_eq!(diag[(0, 1)], 11);
+
+        let matrix: Matrix<usize> = Matrix::from_fn_row_major(2, 4, |i, j| 10 * i + j);
+        assert_eq!(
+            matrix.diagonal().iter().copied().collect::<Vec<usize>>(),
+            vec![0, 11]
+        );
+    }
+
+    #[test]
+    fn test_matrix_diagonal_offset() {
+        for matrix in [
+            Matrix::from_fn_row_major(3, 5, |i, j| 10 * i + j),
+            Matrix::from_fn_column_major(3, 5, |i, j| 10 * i + j),
+        ] {
+            let diagonal = |k: isize| {
+                return matrix
+                    .diagonal_offset(k)
+                    .iter()
+                    .copied()
+                    .collect::<Vec<usize>>();
+            };
+
+            assert_eq!(diagonal(0), vec![0, 11, 22]);
+            assert_eq!(diagonal(1), vec![1, 12, 23]);
+            assert_eq!(diagonal(3), vec![3, 14]);
+            assert_eq!(diagonal(4), vec![4]);
+            assert_eq!(diagonal(-1), vec![10, 21]);
+            assert_eq!(diagonal(-2), vec![20]);
+            assert_eq!(diagonal(5), Vec::<usize>::new());
+            assert_eq!(diagonal(-3), Vec::<usize>::new());
+        }
+
+        let mut matrix: Matrix<i32> = Matrix::new_column_major(4, 3);
+        matrix.diagonal_offset_mut(-1).fill(1);
+        matrix.diagonal_offset_mut(2).fill(2);
+
+        let expected: Matrix<i32> =
+            Matrix::from_rows(&[&[0, 0, 2], &[1, 0, 0], &[0, 1, 0], &[0, 0, 1]]).unwrap();
+        assert_eq!(matrix, expected);
+    }
+
+    #[test]
+    fn test_matrix_diagonal_mut() {
+        let mut matrix: Matrix<f64> = Matrix::zeros(3, 2);
+        matrix.diagonal_mut().apply(|x| *x += 2.0);
+
+        assert_eq!(matrix[(0, 0)], 2.0);
+        assert_eq!(matrix[(1, 1)], 2.0);
+        assert_eq!(matrix[(0, 1)], 0.0);
+        assert_eq!(matrix[(2, 1)], 0.0);
+    }
+
+    #[test]
+    fn test_matrix_rows() {
+        let data_ref: Vec<i32> = vec![1, 2, 3, 4, 5, 6];
 
-        let mut matrix: Matrix<i32> = Matrix::new_row_major(nb_rows, nb_cols);
+        for matrix in [
+            Matrix::from_vec_row_major(2, 3, data_ref.clone()).unwrap(),
+            Matrix::from_vec_column_major(3, 2, vec![1, 4, 2, 5, 3, 6]).unwrap(),
+        ] {
+            let mut nb_rows: usize = 0;
 
-        let data_ref: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
-        matrix.data = data_ref.clone();
+            for (row_id, row) in matrix.rows().enumerate() {
+                assert_eq!(row.nb_cols(), matrix.nb_cols());
 
-        let factor: i32 = 3;
+                for col_id in 0..row.nb_cols() {
+                    assert_eq!(row[(0, col_id)], matrix[(row_id, col_id)]);
+                }
 
-        {
-            let mut view: ViewMut<i32> = matrix.full_view_mut();
+                nb_rows += 1;
+            }
 
-            view[(1, 2)] *= factor;
-            view[(2, 1)] *= factor;
+            assert_eq!(nb_rows, matrix.nb_rows());
         }
+    }
 
-        assert_eq!(matrix[(0, 0)], data_ref[0]);
-        assert_eq!(matrix[(0, 1)], data_ref[1]);
-        assert_eq!(matrix[(0, 2)], data_ref[2]);
-        assert_eq!(matrix[(1, 0)], data_ref[3]);
-        assert_eq!(matrix[(1, 1)], data_ref[4]);
-        assert_eq!(matrix[(1, 2)], factor * data_ref[5]);
-        assert_eq!(matrix[(2, 0)], data_ref[6]);
-        assert_eq!(matrix[(2, 1)], factor * data_ref[7]);
-        assert_eq!(matrix[(2, 2)], data_ref[8]);
+    #[test]
+    fn test_matrix_cols() {
+        let matrix: Matrix<i32> =
+            Matrix::from_vec_column_major(3, 3, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]).unwrap();
+
+        let firsts: Vec<i32> = matrix.cols().map(|col| col[(0, 0)]).collect();
+        assert_eq!(firsts, vec![1, 4, 7]);
+
+        let lasts: Vec<i32> = matrix.cols().rev().map(|col| col[(2, 0)]).collect();
+        assert_eq!(lasts, vec![9, 6, 3]);
     }
 
     #[test]
-    fn test_matrix_column_major_full_view_mut() {
-        let nb_rows: usize = 3;
-        let nb_cols: usize = 3;
+    fn test_matrix_col() {
+        let data_ref: Vec<i32> = vec![1, 2, 3, 4, 5, 6];
+
+        let matrix: Matrix<i32> = Matrix::from_vec_row_major(3, 2, data_ref.clone()).unwrap();
+        let col: View<i32> = matrix.col(1);
+
+        assert_eq!(col.nb_rows(), 3);
+        assert_eq!(col.nb_cols(), 1);
+        assert_eq!(col[(0, 0)], data_ref[1]);
+        assert_eq!(col[(1, 0)], data_ref[3]);
+        assert_eq!(col[(2, 0)], data_ref[5]);
+
+        let matrix: Matrix<i32> = Matrix::from_vec_column_major(3, 2, data_ref.clone()).unwrap();
+        let col: View<i32> = matrix.col(1);
+
+        assert_eq!(col.nb_rows(), 3);
+        assert_eq!(col.nb_cols(), 1);
+        assert_eq!(col[(0, 0)], data_ref[3]);
+        assert_eq!(col[(1, 0)], data_ref[4]);
+        assert_eq!(col[(2, 0)], data_ref[5]);
+    }
 
-        let mut matrix: Matrix<i32> = Matrix::new_column_major(nb_rows, nb_cols);
+    #[test]
+    fn test_matrix_col_mut() {
+        let mut matrix: Matrix<i32> =
+            Matrix::from_vec_row_major(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+
+        {
+            let mut col: ViewMut<i32> = matrix.col_mut(0);
 
+            col[(0, 0)] = 10;
+            col[(1, 0)] = 40;
+        }
+
+        assert_eq!(matrix.data, vec![10, 2, 3, 40, 5, 6]);
+
+        let mut matrix: Matrix<i32> =
+            Matrix::from_vec_column_major(2, 3, vec![1, 4, 2, 5, 3, 6]).unwrap();
+        matrix.col_mut(2).fill(0);
+
+        assert_eq!(
+            matrix.full_view(),
+            Matrix::from_rows(&[&[1, 2, 0], &[4, 5, 0]]).unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "column index 3 is out of bounds of matrix with 3 columns")]
+    fn test_matrix_col_out_of_bounds() {
+        let matrix: Matrix<i32> = Matrix::new_column_major(2, 3);
+        let _ = matrix.col(3);
+    }
+
+    #[test]
+    fn test_matrix_move_row_to_front() {
         let data_ref: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
-        matrix.data = data_ref.clone();
 
-        let factor: i32 = 3;
+        let mut matrix: Matrix<i32> = Matrix::from_vec_row_major(3, 3, data_ref.clone()).unwrap();
+        matrix.move_row_to_front(2).unwrap();
 
-        {
-            let mut view: ViewMut<i32> = matrix.full_view_mut();
+        let view: View<i32> = matrix.full_view();
+        let expected: Vec<i32> = vec![7, 8, 9, 1, 2, 3, 4, 5, 6];
 
-            view[(1, 0)] *= factor;
-            view[(2, 1)] *= factor;
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_eq!(view[(i, j)], expected[3 * i + j]);
+            }
         }
 
-        assert_eq!(matrix[(0, 0)], data_ref[0]);
-        assert_eq!(matrix[(1, 0)], factor * data_ref[1]);
-        assert_eq!(matrix[(2, 0)], data_ref[2]);
-        assert_eq!(matrix[(0, 1)], data_ref[3]);
-        assert_eq!(matrix[(1, 1)], data_ref[4]);
-        assert_eq!(matrix[(2, 1)], factor * data_ref[5]);
-        assert_eq!(matrix[(0, 2)], data_ref[6]);
-        assert_eq!(matrix[(1, 2)], data_ref[7]);
-        assert_eq!(matrix[(2, 2)], data_ref[8]);
+        let mut matrix: Matrix<i32> = Matrix::from_vec_column_major(3, 3, data_ref).unwrap();
+        matrix.move_row_to_front(2).unwrap();
+        assert_eq!(matrix.data, vec![3, 1, 2, 6, 4, 5, 9, 7, 8]);
+
+        assert_eq!(
+            matrix.move_row_to_front(3),
+            Err(ShapeError::RowOutOfBounds {
+                row_id: 3,
+                nb_rows: 3
+            })
+        );
     }
 
     #[test]
-    fn test_matrix_row_major_view() {
-        let nb_rows: usize = 4;
-        let nb_cols: usize = 4;
+    fn test_matrix_move_col_to_front() {
+        let data_ref: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
 
-        let mut matrix: Matrix<i32> = Matrix::new_row_major(nb_rows, nb_cols);
+        let mut matrix: Matrix<i32> = Matrix::from_vec_row_major(3, 3, data_ref.clone()).unwrap();
+        matrix.move_col_to_front(1).unwrap();
+        assert_eq!(matrix.data, vec![2, 1, 3, 5, 4, 6, 8, 7, 9]);
 
-        let data_ref: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
-        matrix.data = data_ref.clone();
+        let mut matrix: Matrix<i32> = Matrix::from_vec_column_major(3, 3, data_ref).unwrap();
+        matrix.move_col_to_front(2).unwrap();
+        assert_eq!(matrix.data, vec![7, 8, 9, 1, 2, 3, 4, 5, 6]);
 
-        let view: View<i32> = matrix.view(ViewParameters::new(1, 1, 2, 2));
+        assert_eq!(
+            matrix.move_col_to_front(5),
+            Err(ShapeError::ColumnOutOfBounds {
+                col_id: 5,
+                nb_cols: 3
+            })
+        );
+    }
 
-        assert_eq!(view[(0, 0)], data_ref[5]);
-        assert_eq!(view[(0, 1)], data_ref[6]);
-        assert_eq!(view[(1, 0)], data_ref[9]);
-        assert_eq!(view[(1, 1)], data_ref[10]);
+    #[test]
+    fn test_matrix_sort_rows_by_col() {
+        let mut matrix: Matrix<i32> =
+            Matrix::from_vec_column_major(5, 2, vec![3, 1, 2, 1, 0, 10, 11, 12, 13, 14]).unwrap();
+
+        let permutation: Vec<usize> = matrix.sort_rows_by_col(0);
+
+        // Rows 1 and 3 have the same key and keep their relative order
+        assert_eq!(permutation, vec![4, 1, 3, 2, 0]);
+        assert_eq!(matrix.data, vec![0, 1, 1, 2, 3, 14, 11, 13, 12, 10]);
     }
 
     #[test]
-    fn test_matrix_column_major_view() {
-        let nb_rows: usize = 4;
-        let nb_cols: usize = 4;
+    fn test_matrix_sort_rows_by_col_row_major() {
+        let mut matrix: Matrix<f64> =
+            Matrix::from_rows(&[&[1.0, f64::NAN], &[2.0, 0.5], &[3.0, -1.0]]).unwrap();
 
-        let mut matrix: Matrix<i32> = Matrix::new_column_major(nb_rows, nb_cols);
+        let permutation: Vec<usize> = matrix.sort_rows_by_col(1);
 
-        let data_ref: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
-        matrix.data = data_ref.clone();
+        assert_eq!(permutation, vec![2, 1, 0]);
+        assert_eq!(matrix[(0, 0)], 3.0);
+        assert_eq!(matrix[(1, 0)], 2.0);
+        assert_eq!(matrix[(2, 0)], 1.0);
+        assert!(matrix[(2, 1)].is_nan());
+    }
 
-        let view: View<i32> = matrix.view(ViewParameters::new(1, 1, 2, 2));
+    #[test]
+    fn test_matrix_swap_col_blocks() {
+        let mut matrix: Matrix<i32> = Matrix::from_fn_row_major(3, 5, |i, j| (10 * i + j) as i32);
+        matrix.swap_col_blocks(0, 3, 2).unwrap();
+
+        let expected: Matrix<i32> = Matrix::from_rows(&[
+            &[3, 4, 2, 0, 1],
+            &[13, 14, 12, 10, 11],
+            &[23, 24, 22, 20, 21],
+        ])
+        .unwrap();
+
+        assert_eq!(matrix.data, expected.data);
+
+        let mut matrix: Matrix<i32> =
+            Matrix::from_fn_column_major(3, 5, |i, j| (10 * i + j) as i32);
+        matrix.swap_col_blocks(3, 0, 2).unwrap();
+
+        for i in 0..3 {
+            for j in 0..5 {
+                assert_eq!(matrix[(i, j)], expected[(i, j)]);
+            }
+        }
+    }
 
-        assert_eq!(view[(0, 0)], data_ref[5]);
-        assert_eq!(view[(0, 1)], data_ref[9]);
-        assert_eq!(view[(1, 0)], data_ref[6]);
-        assert_eq!(view[(1, 1)], data_ref[10]);
+    #[test]
+    fn test_matrix_swap_col_blocks_errors() {
+        let mut matrix: Matrix<i32> = Matrix::new_row_major(3, 5);
+
+        assert_eq!(
+            matrix.swap_col_blocks(0, 1, 2),
+            Err(ShapeError::OverlappingBlocks {
+                first: 0,
+                second: 1,
+                count: 2
+            })
+        );
+
+        assert_eq!(
+            matrix.swap_col_blocks(0, 4, 2),
+            Err(ShapeError::ColumnOutOfBounds {
+                col_id: 5,
+                nb_cols: 5
+            })
+        );
+
+        assert_eq!(
+            matrix.swap_col_blocks(0, usize::MAX, 2),
+            Err(ShapeError::ColumnOutOfBounds {
+                col_id: usize::MAX,
+                nb_cols: 5
+            })
+        );
     }
 
     #[test]
-    fn test_matrix_row_major_view_mut() {
-        let nb_rows: usize = 4;
-        let nb_cols: usize = 4;
+    fn test_matrix_map() {
+        let matrix: Matrix<i32> =
+            Matrix::from_vec_column_major(2, 3, vec![1, 4, 2, 5, 3, 6]).unwrap();
+        let mapped: Matrix<f64> = matrix.map(|&x| x as f64 / 2.0);
 
-        let mut matrix: Matrix<i32> = Matrix::new_row_major(nb_rows, nb_cols);
+        assert_eq!(mapped.nb_rows(), 2);
+        assert_eq!(mapped.nb_cols(), 3);
 
-        let data_ref: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
-        matrix.data = data_ref.clone();
+        let view: View<f64> = mapped.full_view();
 
-        let factor: i32 = 3;
+        assert_eq!(view[(0, 0)], 0.5);
+        assert_eq!(view[(0, 2)], 1.5);
+        assert_eq!(view[(1, 0)], 2.0);
+        assert_eq!(view[(1, 1)], 2.5);
+        assert_eq!(mapped.data, vec![0.5, 2.0, 1.0, 2.5, 1.5, 3.0]);
+    }
 
-        {
-            let mut view: ViewMut<i32> = matrix.view_mut(ViewParameters::new(1, 1, 2, 2));
+    #[test]
+    fn test_matrix_map_padded() {
+        // Padding slots hold zeros which must not be visited
+        let matrix: Matrix<i32> =
+            Matrix::from_raw_parts(2, 2, Accessor::new(1, 3), vec![1, 2, 0, 5, 10, 0]).unwrap();
+        let mapped: Matrix<i32> = matrix.map(|&x| 10 / x);
+
+        assert_eq!(mapped, Matrix::from_rows(&[&[10, 2], &[5, 1]]).unwrap());
+        assert_eq!(mapped.storage_order(), StorageOrder::ColumnMajor);
+        assert_eq!(mapped.data, vec![10, 5, 2, 1]);
+    }
 
-            view[(0, 0)] *= factor;
-            view[(0, 1)] *= factor;
-            view[(1, 0)] *= factor;
-            view[(1, 1)] *= factor;
+    #[test]
+    fn test_matrix_iterators() {
+        let mut matrix: Matrix<i32> =
+            Matrix::from_vec_column_major(2, 3, vec![1, 4, 2, 5, 3, 6]).unwrap();
+
+        let mut iter: ViewIter<i32> = matrix.iter();
+        assert_eq!(iter.len(), 6);
+        iter.next();
+        assert_eq!(iter.size_hint(), (5, Some(5)));
+
+        assert_eq!(matrix.iter().sum::<i32>(), 21);
+        assert_eq!(
+            matrix.iter().copied().collect::<Vec<i32>>(),
+            vec![1, 2, 3, 4, 5, 6]
+        );
+
+        for (index, element) in matrix.iter_mut().enumerate() {
+            *element += 10 * index as i32;
         }
 
-        assert_eq!(matrix[(0, 0)], data_ref[0]);
-        assert_eq!(matrix[(0, 1)], data_ref[1]);
-        assert_eq!(matrix[(0, 2)], data_ref[2]);
-        assert_eq!(matrix[(0, 3)], data_ref[3]);
-        assert_eq!(matrix[(1, 0)], data_ref[4]);
-        assert_eq!(matrix[(1, 1)], factor * data_ref[5]);
-        assert_eq!(matrix[(1, 2)], factor * data_ref[6]);
-        assert_eq!(matrix[(1, 3)], data_ref[7]);
-        assert_eq!(matrix[(2, 0)], data_ref[8]);
-        assert_eq!(matrix[(2, 1)], factor * data_ref[9]);
-        assert_eq!(matrix[(2, 2)], factor * data_ref[10]);
-        assert_eq!(matrix[(2, 3)], data_ref[11]);
-        assert_eq!(matrix[(3, 0)], data_ref[12]);
-        assert_eq!(matrix[(3, 1)], data_ref[13]);
-        assert_eq!(matrix[(3, 2)], data_ref[14]);
-        assert_eq!(matrix[(3, 3)], data_ref[15]);
+        assert_eq!(matrix.data, vec![1, 34, 12, 45, 23, 56]);
+        assert_eq!(matrix.iter_mut().len(), 6);
+
+        let owned: Vec<i32> = matrix.into_iter().collect();
+        assert_eq!(owned, vec![1, 12, 23, 34, 45, 56]);
+
+        let strided: Matrix<String> = Matrix::from_raw_parts(
+            2,
+            2,
+            Accessor::new(1, 3),
+            ["a", "b", "x", "c", "d"].map(String::from).to_vec(),
+        )
+        .unwrap();
+
+        let mut into_iter: MatrixIntoIter<String> = strided.into_iter();
+        assert_eq!(into_iter.len(), 4);
+        assert_eq!(
+            into_iter.by_ref().collect::<Vec<String>>(),
+            ["a", "c", "b", "d"].map(String::from).to_vec()
+        );
+        assert_eq!(into_iter.next(), None);
     }
 
     #[test]
-    fn test_matrix_column_major_view_mut() {
-        let nb_rows: usize = 4;
-        let nb_cols: usize = 4;
+    fn test_matrix_iterators_strided() {
+        let mut padded: Matrix<i32> =
+            Matrix::from_raw_parts(2, 2, Accessor::new_with_offset(3, 1, 0, 1), vec![0; 6])
+                .unwrap();
 
-        let mut matrix: Matrix<i32> = Matrix::new_column_major(nb_rows, nb_cols);
+        for (index, element) in padded.iter_mut().enumerate() {
+            *element = index as i32 + 1;
+        }
 
-        let data_ref: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
-        matrix.data = data_ref.clone();
+        assert_eq!(padded.data, vec![0, 1, 2, 0, 3, 4]);
+        assert_eq!(padded.into_iter().collect::<Vec<i32>>(), vec![1, 2, 3, 4]);
+
+        // Elements left in iterator and padding are dropped with it
+        let strided: Matrix<String> = Matrix::from_raw_parts(
+            2,
+            2,
+            Accessor::new(1, 3),
+            ["a", "b", "x", "c", "d", "y"].map(String::from).to_vec(),
+        )
+        .unwrap();
+
+        let mut into_iter: MatrixIntoIter<String> = strided.into_iter();
+        assert_eq!(into_iter.next(), Some(String::from("a")));
+        assert_eq!(into_iter.len(), 3);
+        drop(into_iter);
+
+        let mut empty: Matrix<i32> = Matrix::zeros(0, 3);
+        assert_eq!(empty.iter_mut().len(), 0);
+        assert_eq!(empty.into_iter().next(), None);
+    }
 
-        let factor: i32 = 3;
+    #[test]
+    #[should_panic(expected = "number of elements of matrix overflows")]
+    fn test_matrix_iter_mut_too_many_elements() {
+        // Constructors reject such a matrix, iterator must not rely on them
+        let mut matrix: Matrix<u64> = Matrix {
+            nb_rows: 2,
+            nb_cols: (1 << (usize::BITS - 1)) + 1,
+            accessor: Accessor::new(1, 2),
+            storage_order: StorageOrder::ColumnMajor,
+            data: vec![1, 2],
+        };
 
-        {
-            let mut view: ViewMut<i32> = matrix.view_mut(ViewParameters::new(1, 1, 2, 2));
+        matrix.iter_mut();
+    }
 
-            view[(0, 0)] *= factor;
-            view[(0, 1)] *= factor;
-            view[(1, 0)] *= factor;
-            view[(1, 1)] *= factor;
+    #[test]
+    #[should_panic(expected = "outside of its data")]
+    fn test_matrix_into_iter_out_of_data() {
+        let matrix: Matrix<u64> = Matrix {
+            nb_rows: 3,
+            nb_cols: 1,
+            accessor: Accessor::new(1 << (usize::BITS - 1), 1),
+            storage_order: StorageOrder::RowMajor,
+            data: vec![7],
+        };
+
+        matrix.into_iter();
+    }
+
+    #[test]
+    #[should_panic(expected = "share memory locations")]
+    fn test_matrix_iter_mut_aliasing() {
+        let mut broadcast: Matrix<i32> =
+            Matrix::from_raw_parts(2, 2, Accessor::new(0, 1), vec![1, 2]).unwrap();
+
+        broadcast.iter_mut();
+    }
+
+    #[test]
+    fn test_matrix_to_row_major_and_column_major() {
+        let matrix: Matrix<i32> =
+            Matrix::from_vec_column_major(2, 3, vec![1, 4, 2, 5, 3, 6]).unwrap();
+
+        let row_major: Matrix<i32> = matrix.to_row_major();
+        assert_eq!(row_major.storage_order(), StorageOrder::RowMajor);
+        assert_eq!(row_major.data, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(row_major, matrix);
+
+        let column_major: Matrix<i32> = row_major.to_column_major();
+        assert_eq!(column_major.storage_order(), StorageOrder::ColumnMajor);
+        assert_eq!(column_major.data, matrix.data);
+
+        let mut copy: Matrix<i32> = row_major.to_row_major();
+        copy[(0, 0)] = 7;
+        assert_eq!(row_major[(0, 0)], 1);
+    }
+
+    #[test]
+    fn test_matrix_interleave_cols() {
+        let red: Matrix<i32> = Matrix::from_rows(&[&[1, 2], &[3, 4], &[5, 6]]).unwrap();
+        let green: Matrix<i32> =
+            Matrix::from_vec_column_major(3, 2, vec![10, 30, 50, 20, 40, 60]).unwrap();
+
+        let interleaved: Matrix<i32> =
+            Matrix::interleave_cols(&[red.full_view(), green.full_view()]).unwrap();
+
+        let expected: Matrix<i32> =
+            Matrix::from_rows(&[&[1, 10, 2, 20], &[3, 30, 4, 40], &[5, 50, 6, 60]]).unwrap();
+        assert_eq!(interleaved, expected);
+
+        let blue: Matrix<i32> = Matrix::zeros(2, 3);
+        assert_eq!(
+            Matrix::interleave_cols(&[red.full_view(), blue.full_view()]).err(),
+            Some(ShapeError::DimensionMismatch {
+                expected: (3, 2),
+                actual: (2, 3)
+            })
+        );
+        assert_eq!(
+            Matrix::<i32>::interleave_cols(&[]).err(),
+            Some(ShapeError::Empty)
+        );
+    }
+
+    #[test]
+    fn test_matrix_transpose() {
+        for matrix in [
+            Matrix::from_rows(&[&[1, 2, 3], &[4, 5, 6]]).unwrap(),
+            Matrix::from_vec_column_major(2, 3, vec![1, 4, 2, 5, 3, 6]).unwrap(),
+        ] {
+            let transposed: Matrix<i32> = matrix.transpose();
+
+            assert_eq!(transposed.nb_rows(), 3);
+            assert_eq!(transposed.nb_cols(), 2);
+            assert_eq!(transposed.storage_order(), matrix.storage_order());
+
+            for row_id in 0..2 {
+                for col_id in 0..3 {
+                    assert_eq!(transposed[(col_id, row_id)], matrix[(row_id, col_id)]);
+                }
+            }
+
+            assert_eq!(transposed, matrix.transpose_view());
         }
+    }
 
-        assert_eq!(matrix[(0, 0)], data_ref[0]);
-        assert_eq!(matrix[(1, 0)], data_ref[1]);
-        assert_eq!(matrix[(2, 0)], data_ref[2]);
-        assert_eq!(matrix[(3, 0)], data_ref[3]);
-        assert_eq!(matrix[(0, 1)], data_ref[4]);
-        assert_eq!(matrix[(1, 1)], factor * data_ref[5]);
-        assert_eq!(matrix[(2, 1)], factor * data_ref[6]);
-        assert_eq!(matrix[(3, 1)], data_ref[7]);
-        assert_eq!(matrix[(0, 2)], data_ref[8]);
-        assert_eq!(matrix[(1, 2)], factor * data_ref[9]);
-        assert_eq!(matrix[(2, 2)], factor * data_ref[10]);
-        assert_eq!(matrix[(3, 2)], data_ref[11]);
-        assert_eq!(matrix[(0, 3)], data_ref[12]);
-        assert_eq!(matrix[(1, 3)], data_ref[13]);
-        assert_eq!(matrix[(2, 3)], data_ref[14]);
-        assert_eq!(matrix[(3, 3)], data_ref[15]);
+    #[test]
+    fn test_matrix_into_storage_order() {
+        for (nb_rows, nb_cols) in [(3, 5), (70, 33), (1, 4)] {
+            let matrix: Matrix<usize> =
+                Matrix::from_fn_row_major(nb_rows, nb_cols, |i, j| 1000 * i + j);
+
+            let column_major: Matrix<usize> =
+                matrix.clone().into_storage_order(StorageOrder::ColumnMajor);
+            assert_eq!(column_major.storage_order(), StorageOrder::ColumnMajor);
+
+            for col_id in 0..nb_cols {
+                for row_id in 0..nb_rows {
+                    assert_eq!(
+                        column_major.data[col_id * nb_rows + row_id],
+                        1000 * row_id + col_id
+                    );
+                }
+            }
+
+            let row_major: Matrix<usize> = column_major.into_storage_order(StorageOrder::RowMajor);
+            assert_eq!(row_major.storage_order(), StorageOrder::RowMajor);
+            assert_eq!(row_major.data, matrix.data);
+        }
+    }
+
+    #[test]
+    fn test_matrix_reshape() {
+        let mut matrix: Matrix<usize> =
+            Matrix::from_vec_row_major(2, 6, (0..12).collect()).unwrap();
+        matrix.reshape(3, 4).unwrap();
+
+        assert_eq!(matrix.nb_rows(), 3);
+        assert_eq!(matrix.nb_cols(), 4);
+        assert_eq!(matrix.storage_order(), StorageOrder::RowMajor);
+        assert_eq!(matrix[(1, 0)], 4);
+        assert_eq!(matrix[(2, 3)], 11);
+        assert_eq!(matrix, Matrix::from_fn_row_major(3, 4, |i, j| 4 * i + j));
+
+        let mut column_major: Matrix<usize> =
+            Matrix::from_vec_column_major(2, 6, (0..12).collect()).unwrap();
+        column_major.reshape(4, 3).unwrap();
+        assert_eq!(
+            column_major,
+            Matrix::from_fn_column_major(4, 3, |i, j| 4 * j + i)
+        );
+
+        assert_eq!(
+            matrix.reshape(5, 2),
+            Err(ShapeError::LengthMismatch {
+                expected: 12,
+                actual: 10
+            })
+        );
+
+        let mut shifted: Matrix<i32> =
+            Matrix::from_raw_parts(2, 2, Accessor::new_with_offset(2, 1, 1, 0), vec![0; 6])
+                .unwrap();
+        assert_eq!(shifted.reshape(1, 4), Err(ShapeError::NotContiguous));
+        assert_eq!(shifted.nb_rows(), 2);
+    }
+
+    #[test]
+    fn test_matrix_reshape_to() {
+        let mut matrix: Matrix<usize> =
+            Matrix::from_vec_row_major(2, 6, (0..12).collect()).unwrap();
+        matrix.reshape_to(3, 4, StorageOrder::ColumnMajor).unwrap();
+
+        assert_eq!(matrix.nb_rows(), 3);
+        assert_eq!(matrix.nb_cols(), 4);
+        assert_eq!(matrix.storage_order(), StorageOrder::ColumnMajor);
+        assert_eq!(matrix, Matrix::from_fn_row_major(3, 4, |i, j| 4 * i + j));
+
+        // Column-major data is read in column-major order
+        matrix.reshape_to(6, 2, StorageOrder::ColumnMajor).unwrap();
+        assert_eq!(matrix.data, vec![0, 4, 8, 1, 5, 9, 2, 6, 10, 3, 7, 11]);
+        assert_eq!(matrix[(1, 0)], 4);
+        assert_eq!(matrix[(0, 1)], 2);
+    }
+
+    #[test]
+    fn test_matrix_reshape_to_errors() {
+        let mut matrix: Matrix<i32> = Matrix::zeros(2, 6);
+
+        assert_eq!(
+            matrix.reshape_to(5, 2, StorageOrder::RowMajor),
+            Err(ShapeError::LengthMismatch {
+                expected: 12,
+                actual: 10
+            })
+        );
+
+        let mut padded: Matrix<i32> =
+            Matrix::from_raw_parts(2, 2, Accessor::new(1, 3), vec![0; 6]).unwrap();
+        assert_eq!(
+            padded.reshape_to(1, 4, StorageOrder::RowMajor),
+            Err(ShapeError::NotContiguous)
+        );
+
+        let mut shifted: Matrix<i32> =
+            Matrix::from_raw_parts(2, 2, Accessor::new_with_offset(2, 1, 1, 0), vec![0; 6])
+                .unwrap();
+        assert_eq!(
+            shifted.reshape_to(1, 4, StorageOrder::RowMajor),
+            Err(ShapeError::NotContiguous)
+        );
+    }
+
+    #[test]
+    fn test_matrix_into_same_storage_order() {
+        let matrix: Matrix<i32> =
+            Matrix::from_vec_column_major(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let data_ptr: *const i32 = matrix.data.as_ptr();
+
+        let converted: Matrix<i32> = matrix.into_storage_order(StorageOrder::ColumnMajor);
+
+        assert_eq!(converted.data.as_ptr(), data_ptr);
+        assert_eq!(converted.data, vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_matrix_partial_eq() {
+        let row_major: Matrix<f64> =
+            Matrix::from_vec_row_major(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        let column_major: Matrix<f64> =
+            Matrix::from_vec_column_major(2, 3, vec![1.0, 4.0, 2.0, 5.0, 3.0, 6.0]).unwrap();
+
+        assert_eq!(row_major, column_major);
+
+        let reshaped: Matrix<f64> =
+            Matrix::from_vec_row_major(3, 2, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        assert_ne!(row_major, reshaped);
+
+        let mut modified: Matrix<f64> = column_major.clone();
+        modified[(1, 2)] = 7.0;
+        assert_ne!(row_major, modified);
+
+        let with_nan: Matrix<f64> = Matrix::filled(1, 1, f64::NAN);
+        assert_ne!(with_nan, with_nan.clone());
+    }
+
+    #[test]
+    fn test_matrix_partial_eq_with_view() {
+        let matrix: Matrix<i32> = Matrix::from_fn_row_major(3, 4, |i, j| (10 * i + j) as i32);
+        let column_major: Matrix<i32> =
+            matrix.clone().into_storage_order(StorageOrder::ColumnMajor);
+
+        assert_eq!(matrix, column_major);
+        assert_eq!(matrix, column_major.full_view());
+        assert_eq!(matrix.full_view(), column_major);
+        assert_eq!(matrix, matrix.full_view());
+
+        assert_ne!(matrix, matrix.view(ViewParameters::new(0, 0, 3, 3)));
+        assert_ne!(matrix.transpose_view(), matrix);
+        assert!(
+            matrix.view(ViewParameters::new(1, 1, 2, 3))
+                == matrix.view(ViewParameters::new(1, 1, 2, 3))
+        );
+    }
+
+    #[test]
+    fn test_matrix_display() {
+        let matrix: Matrix<f64> =
+            Matrix::from_vec_column_major(2, 2, vec![1.5, -2.0, 10.0, 0.25]).unwrap();
+
+        assert_eq!(matrix.to_string(), "1.5   10\n -2 0.25");
+    }
+
+    #[test]
+    fn test_matrix_debug() {
+        let matrix: Matrix<i32> =
+            Matrix::from_vec_column_major(2, 3, vec![1, 4, 2, 5, 3, 6]).unwrap();
+
+        assert_eq!(
+            format!("{:?}", matrix),
+            "Matrix { nb_rows: 2, nb_cols: 3, storage_order: ColumnMajor, strides: (1, 2), elements: [[1, 2, 3], [4, 5, 6]] }"
+        );
+
+        let matrix: Matrix<i32> = Matrix::from_vec_row_major(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+
+        assert_eq!(
+            format!("{:?}", matrix),
+            "Matrix { nb_rows: 2, nb_cols: 3, storage_order: RowMajor, strides: (3, 1), elements: [[1, 2, 3], [4, 5, 6]] }"
+        );
+    }
+
+    #[test]
+    fn test_matrix_unique_rows() {
+        let matrix: Matrix<i32> =
+            Matrix::from_vec_column_major(5, 2, vec![1, 2, 1, 3, 2, 10, 20, 10, 30, 20]).unwrap();
+
+        let (unique, mapping): (Matrix<i32>, Vec<usize>) = matrix.unique_rows();
+
+        assert_eq!(unique.nb_rows(), 3);
+        assert_eq!(unique.nb_cols(), 2);
+        assert_eq!(unique.data, vec![1, 10, 2, 20, 3, 30]);
+        assert_eq!(mapping, vec![0, 1, 0, 2, 1]);
+    }
+
+    #[test]
+    fn test_matrix_unique_rows_edge_cases() {
+        let empty: Matrix<i32> = Matrix::new_row_major(0, 3);
+        let (unique, mapping): (Matrix<i32>, Vec<usize>) = empty.unique_rows();
+
+        assert_eq!(unique.nb_rows(), 0);
+        assert_eq!(unique.nb_cols(), 3);
+        assert!(mapping.is_empty());
+
+        let identical: Matrix<i32> = Matrix::filled(4, 2, 7);
+        let (unique, mapping): (Matrix<i32>, Vec<usize>) = identical.unique_rows();
+
+        assert_eq!(unique.nb_rows(), 1);
+        assert_eq!(unique.data, vec![7, 7]);
+        assert_eq!(mapping, vec![0, 0, 0, 0]);
+
+        // Rows of padded matrix are not contiguous slices of its data
+        let padded: Matrix<i32> =
+            Matrix::from_raw_parts(3, 2, Accessor::new(3, 1), vec![1, 2, 0, 3, 4, 0, 1, 2, 0])
+                .unwrap();
+        let (unique, mapping): (Matrix<i32>, Vec<usize>) = padded.unique_rows();
+
+        assert_eq!(unique.data, vec![1, 2, 3, 4]);
+        assert_eq!(mapping, vec![0, 1, 0]);
+
+        let row_major: Matrix<i32> = Matrix::from_fn_row_major(4, 3, |i, _| (i % 2) as i32);
+        let (unique, mapping): (Matrix<i32>, Vec<usize>) = row_major.unique_rows();
+
+        assert_eq!(unique.data, vec![0, 0, 0, 1, 1, 1]);
+        assert_eq!(mapping, vec![0, 1, 0, 1]);
+    }
+
+    #[test]
+    fn test_matrix_unique_rows_with_tolerance() {
+        let matrix: Matrix<f64> =
+            Matrix::from_rows(&[&[1.0, 2.0], &[1.0 + 1e-10, 2.0], &[1.1, 2.0]]).unwrap();
+
+        let (unique, mapping): (Matrix<f64>, Vec<usize>) = matrix.unique_rows_with_tolerance(1e-8);
+
+        assert_eq!(unique.nb_rows(), 2);
+        assert_eq!(unique.data, vec![1.0, 2.0, 1.1, 2.0]);
+        assert_eq!(mapping, vec![0, 0, 1]);
     }
 }
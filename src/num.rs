@@ -0,0 +1,96 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Zero
+/// This trait gives the additive identity of a numeric type
+pub trait Zero {
+    fn zero() -> Self;
+}
+
+/// One
+/// This trait gives the multiplicative identity of a numeric type
+pub trait One {
+    fn one() -> Self;
+}
+
+/// Float
+/// This trait gathers operations on floating point numbers needed by numerical algorithms
+pub trait Float:
+    Copy
+    + PartialOrd
+    + Zero
+    + One
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    /// Get not a number value
+    fn nan() -> Self;
+
+    /// Convert an unsigned integer to floating point number
+    fn from_usize(value: usize) -> Self;
+
+    /// Convert a double precision floating point number to floating point number
+    fn from_f64(value: f64) -> Self;
+
+    /// Compute square root
+    fn sqrt(self) -> Self;
+
+    /// Compute absolute value
+    fn abs(self) -> Self;
+
+    /// Check that number is neither infinite nor not a number
+    fn is_finite(self) -> bool;
+}
+
+macro_rules! impl_zero_one {
+    ($zero:expr, $one:expr, $($t:ty)*) => ($(
+        impl Zero for $t {
+            fn zero() -> Self {
+                return $zero;
+            }
+        }
+
+        impl One for $t {
+            fn one() -> Self {
+                return $one;
+            }
+        }
+    )*)
+}
+
+impl_zero_one!(0, 1, i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+impl_zero_one!(0.0, 1.0, f32 f64);
+
+macro_rules! impl_float {
+    ($($t:ident)*) => ($(
+        impl Float for $t {
+            fn nan() -> Self {
+                return $t::NAN;
+            }
+
+            fn from_usize(value: usize) -> Self {
+                return value as $t;
+            }
+
+            fn from_f64(value: f64) -> Self {
+                return value as $t;
+            }
+
+            fn sqrt(self) -> Self {
+                return $t::sqrt(self);
+            }
+
+            fn abs(self) -> Self {
+                return $t::abs(self);
+            }
+
+            fn is_finite(self) -> bool {
+                return $t::is_finite(self);
+            }
+        }
+    )*)
+}
+
+impl_float!(f32 f64);
@@ -0,0 +1,73 @@
+use rand::distr::{Distribution, StandardUniform};
+use rand::Rng;
+
+use super::matrix::Matrix;
+
+impl<T> Matrix<T> {
+    /// Create a row-major matrix from number of rows and columns of matrix
+    /// where elements are drawn from the standard distribution of T with given random generator
+    pub fn random<R>(nb_rows: usize, nb_cols: usize, rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized,
+        StandardUniform: Distribution<T>,
+    {
+        return Self::random_with(nb_rows, nb_cols, &StandardUniform, rng);
+    }
+
+    /// Create a row-major matrix from number of rows and columns of matrix
+    /// where elements are drawn from given distribution with given random generator
+    pub fn random_with<D, R>(nb_rows: usize, nb_cols: usize, distribution: &D, rng: &mut R) -> Self
+    where
+        D: Distribution<T>,
+        R: Rng + ?Sized,
+    {
+        return Self::from_fn_row_major(nb_rows, nb_cols, |_, _| distribution.sample(rng));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::distr::Uniform;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_matrix_random() {
+        let matrix: Matrix<f64> = Matrix::random(3, 4, &mut StdRng::seed_from_u64(42));
+
+        assert_eq!(matrix.nb_rows(), 3);
+        assert_eq!(matrix.nb_cols(), 4);
+
+        for i in 0..3 {
+            for j in 0..4 {
+                assert!(matrix[(i, j)] >= 0.0 && matrix[(i, j)] < 1.0);
+            }
+        }
+
+        // Same seed gives same matrix
+        let other: Matrix<f64> = Matrix::random(3, 4, &mut StdRng::seed_from_u64(42));
+
+        for i in 0..3 {
+            for j in 0..4 {
+                assert_eq!(matrix[(i, j)], other[(i, j)]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_matrix_random_with() {
+        let distribution: Uniform<i32> = Uniform::new_inclusive(-5, 5).unwrap();
+        let matrix: Matrix<i32> =
+            Matrix::random_with(4, 2, &distribution, &mut StdRng::seed_from_u64(7));
+
+        assert_eq!(matrix.nb_rows(), 4);
+        assert_eq!(matrix.nb_cols(), 2);
+
+        for i in 0..4 {
+            for j in 0..2 {
+                assert!((-5..=5).contains(&matrix[(i, j)]));
+            }
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use serde::de::Error;
+use serde::ser::{SerializeSeq, SerializeStruct};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::error::ShapeError;
+use super::matrix::Matrix;
+use super::view::{StorageOrder, View};
+
+/// Elements of a view serialized as a sequence following given logical order
+struct OrderedElements<'a, T> {
+    view: View<'a, T>,
+    order: StorageOrder,
+}
+
+impl<'a, T> Serialize for OrderedElements<'a, T>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (nb_rows, nb_cols): (usize, usize) = (self.view.nb_rows(), self.view.nb_cols());
+        let mut seq: S::SerializeSeq = serializer.serialize_seq(Some(nb_rows * nb_cols))?;
+
+        match self.order {
+            StorageOrder::RowMajor => {
+                for row_id in 0..nb_rows {
+                    for col_id in 0..nb_cols {
+                        seq.serialize_element(&self.view[(row_id, col_id)])?;
+                    }
+                }
+            }
+            StorageOrder::ColumnMajor => {
+                for col_id in 0..nb_cols {
+                    for row_id in 0..nb_rows {
+                        seq.serialize_element(&self.view[(row_id, col_id)])?;
+                    }
+                }
+            }
+        }
+
+        return seq.end();
+    }
+}
+
+impl<T> Serialize for Matrix<T>
+where
+    T: Serialize,
+{
+    /// Serialize number of rows, number of columns, storage order and elements in this order
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let order: StorageOrder = self.storage_order();
+
+        let mut state: S::SerializeStruct = serializer.serialize_struct("Matrix", 4)?;
+        state.serialize_field("nb_rows", &self.nb_rows())?;
+        state.serialize_field("nb_cols", &self.nb_cols())?;
+        state.serialize_field("storage_order", &order)?;
+        state.serialize_field(
+            "data",
+            &OrderedElements {
+                view: self.full_view(),
+                order,
+            },
+        )?;
+
+        return state.end();
+    }
+}
+
+/// Serialized form of matrix, before its number of elements is checked
+#[derive(Deserialize)]
+#[serde(rename = "Matrix")]
+struct RawMatrix<T> {
+    nb_rows: usize,
+    nb_cols: usize,
+    storage_order: StorageOrder,
+    data: Vec<T>,
+}
+
+impl<'de, T> Deserialize<'de> for Matrix<T>
+where
+    T: Deserialize<'de>,
+{
+    /// Deserialize matrix, failing if number of elements does not match dimensions
+    /// or if dimensions give a number of elements which overflows
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw: RawMatrix<T> = RawMatrix::deserialize(deserializer)?;
+
+        let matrix: Result<Matrix<T>, ShapeError> = match raw.storage_order {
+            StorageOrder::RowMajor => {
+                Matrix::from_vec_row_major(raw.nb_rows, raw.nb_cols, raw.data)
+            }
+            StorageOrder::ColumnMajor => {
+                Matrix::from_vec_column_major(raw.nb_rows, raw.nb_cols, raw.data)
+            }
+        };
+
+        return matrix.map_err(D::Error::custom);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::view::Accessor;
+
+    #[test]
+    fn test_matrix_json_round_trip() {
+        let matrix: Matrix<f64> =
+            Matrix::from_vec_column_major(2, 3, vec![1.0, 4.0, 2.0, 5.0, 3.0, 6.5]).unwrap();
+
+        let json: String = serde_json::to_string(&matrix).unwrap();
+        assert_eq!(
+            json,
+            r#"{"nb_rows":2,"nb_cols":3,"storage_order":"ColumnMajor","data":[1.0,4.0,2.0,5.0,3.0,6.5]}"#
+        );
+
+        let deserialized: Matrix<f64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, matrix);
+        assert_eq!(deserialized.storage_order(), StorageOrder::ColumnMajor);
+    }
+
+    #[test]
+    fn test_matrix_bincode_round_trip() {
+        let matrix: Matrix<i32> = Matrix::from_fn_row_major(3, 2, |i, j| (10 * i + j) as i32);
+
+        let bytes: Vec<u8> = bincode::serialize(&matrix).unwrap();
+        let deserialized: Matrix<i32> = bincode::deserialize(&bytes).unwrap();
+
+        assert_eq!(deserialized, matrix);
+        assert_eq!(deserialized.storage_order(), StorageOrder::RowMajor);
+    }
+
+    #[test]
+    fn test_matrix_serialize_padded_data() {
+        // Column-major matrix with a padding row is serialized without padding
+        let matrix: Matrix<i32> =
+            Matrix::from_raw_parts(2, 2, Accessor::new(1, 3), vec![1, 2, -1, 3, 4, -1]).unwrap();
+
+        let json: String = serde_json::to_string(&matrix).unwrap();
+        assert_eq!(
+            json,
+            r#"{"nb_rows":2,"nb_cols":2,"storage_order":"ColumnMajor","data":[1,2,3,4]}"#
+        );
+    }
+
+    #[test]
+    fn test_matrix_deserialize_length_mismatch() {
+        let json: &str =
+            r#"{"nb_rows":2,"nb_cols":3,"storage_order":"RowMajor","data":[1,2,3,4,5]}"#;
+        let error: serde_json::Error = serde_json::from_str::<Matrix<i32>>(json).unwrap_err();
+
+        assert!(error
+            .to_string()
+            .contains("5 elements were given but 6 were expected"));
+
+        // Number of elements overflows instead of matching empty data
+        let json: &str =
+            r#"{"nb_rows":4294967296,"nb_cols":4294967296,"storage_order":"RowMajor","data":[]}"#;
+        let error: serde_json::Error = serde_json::from_str::<Matrix<i32>>(json).unwrap_err();
+
+        assert!(error.to_string().contains("overflows"));
+    }
+}
@@ -0,0 +1,99 @@
+use std::cmp::Ordering;
+
+/// Compare two partially ordered values with a total order
+/// Values which are not comparable with themselves, like NaN, are greater than all other values
+/// and equal between them, so they are placed at the end by an ascending sort
+pub fn compare_nan_last<T>(a: &T, b: &T) -> Ordering
+where
+    T: PartialOrd,
+{
+    return match a.partial_cmp(b) {
+        Some(ordering) => ordering,
+        None => {
+            let a_is_nan: bool = a.partial_cmp(a).is_none();
+            let b_is_nan: bool = b.partial_cmp(b).is_none();
+            a_is_nan.cmp(&b_is_nan)
+        }
+    };
+}
+
+/// Compare two partially ordered values with a total order for a descending sort
+/// Values which are not comparable with themselves, like NaN, are still placed at the end
+pub fn compare_nan_last_descending<T>(a: &T, b: &T) -> Ordering
+where
+    T: PartialOrd,
+{
+    return match b.partial_cmp(a) {
+        Some(ordering) => ordering,
+        None => compare_nan_last(a, b),
+    };
+}
+
+/// Apply a permutation in place with swaps following its cycles
+/// After application, position i contains the element which was at position permutation[i]
+/// The swap closure must exchange elements at the two given positions
+pub fn apply_permutation<F>(permutation: &[usize], mut swap: F)
+where
+    F: FnMut(usize, usize),
+{
+    let mut visited: Vec<bool> = vec![false; permutation.len()];
+
+    for start in 0..permutation.len() {
+        if visited[start] {
+            continue;
+        }
+
+        let mut current: usize = start;
+
+        loop {
+            visited[current] = true;
+            let next: usize = permutation[current];
+
+            if next == start {
+                break;
+            }
+
+            swap(current, next);
+            current = next;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_nan_last() {
+        assert_eq!(compare_nan_last(&1.0, &2.0), Ordering::Less);
+        assert_eq!(compare_nan_last(&2.0, &1.0), Ordering::Greater);
+        assert_eq!(compare_nan_last(&f64::NAN, &1.0), Ordering::Greater);
+        assert_eq!(compare_nan_last(&1.0, &f64::NAN), Ordering::Less);
+        assert_eq!(compare_nan_last(&f64::NAN, &f64::NAN), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_compare_nan_last_descending() {
+        assert_eq!(compare_nan_last_descending(&1.0, &2.0), Ordering::Greater);
+        assert_eq!(compare_nan_last_descending(&2.0, &1.0), Ordering::Less);
+        assert_eq!(
+            compare_nan_last_descending(&f64::NAN, &1.0),
+            Ordering::Greater
+        );
+        assert_eq!(compare_nan_last_descending(&1.0, &f64::NAN), Ordering::Less);
+        assert_eq!(
+            compare_nan_last_descending(&f64::NAN, &f64::NAN),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn test_apply_permutation() {
+        let mut data: Vec<char> = vec!['a', 'b', 'c', 'd', 'e'];
+        let permutation: Vec<usize> = vec![3, 0, 4, 1, 2];
+
+        apply_permutation(&permutation, |i, j| data.swap(i, j));
+
+        assert_eq!(data, vec!['d', 'a', 'e', 'b', 'c']);
+    }
+}
@@ -0,0 +1,694 @@
+use super::error::ShapeError;
+use super::matrix::Matrix;
+use super::num::Float;
+use super::view::View;
+
+/// Compute the Mahalanobis distance between x and y from an inverse covariance matrix
+/// that is sqrt((x - y)^T * inv_cov * (x - y))
+/// The inverse covariance matrix must be square and its dimension must match the ones of x and y
+pub fn weighted_distance<T>(x: &[T], y: &[T], inv_cov: &View<T>) -> Result<f64, ShapeError>
+where
+    T: Copy + Into<f64>,
+{
+    if inv_cov.nb_rows() != inv_cov.nb_cols() {
+        return Err(ShapeError::NotSquare {
+            nb_rows: inv_cov.nb_rows(),
+            nb_cols: inv_cov.nb_cols(),
+        });
+    }
+
+    let dimension: usize = inv_cov.nb_rows();
+
+    for vector in [x, y] {
+        if vector.len() != dimension {
+            return Err(ShapeError::LengthMismatch {
+                expected: dimension,
+                actual: vector.len(),
+            });
+        }
+    }
+
+    let diff: Vec<f64> = x
+        .iter()
+        .zip(y.iter())
+        .map(|(&xi, &yi)| xi.into() - yi.into())
+        .collect();
+
+    let mut squared_distance: f64 = 0.0;
+
+    for (row_id, diff_row) in diff.iter().enumerate() {
+        for (col_id, diff_col) in diff.iter().enumerate() {
+            squared_distance += diff_row * inv_cov[(row_id, col_id)].into() * diff_col;
+        }
+    }
+
+    return Ok(squared_distance.sqrt());
+}
+
+/// Observations
+/// This enumeration tells if observations of a data matrix are stored along its rows,
+/// so variables are its columns, or along its columns, so variables are its rows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Observations {
+    InRows,
+    InColumns,
+}
+
+/// Compute covariance matrix of data where each observation is a row or a column
+/// according to observations parameter. The normalization divides by (number of observations - ddof),
+/// so ddof = 0 gives population covariance and ddof = 1 gives sample covariance.
+/// Data are centered then only the lower triangle of the symmetric product is computed
+/// and mirrored to the upper triangle.
+/// An error is returned if number of observations is not greater than ddof
+pub fn covariance<T>(
+    data: &View<T>,
+    ddof: usize,
+    observations: Observations,
+) -> Result<Matrix<T>, ShapeError>
+where
+    T: Float,
+{
+    let nb_obs: usize = match observations {
+        Observations::InRows => data.nb_rows(),
+        Observations::InColumns => data.nb_cols(),
+    };
+
+    if nb_obs <= ddof {
+        return Err(ShapeError::NotEnoughObservations { nb_obs, ddof });
+    }
+
+    let (nb_obs, centered): (usize, Vec<Vec<T>>) = centered_variables(data, observations);
+    let nb_vars: usize = centered.len();
+    let divisor: T = T::from_usize(nb_obs - ddof);
+
+    let mut lower: Vec<T> = Vec::with_capacity(nb_vars * (nb_vars + 1) / 2);
+
+    for var_i in 0..nb_vars {
+        for var_j in 0..=var_i {
+            let product: T = centered[var_i]
+                .iter()
+                .zip(centered[var_j].iter())
+                .fold(T::zero(), |acc: T, (&x, &y)| acc + x * y);
+
+            lower.push(product / divisor);
+        }
+    }
+
+    return Ok(Matrix::from_fn_row_major(nb_vars, nb_vars, |i, j| {
+        if j <= i {
+            return lower[i * (i + 1) / 2 + j];
+        } else {
+            return lower[j * (j + 1) / 2 + i];
+        }
+    }));
+}
+
+/// Compute correlation matrix of data where each observation is a row or a column
+/// according to observations parameter. It is the covariance normalized by standard deviations.
+/// A variable with zero variance has no defined correlation, so its row and its column
+/// in result are filled with NaN instead of panicking, like all of them without observation.
+pub fn correlation<T>(data: &View<T>, observations: Observations) -> Matrix<T>
+where
+    T: Float,
+{
+    let nb_vars: usize = match observations {
+        Observations::InRows => data.nb_cols(),
+        Observations::InColumns => data.nb_rows(),
+    };
+
+    let cov: Matrix<T> = match covariance(data, 0, observations) {
+        Ok(cov) => cov,
+        Err(_) => return Matrix::from_fn_row_major(nb_vars, nb_vars, |_, _| T::nan()),
+    };
+
+    let std_devs: Vec<T> = (0..nb_vars).map(|var| cov[(var, var)].sqrt()).collect();
+
+    return Matrix::from_fn_row_major(nb_vars, nb_vars, |i, j| {
+        if std_devs[i] == T::zero() || std_devs[j] == T::zero() {
+            return T::nan();
+        }
+
+        return cov[(i, j)] / (std_devs[i] * std_devs[j]);
+    });
+}
+
+/// Metric
+/// This enumeration defines distance between two vectors x and y
+/// - Euclidean is the norm of x - y
+/// - SquaredEuclidean is the squared norm of x - y
+/// - Manhattan is the sum of absolute values of x - y
+/// - Cosine is one minus the cosine of angle between x and y
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    Euclidean,
+    SquaredEuclidean,
+    Manhattan,
+    Cosine,
+}
+
+/// Compute distances between each row of x and each row of y, or x itself when y is None
+/// Element (i, j) of result is the distance between row i of x and row j of y.
+/// Euclidean distances are computed from squared norms and inner products as
+/// |x|^2 + |y|^2 - 2 x.y, clamped to zero to remove negative values due to cancellation.
+/// A row with zero norm has no direction, so its cosine distance to any row is one
+pub fn pairwise_distances<T>(
+    x: &View<T>,
+    y: Option<&View<T>>,
+    metric: Metric,
+) -> Result<Matrix<T>, ShapeError>
+where
+    T: Float,
+{
+    let y: &View<T> = y.unwrap_or(x);
+
+    if x.nb_cols() != y.nb_cols() {
+        return Err(ShapeError::LengthMismatch {
+            expected: x.nb_cols(),
+            actual: y.nb_cols(),
+        });
+    }
+
+    let dimension: usize = x.nb_cols();
+
+    let inner = |i: usize, j: usize| -> T {
+        return (0..dimension).fold(T::zero(), |acc: T, k| acc + x[(i, k)] * y[(j, k)]);
+    };
+
+    let squared_norms = |view: &View<T>| -> Vec<T> {
+        return (0..view.nb_rows())
+            .map(|i| (0..dimension).fold(T::zero(), |acc: T, k| acc + view[(i, k)] * view[(i, k)]))
+            .collect();
+    };
+
+    let x_norms: Vec<T> = squared_norms(x);
+    let y_norms: Vec<T> = squared_norms(y);
+    let two: T = T::one() + T::one();
+
+    return Ok(Matrix::from_fn_row_major(
+        x.nb_rows(),
+        y.nb_rows(),
+        |i, j| {
+            return match metric {
+                Metric::Euclidean | Metric::SquaredEuclidean => {
+                    let squared: T = x_norms[i] + y_norms[j] - two * inner(i, j);
+                    let squared: T = if squared > T::zero() {
+                        squared
+                    } else {
+                        T::zero()
+                    };
+
+                    if metric == Metric::Euclidean {
+                        squared.sqrt()
+                    } else {
+                        squared
+                    }
+                }
+                Metric::Manhattan => {
+                    (0..dimension).fold(T::zero(), |acc: T, k| acc + (x[(i, k)] - y[(j, k)]).abs())
+                }
+                Metric::Cosine => {
+                    let norms: T = (x_norms[i] * y_norms[j]).sqrt();
+
+                    if norms == T::zero() {
+                        T::one()
+                    } else {
+                        T::one() - inner(i, j) / norms
+                    }
+                }
+            };
+        },
+    ));
+}
+
+/// Norm kind
+/// This enumeration defines which norm of a vector is used
+/// - L1 is the sum of absolute values
+/// - L2 is the Euclidean norm
+/// - Max is the largest absolute value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NormKind {
+    L1,
+    L2,
+    Max,
+}
+
+/// Zero rows
+/// This enumeration tells what happens to rows whose norm is zero, which cannot be normalized.
+/// Their norm can be zero because of underflow even if their elements are not all zero
+/// - Untouched leaves them as they are
+/// - Zeroed sets all their elements to zero
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZeroRows {
+    Untouched,
+    Zeroed,
+}
+
+impl<T> Matrix<T>
+where
+    T: Float,
+{
+    /// Scale each row of matrix to have a unit norm of given kind
+    /// Rows with a zero norm cannot be normalized and are left untouched or zeroed
+    /// according to zero_rows parameter
+    pub fn normalize_rows(&mut self, norm: NormKind, zero_rows: ZeroRows) {
+        for row_id in 0..self.nb_rows() {
+            let row_norm: T = match norm {
+                NormKind::L1 => (0..self.nb_cols()).fold(T::zero(), |acc: T, col_id| {
+                    acc + self[(row_id, col_id)].abs()
+                }),
+                NormKind::L2 => (0..self.nb_cols())
+                    .fold(T::zero(), |acc: T, col_id| {
+                        return acc + self[(row_id, col_id)] * self[(row_id, col_id)];
+                    })
+                    .sqrt(),
+                NormKind::Max => (0..self.nb_cols()).fold(T::zero(), |acc: T, col_id| {
+                    let value: T = self[(row_id, col_id)].abs();
+                    return if value > acc { value } else { acc };
+                }),
+            };
+
+            if row_norm != T::zero() {
+                for col_id in 0..self.nb_cols() {
+                    self[(row_id, col_id)] = self[(row_id, col_id)] / row_norm;
+                }
+            } else if zero_rows == ZeroRows::Zeroed {
+                for col_id in 0..self.nb_cols() {
+                    self[(row_id, col_id)] = T::zero();
+                }
+            }
+        }
+    }
+
+    /// Center each column of matrix on its mean and divide it by its standard deviation,
+    /// the normalization of variance divides by (number of rows - ddof).
+    /// It returns means and standard deviations of columns, so the same transformation
+    /// can be applied to other data with apply_standardization.
+    /// A column with zero standard deviation is only centered, so no infinity is produced.
+    /// An error is returned if number of rows is not greater than ddof, and matrix is unchanged
+    pub fn standardize_cols(&mut self, ddof: usize) -> Result<(Vec<T>, Vec<T>), ShapeError> {
+        let nb_rows: usize = self.nb_rows();
+
+        if nb_rows <= ddof {
+            return Err(ShapeError::NotEnoughObservations {
+                nb_obs: nb_rows,
+                ddof,
+            });
+        }
+
+        let divisor: T = T::from_usize(nb_rows - ddof);
+
+        let means: Vec<T> = (0..self.nb_cols())
+            .map(|col_id| {
+                let sum: T =
+                    (0..nb_rows).fold(T::zero(), |acc: T, row_id| acc + self[(row_id, col_id)]);
+                return sum / T::from_usize(nb_rows);
+            })
+            .collect();
+
+        let std_devs: Vec<T> = means
+            .iter()
+            .enumerate()
+            .map(|(col_id, &mean)| {
+                let sum: T = (0..nb_rows).fold(T::zero(), |acc: T, row_id| {
+                    let diff: T = self[(row_id, col_id)] - mean;
+                    return acc + diff * diff;
+                });
+                return (sum / divisor).sqrt();
+            })
+            .collect();
+
+        self.standardize(&means, &std_devs);
+
+        return Ok((means, std_devs));
+    }
+
+    /// Center each column of matrix on given mean and divide it by given standard deviation
+    /// A column with zero standard deviation is only centered, so no infinity is produced
+    pub fn apply_standardization(&mut self, means: &[T], std_devs: &[T]) -> Result<(), ShapeError> {
+        for values in [means, std_devs] {
+            if values.len() != self.nb_cols() {
+                return Err(ShapeError::LengthMismatch {
+                    expected: self.nb_cols(),
+                    actual: values.len(),
+                });
+            }
+        }
+
+        self.standardize(means, std_devs);
+        return Ok(());
+    }
+
+    /// Center and scale each column of matrix where means and standard deviations
+    /// are known to have one value per column
+    fn standardize(&mut self, means: &[T], std_devs: &[T]) {
+        for col_id in 0..self.nb_cols() {
+            let scale: T = if std_devs[col_id] == T::zero() {
+                T::one()
+            } else {
+                std_devs[col_id]
+            };
+
+            for row_id in 0..self.nb_rows() {
+                self[(row_id, col_id)] = (self[(row_id, col_id)] - means[col_id]) / scale;
+            }
+        }
+    }
+}
+
+/// Extract each variable of data as a vector of centered observations
+/// It returns the number of observations with the centered variables
+fn centered_variables<T>(data: &View<T>, observations: Observations) -> (usize, Vec<Vec<T>>)
+where
+    T: Float,
+{
+    let (nb_obs, nb_vars): (usize, usize) = match observations {
+        Observations::InRows => (data.nb_rows(), data.nb_cols()),
+        Observations::InColumns => (data.nb_cols(), data.nb_rows()),
+    };
+
+    let element = |obs: usize, var: usize| -> T {
+        return match observations {
+            Observations::InRows => data[(obs, var)],
+            Observations::InColumns => data[(var, obs)],
+        };
+    };
+
+    let mut centered: Vec<Vec<T>> = Vec::with_capacity(nb_vars);
+
+    for var in 0..nb_vars {
+        let values: Vec<T> = (0..nb_obs).map(|obs| element(obs, var)).collect();
+        let sum: T = values.iter().fold(T::zero(), |acc: T, &x| acc + x);
+        let mean: T = sum / T::from_usize(nb_obs);
+
+        centered.push(values.into_iter().map(|x| x - mean).collect());
+    }
+
+    return (nb_obs, centered);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::view::Accessor;
+
+    #[test]
+    fn test_weighted_distance_diagonal() {
+        let inv_cov_data: Vec<f64> = vec![4.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.25];
+        let inv_cov: View<f64> = View::new(3, 3, Accessor::new(3, 1), inv_cov_data.as_slice());
+
+        let x: Vec<f64> = vec![1.0, 2.0, 3.0];
+        let y: Vec<f64> = vec![2.0, 0.0, 7.0];
+
+        // Weighted Euclidean distance sqrt(4 * 1 + 1 * 4 + 0.25 * 16)
+        let distance: f64 = weighted_distance(&x, &y, &inv_cov).unwrap();
+        assert!((distance - 12.0_f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_weighted_distance_errors() {
+        let inv_cov_data: Vec<f64> = vec![1.0, 0.0, 0.0, 1.0, 0.0, 0.0];
+        let x: Vec<f64> = vec![1.0, 2.0];
+        let y: Vec<f64> = vec![2.0, 0.0, 7.0];
+
+        let not_square: View<f64> = View::new(2, 3, Accessor::new(3, 1), inv_cov_data.as_slice());
+        assert_eq!(
+            weighted_distance(&x, &x, &not_square),
+            Err(ShapeError::NotSquare {
+                nb_rows: 2,
+                nb_cols: 3
+            })
+        );
+
+        let inv_cov: View<f64> = View::new(2, 2, Accessor::new(2, 1), inv_cov_data.as_slice());
+        assert_eq!(
+            weighted_distance(&x, &y, &inv_cov),
+            Err(ShapeError::LengthMismatch {
+                expected: 2,
+                actual: 3
+            })
+        );
+    }
+
+    /// Naive covariance where observations are rows, computed entry by entry
+    fn naive_covariance(data: &View<f64>, ddof: usize) -> Vec<f64> {
+        let nb_obs: usize = data.nb_rows();
+        let nb_vars: usize = data.nb_cols();
+
+        let means: Vec<f64> = (0..nb_vars)
+            .map(|j| (0..nb_obs).map(|k| data[(k, j)]).sum::<f64>() / nb_obs as f64)
+            .collect();
+
+        let mut cov: Vec<f64> = vec![0.0; nb_vars * nb_vars];
+
+        for i in 0..nb_vars {
+            for j in 0..nb_vars {
+                for k in 0..nb_obs {
+                    cov[i * nb_vars + j] += (data[(k, i)] - means[i]) * (data[(k, j)] - means[j]);
+                }
+
+                cov[i * nb_vars + j] /= (nb_obs - ddof) as f64;
+            }
+        }
+
+        return cov;
+    }
+
+    #[test]
+    fn test_covariance() {
+        let data: Vec<f64> = vec![
+            1.0, 2.0, 0.5, 2.0, 1.0, 1.5, 4.0, 3.5, -1.0, 3.0, 7.0, 2.0, 0.0, 1.0, 1.0,
+        ];
+        let view: View<f64> = View::new(5, 3, Accessor::new(3, 1), data.as_slice());
+
+        for ddof in [0, 1] {
+            let cov: Matrix<f64> = covariance(&view, ddof, Observations::InRows).unwrap();
+            let cov_ref: Vec<f64> = naive_covariance(&view, ddof);
+
+            assert_eq!(cov.nb_rows(), 3);
+            assert_eq!(cov.nb_cols(), 3);
+
+            for i in 0..3 {
+                for j in 0..3 {
+                    assert!((cov[(i, j)] - cov_ref[i * 3 + j]).abs() < 1e-12);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_covariance_observations_in_columns() {
+        let data: Vec<f64> = vec![
+            1.0, 2.0, 0.5, 2.0, 1.0, 1.5, 4.0, 3.5, -1.0, 3.0, 7.0, 2.0, 0.0, 1.0, 1.0,
+        ];
+        let view: View<f64> = View::new(5, 3, Accessor::new(3, 1), data.as_slice());
+        let transposed: View<f64> = View::new(3, 5, Accessor::new(1, 3), data.as_slice());
+
+        let cov: Matrix<f64> = covariance(&view, 1, Observations::InRows).unwrap();
+        let cov_transposed: Matrix<f64> =
+            covariance(&transposed, 1, Observations::InColumns).unwrap();
+
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((cov[(i, j)] - cov_transposed[(i, j)]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_covariance_not_enough_observations() {
+        let data: Vec<f64> = vec![1.0, 2.0, 3.0];
+        let view: View<f64> = View::new(1, 3, Accessor::new(3, 1), data.as_slice());
+
+        assert!(covariance(&view, 0, Observations::InRows).is_ok());
+        assert_eq!(
+            covariance(&view, 1, Observations::InRows),
+            Err(ShapeError::NotEnoughObservations { nb_obs: 1, ddof: 1 })
+        );
+        assert_eq!(
+            covariance(&view, 4, Observations::InColumns),
+            Err(ShapeError::NotEnoughObservations { nb_obs: 3, ddof: 4 })
+        );
+
+        let empty: View<f64> = View::new(0, 3, Accessor::new(3, 1), data.as_slice());
+        assert_eq!(
+            covariance(&empty, 0, Observations::InRows),
+            Err(ShapeError::NotEnoughObservations { nb_obs: 0, ddof: 0 })
+        );
+
+        let corr: Matrix<f64> = correlation(&empty, Observations::InRows);
+        assert_eq!((corr.nb_rows(), corr.nb_cols()), (3, 3));
+        assert!(corr.iter().all(|x| x.is_nan()));
+    }
+
+    #[test]
+    fn test_correlation() {
+        let data: Vec<f64> = vec![1.0, 2.0, 5.0, 2.0, 4.0, 5.0, 3.0, 6.0, 5.0, 4.0, 7.0, 5.0];
+        let view: View<f64> = View::new(4, 3, Accessor::new(3, 1), data.as_slice());
+
+        let corr: Matrix<f64> = correlation(&view, Observations::InRows);
+
+        assert!((corr[(0, 0)] - 1.0).abs() < 1e-12);
+        assert!((corr[(1, 1)] - 1.0).abs() < 1e-12);
+        assert!((corr[(0, 1)] - corr[(1, 0)]).abs() < 1e-12);
+        assert!(corr[(0, 1)] > 0.9 && corr[(0, 1)] < 1.0);
+
+        // Last column is constant so its correlations are not defined
+        for k in 0..3 {
+            assert!(corr[(2, k)].is_nan());
+            assert!(corr[(k, 2)].is_nan());
+        }
+    }
+
+    #[test]
+    fn test_normalize_rows() {
+        let rows: [&[f64]; 3] = [&[3.0, -4.0], &[0.0, 0.0], &[-1.0, 0.5]];
+
+        let mut matrix: Matrix<f64> = Matrix::from_rows(&rows).unwrap();
+        matrix.normalize_rows(NormKind::L2, ZeroRows::Untouched);
+
+        assert!((matrix[(0, 0)] - 0.6).abs() < 1e-12);
+        assert!((matrix[(0, 1)] + 0.8).abs() < 1e-12);
+        assert_eq!((matrix[(1, 0)], matrix[(1, 1)]), (0.0, 0.0));
+
+        let mut matrix: Matrix<f64> = Matrix::from_rows(&rows).unwrap();
+        matrix.normalize_rows(NormKind::L1, ZeroRows::Untouched);
+
+        assert!((matrix[(0, 0)] - 3.0 / 7.0).abs() < 1e-12);
+        assert!((matrix[(2, 1)] - 0.5 / 1.5).abs() < 1e-12);
+
+        let mut matrix: Matrix<f64> = Matrix::from_rows(&rows).unwrap();
+        matrix.normalize_rows(NormKind::Max, ZeroRows::Zeroed);
+
+        assert_eq!((matrix[(0, 0)], matrix[(0, 1)]), (0.75, -1.0));
+        assert_eq!((matrix[(2, 0)], matrix[(2, 1)]), (-1.0, 0.5));
+
+        // Squares of elements underflow so L2 norm of row is zero
+        let tiny: [&[f64]; 1] = [&[1e-200, -1e-200]];
+
+        let mut matrix: Matrix<f64> = Matrix::from_rows(&tiny).unwrap();
+        matrix.normalize_rows(NormKind::L2, ZeroRows::Untouched);
+        assert_eq!((matrix[(0, 0)], matrix[(0, 1)]), (1e-200, -1e-200));
+
+        let mut matrix: Matrix<f64> = Matrix::from_rows(&tiny).unwrap();
+        matrix.normalize_rows(NormKind::L2, ZeroRows::Zeroed);
+        assert_eq!((matrix[(0, 0)], matrix[(0, 1)]), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_standardize_cols() {
+        let mut matrix: Matrix<f64> =
+            Matrix::from_rows(&[&[1.0, 5.0], &[2.0, 5.0], &[3.0, 5.0], &[6.0, 5.0]]).unwrap();
+
+        let (means, std_devs): (Vec<f64>, Vec<f64>) = matrix.standardize_cols(1).unwrap();
+
+        assert_eq!(means, vec![3.0, 5.0]);
+        assert!((std_devs[0] - (14.0_f64 / 3.0).sqrt()).abs() < 1e-12);
+        assert_eq!(std_devs[1], 0.0);
+
+        let mean: f64 = (0..4).map(|i| matrix[(i, 0)]).sum::<f64>() / 4.0;
+        let variance: f64 = (0..4).map(|i| matrix[(i, 0)] * matrix[(i, 0)]).sum::<f64>() / 3.0;
+
+        assert!(mean.abs() < 1e-12);
+        assert!((variance - 1.0).abs() < 1e-12);
+
+        // Zero-variance column is only centered
+        for i in 0..4 {
+            assert_eq!(matrix[(i, 1)], 0.0);
+        }
+
+        let mut new_data: Matrix<f64> = Matrix::from_rows(&[&[3.0, 7.0]]).unwrap();
+        new_data.apply_standardization(&means, &std_devs).unwrap();
+
+        assert_eq!((new_data[(0, 0)], new_data[(0, 1)]), (0.0, 2.0));
+
+        assert_eq!(
+            new_data.apply_standardization(&means[..1], &std_devs),
+            Err(ShapeError::LengthMismatch {
+                expected: 2,
+                actual: 1
+            })
+        );
+
+        // Single row has no sample standard deviation
+        let mut single: Matrix<f64> = Matrix::from_rows(&[&[1.0, 2.0]]).unwrap();
+        assert_eq!(
+            single.standardize_cols(1),
+            Err(ShapeError::NotEnoughObservations { nb_obs: 1, ddof: 1 })
+        );
+        assert_eq!((single[(0, 0)], single[(0, 1)]), (1.0, 2.0));
+        assert!(single.standardize_cols(0).is_ok());
+    }
+
+    #[test]
+    fn test_pairwise_distances() {
+        let x: Matrix<f64> =
+            Matrix::from_rows(&[&[1.0, 2.0, 0.0], &[-1.0, 0.5, 3.0], &[0.0, 0.0, 0.0]]).unwrap();
+        let y: Matrix<f64> = Matrix::from_rows(&[&[2.0, 2.0, 1.0], &[1.0, -1.0, 0.5]]).unwrap();
+
+        let x_view: View<f64> = x.full_view();
+        let y_view: View<f64> = y.full_view();
+
+        for metric in [
+            Metric::Euclidean,
+            Metric::SquaredEuclidean,
+            Metric::Manhattan,
+            Metric::Cosine,
+        ] {
+            let distances: Matrix<f64> =
+                pairwise_distances(&x_view, Some(&y_view), metric).unwrap();
+
+            assert_eq!(distances.nb_rows(), 3);
+            assert_eq!(distances.nb_cols(), 2);
+
+            for i in 0..3 {
+                for j in 0..2 {
+                    let a: Vec<f64> = (0..3).map(|k| x[(i, k)]).collect();
+                    let b: Vec<f64> = (0..3).map(|k| y[(j, k)]).collect();
+
+                    let squared: f64 = a.iter().zip(b.iter()).map(|(u, v)| (u - v) * (u - v)).sum();
+                    let dot: f64 = a.iter().zip(b.iter()).map(|(u, v)| u * v).sum();
+                    let norm_a: f64 = a.iter().map(|u| u * u).sum::<f64>().sqrt();
+                    let norm_b: f64 = b.iter().map(|v| v * v).sum::<f64>().sqrt();
+
+                    let expected: f64 = match metric {
+                        Metric::Euclidean => squared.sqrt(),
+                        Metric::SquaredEuclidean => squared,
+                        Metric::Manhattan => {
+                            a.iter().zip(b.iter()).map(|(u, v)| (u - v).abs()).sum()
+                        }
+                        Metric::Cosine if norm_a * norm_b == 0.0 => 1.0,
+                        Metric::Cosine => 1.0 - dot / (norm_a * norm_b),
+                    };
+
+                    assert!((distances[(i, j)] - expected).abs() < 1e-12);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_pairwise_distances_with_itself() {
+        let x: Matrix<f64> = Matrix::from_rows(&[&[1e8, 1.0], &[1e8, 1.0], &[0.0, 1.0]]).unwrap();
+
+        let distances: Matrix<f64> =
+            pairwise_distances(&x.full_view(), None, Metric::Euclidean).unwrap();
+
+        // Cancellation of large norms must not produce negative distances
+        for i in 0..3 {
+            assert!(distances[(i, i)] >= 0.0 && distances[(i, i)] < 1e-3);
+        }
+
+        assert_eq!(distances[(0, 1)], distances[(1, 0)]);
+        assert!((distances[(0, 2)] - 1e8).abs() < 1e-3);
+
+        let y: Matrix<f64> = Matrix::from_rows(&[&[1.0, 2.0, 3.0]]).unwrap();
+
+        assert_eq!(
+            pairwise_distances(&x.full_view(), Some(&y.full_view()), Metric::Manhattan).map(|_| ()),
+            Err(ShapeError::LengthMismatch {
+                expected: 2,
+                actual: 3
+            })
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use super::matrix::Matrix;
+use super::view::View;
+
+/// Create Hilbert matrix of size n, where element (i, j) is 1 / (i + j + 1)
+/// It is symmetric positive definite and very ill-conditioned, its condition number grows like e^(3.5 n)
+pub fn hilbert(n: usize) -> Matrix<f64> {
+    return Matrix::from_fn_row_major(n, n, |i, j| 1.0 / ((i + j + 1) as f64));
+}
+
+/// Create symmetric Pascal matrix of size n, where element (i, j) is binomial coefficient (i + j, i)
+/// It is symmetric positive definite with determinant equal to one,
+/// its Cholesky factor is the lower triangular Pascal matrix
+pub fn pascal(n: usize) -> Matrix<f64> {
+    let mut matrix: Matrix<f64> = Matrix::ones(n, n);
+
+    for i in 1..n {
+        for j in 1..n {
+            matrix[(i, j)] = matrix[(i - 1, j)] + matrix[(i, j - 1)];
+        }
+    }
+
+    return matrix;
+}
+
+/// Create Wilkinson matrix W+ of size n, a symmetric tridiagonal matrix with ones
+/// on sub and super diagonals and |(n - 1) / 2 - i| on main diagonal
+/// Its largest eigenvalues come in nearly equal pairs, which is hard for eigenvalue solvers
+pub fn wilkinson(n: usize) -> Matrix<f64> {
+    let middle: f64 = (n as f64 - 1.0) / 2.0;
+
+    return Matrix::from_fn_row_major(n, n, |i, j| {
+        if i == j {
+            return (middle - i as f64).abs();
+        } else if i + 1 == j || j + 1 == i {
+            return 1.0;
+        } else {
+            return 0.0;
+        }
+    });
+}
+
+/// Create random symmetric positive definite matrix of size n from given seed
+/// It is B^T * B + n * I where elements of B are uniform in [-1, 1), so its eigenvalues are at least n
+pub fn random_spd(n: usize, seed: u64) -> Matrix<f64> {
+    let mut generator: SplitMix64 = SplitMix64 { state: seed };
+    let b: Matrix<f64> = Matrix::from_fn_row_major(n, n, |_, _| generator.next_f64() * 2.0 - 1.0);
+
+    return Matrix::from_fn_row_major(n, n, |i, j| {
+        let dot: f64 = (0..n).map(|k| b[(k, i)] * b[(k, j)]).sum();
+        return if i == j { dot + n as f64 } else { dot };
+    });
+}
+
+/// Compute relative residual ||A * x - b|| / (||A|| * ||x|| + ||b||) of linear system
+/// with Frobenius norm of A and Euclidean norms of vectors
+/// A backward stable solver gives a value of order of f64::EPSILON
+pub fn relative_residual(a: &View<f64>, x: &[f64], b: &[f64]) -> f64 {
+    assert_eq!(a.nb_cols(), x.len());
+    assert_eq!(a.nb_rows(), b.len());
+
+    let residual: f64 = (0..a.nb_rows())
+        .map(|row_id| {
+            let ax: f64 = (0..a.nb_cols())
+                .map(|col_id| a[(row_id, col_id)] * x[col_id])
+                .sum::<f64>();
+            return (ax - b[row_id]).powi(2);
+        })
+        .sum::<f64>()
+        .sqrt();
+
+    let norm_a: f64 = a.iter().map(|value| value * value).sum::<f64>().sqrt();
+    let norm_x: f64 = x.iter().map(|value| value * value).sum::<f64>().sqrt();
+    let norm_b: f64 = b.iter().map(|value| value * value).sum::<f64>().sqrt();
+    let scale: f64 = norm_a * norm_x + norm_b;
+
+    if scale == 0.0 {
+        return residual;
+    }
+
+    return residual / scale;
+}
+
+/// Compute largest absolute element of Q^T * Q - I, which is zero when columns of q are orthonormal
+pub fn orthogonality_error(q: &View<f64>) -> f64 {
+    let mut error: f64 = 0.0;
+
+    for i in 0..q.nb_cols() {
+        for j in 0..q.nb_cols() {
+            let dot: f64 = (0..q.nb_rows()).map(|k| q[(k, i)] * q[(k, j)]).sum();
+            let expected: f64 = if i == j { 1.0 } else { 0.0 };
+            error = error.max((dot - expected).abs());
+        }
+    }
+
+    return error;
+}
+
+/// Small deterministic generator of pseudo-random numbers, so fixtures do not depend on rand feature
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z: u64 = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+        return z ^ (z >> 31);
+    }
+
+    /// Get number uniform in [0, 1) from 53 high bits
+    fn next_f64(&mut self) -> f64 {
+        return (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hilbert() {
+        let matrix: Matrix<f64> = hilbert(3);
+
+        assert_eq!(matrix[(0, 0)], 1.0);
+        assert_eq!(matrix[(1, 2)], 0.25);
+        assert!(matrix.full_view() == matrix.transpose_view());
+    }
+
+    #[test]
+    fn test_pascal() {
+        let matrix: Matrix<f64> = pascal(4);
+        let expected: Matrix<f64> = Matrix::from_rows(&[
+            &[1.0, 1.0, 1.0, 1.0],
+            &[1.0, 2.0, 3.0, 4.0],
+            &[1.0, 3.0, 6.0, 10.0],
+            &[1.0, 4.0, 10.0, 20.0],
+        ])
+        .unwrap();
+
+        assert_eq!(matrix, expected);
+    }
+
+    #[test]
+    fn test_wilkinson() {
+        let matrix: Matrix<f64> = wilkinson(5);
+
+        assert_eq!(
+            matrix.diagonal().iter().copied().collect::<Vec<f64>>(),
+            vec![2.0, 1.0, 0.0, 1.0, 2.0]
+        );
+        assert_eq!(matrix[(1, 2)], 1.0);
+        assert_eq!(matrix[(2, 1)], 1.0);
+        assert_eq!(matrix[(0, 2)], 0.0);
+    }
+
+    #[test]
+    fn test_random_spd() {
+        let matrix: Matrix<f64> = random_spd(5, 42);
+
+        assert_eq!(matrix, random_spd(5, 42));
+        assert_ne!(matrix, random_spd(5, 43));
+        assert!(matrix.full_view() == matrix.transpose_view());
+
+        // Diagonal dominates since eigenvalues are at least n
+        for i in 0..5 {
+            assert!(matrix[(i, i)] >= 5.0);
+        }
+    }
+
+    #[test]
+    fn test_relative_residual() {
+        let a: Matrix<f64> = Matrix::from_rows(&[&[2.0, 0.0], &[0.0, 4.0]]).unwrap();
+
+        assert_eq!(
+            relative_residual(&a.full_view(), &[1.0, 0.5], &[2.0, 2.0]),
+            0.0
+        );
+        assert!(relative_residual(&a.full_view(), &[1.0, 1.0], &[2.0, 2.0]) > 0.1);
+    }
+
+    #[test]
+    fn test_orthogonality_error() {
+        let angle: f64 = 0.3;
+        let rotation: Matrix<f64> =
+            Matrix::from_rows(&[&[angle.cos(), -angle.sin()], &[angle.sin(), angle.cos()]])
+                .unwrap();
+
+        assert!(orthogonality_error(&rotation.full_view()) < 4.0 * f64::EPSILON);
+        assert_eq!(orthogonality_error(&Matrix::identity(3).full_view()), 0.0);
+        assert_eq!(orthogonality_error(&Matrix::ones(3, 1).full_view()), 2.0);
+    }
+}
@@ -0,0 +1,117 @@
+#[cfg(feature = "trace")]
+use std::cell::RefCell;
+#[cfg(feature = "trace")]
+use std::time::Instant;
+
+/// Operation trace
+/// This structure describes one call of a numerical kernel: its name, dimensions of its operands,
+/// the code path it took and its duration in nanoseconds.
+/// Dimensions are (m, k, n) for a product of m x k by k x n matrices
+/// and (n, n, n) for an elimination on a square matrix of size n
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OpTrace {
+    pub op: &'static str,
+    pub dims: (usize, usize, usize),
+    pub path: &'static str,
+    pub nanos: u64,
+}
+
+/// Hook called with trace of each kernel run
+#[cfg(feature = "trace")]
+type TraceHook = Box<dyn Fn(OpTrace)>;
+
+#[cfg(feature = "trace")]
+thread_local! {
+    static TRACE_HOOK: RefCell<Option<TraceHook>> = const { RefCell::new(None) };
+}
+
+/// Install hook called with trace of each kernel run on current thread, replacing previous one
+#[cfg(feature = "trace")]
+pub fn set_trace_hook<F>(hook: F)
+where
+    F: Fn(OpTrace) + 'static,
+{
+    TRACE_HOOK.with(|cell| *cell.borrow_mut() = Some(Box::new(hook)));
+}
+
+/// Remove hook of current thread
+#[cfg(feature = "trace")]
+pub fn clear_trace_hook() {
+    TRACE_HOOK.with(|cell| *cell.borrow_mut() = None);
+}
+
+/// Run kernel f and report its trace to hook of current thread
+/// Without trace feature, f is only called so there is no overhead
+#[inline(always)]
+pub fn traced<R, F>(op: &'static str, dims: (usize, usize, usize), path: &'static str, f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    #[cfg(feature = "trace")]
+    {
+        let start: Instant = Instant::now();
+        let result: R = f();
+        let nanos: u64 = start.elapsed().as_nanos() as u64;
+
+        TRACE_HOOK.with(|cell| {
+            if let Some(hook) = cell.borrow().as_ref() {
+                hook(OpTrace {
+                    op,
+                    dims,
+                    path,
+                    nanos,
+                });
+            }
+        });
+
+        return result;
+    }
+
+    #[cfg(not(feature = "trace"))]
+    {
+        let _ = (op, dims, path);
+        return f();
+    }
+}
+
+#[cfg(all(test, feature = "trace"))]
+mod tests {
+    use super::*;
+    use crate::lu::Lu;
+    use crate::matrix::Matrix;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_trace_hook() {
+        let traces: Rc<RefCell<Vec<OpTrace>>> = Rc::new(RefCell::new(Vec::new()));
+        let collected: Rc<RefCell<Vec<OpTrace>>> = Rc::clone(&traces);
+
+        set_trace_hook(move |trace| collected.borrow_mut().push(trace));
+
+        let a: Matrix<f64> =
+            Matrix::from_rows(&[&[2.0, 1.0, 0.0], &[1.0, 3.0, 1.0], &[0.0, 1.0, 4.0]]).unwrap();
+        let b: Matrix<f64> = Matrix::from_rows(&[&[3.0], &[5.0], &[5.0]]).unwrap();
+        let lu: Lu = Lu::new(&a.full_view()).unwrap();
+        let x: Matrix<f64> = lu.solve(&b.full_view()).unwrap();
+        for row_id in 0..3 {
+            assert!((x[(row_id, 0)] - 1.0).abs() < 1e-12);
+        }
+
+        clear_trace_hook();
+        lu.solve(&b.full_view()).unwrap();
+
+        let traces: Vec<OpTrace> = traces.borrow().clone();
+        let summary: Vec<(&str, (usize, usize, usize), &str)> = traces
+            .iter()
+            .map(|trace| (trace.op, trace.dims, trace.path))
+            .collect();
+
+        assert_eq!(
+            summary,
+            vec![
+                ("lu_factorize", (3, 3, 3), "unblocked"),
+                ("lu_solve", (3, 3, 1), "triangular"),
+            ]
+        );
+    }
+}
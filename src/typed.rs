@@ -0,0 +1,231 @@
+use std::ops::{Add, Mul};
+
+use super::arithmetic::matmul;
+use super::error::ShapeError;
+use super::matrix::Matrix;
+use super::num::Zero;
+
+/// Dimension
+/// This trait describes number of rows or number of columns of a typed matrix,
+/// known at compile time for Const or only at runtime for Dyn
+pub trait Dim: Copy {
+    /// Get size of dimension
+    fn value(&self) -> usize;
+
+    /// Get size of dimension if it is known at compile time
+    fn fixed() -> Option<usize>;
+
+    /// Create dimension with given size, return None if type cannot have this size
+    fn from_value(value: usize) -> Option<Self>;
+}
+
+/// Fixed dimension
+/// This structure is a dimension whose size N is known at compile time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Const<const N: usize>;
+
+impl<const N: usize> Dim for Const<N> {
+    fn value(&self) -> usize {
+        return N;
+    }
+
+    fn fixed() -> Option<usize> {
+        return Some(N);
+    }
+
+    fn from_value(value: usize) -> Option<Self> {
+        if value == N {
+            return Some(Const);
+        } else {
+            return None;
+        }
+    }
+}
+
+/// Dynamic dimension
+/// This structure is a dimension whose size is only known at runtime
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dyn(pub usize);
+
+impl Dim for Dyn {
+    fn value(&self) -> usize {
+        return self.0;
+    }
+
+    fn fixed() -> Option<usize> {
+        return None;
+    }
+
+    fn from_value(value: usize) -> Option<Self> {
+        return Some(Dyn(value));
+    }
+}
+
+/// Typed matrix
+/// This structure wraps a matrix with types of its number of rows and number of columns,
+/// so product of matrices with fixed dimensions which do not match does not compile
+///
+/// ```
+/// use blarus::{Const, Matrix, TypedMatrix};
+///
+/// let a: TypedMatrix<f64, Const<2>, Const<3>> = TypedMatrix::new(Matrix::zeros(2, 3)).unwrap();
+/// let b: TypedMatrix<f64, Const<3>, Const<4>> = TypedMatrix::new(Matrix::zeros(3, 4)).unwrap();
+/// let product: TypedMatrix<f64, Const<2>, Const<4>> = a.mul(&b);
+/// ```
+///
+/// ```compile_fail
+/// use blarus::{Const, Matrix, TypedMatrix};
+///
+/// let a: TypedMatrix<f64, Const<2>, Const<3>> = TypedMatrix::new(Matrix::zeros(2, 3)).unwrap();
+/// let b: TypedMatrix<f64, Const<2>, Const<3>> = TypedMatrix::new(Matrix::zeros(2, 3)).unwrap();
+/// let product = a.mul(&b);
+/// ```
+pub struct TypedMatrix<T, R: Dim, C: Dim> {
+    nb_rows: R,
+    nb_cols: C,
+    matrix: Matrix<T>,
+}
+
+impl<T, R: Dim, C: Dim> TypedMatrix<T, R, C> {
+    /// Wrap matrix whose dimensions must fit types of number of rows and number of columns
+    pub fn new(matrix: Matrix<T>) -> Result<Self, ShapeError> {
+        let dimensions: Option<(R, C)> =
+            R::from_value(matrix.nb_rows()).zip(C::from_value(matrix.nb_cols()));
+
+        return match dimensions {
+            Some((nb_rows, nb_cols)) => Ok(Self {
+                nb_rows,
+                nb_cols,
+                matrix,
+            }),
+            None => Err(ShapeError::DimensionMismatch {
+                expected: (
+                    R::fixed().unwrap_or(matrix.nb_rows()),
+                    C::fixed().unwrap_or(matrix.nb_cols()),
+                ),
+                actual: (matrix.nb_rows(), matrix.nb_cols()),
+            }),
+        };
+    }
+
+    /// Get number of rows of matrix
+    pub fn nb_rows(&self) -> usize {
+        return self.nb_rows.value();
+    }
+
+    /// Get number of columns of matrix
+    pub fn nb_cols(&self) -> usize {
+        return self.nb_cols.value();
+    }
+
+    /// Get wrapped matrix
+    pub fn as_matrix(&self) -> &Matrix<T> {
+        return &self.matrix;
+    }
+
+    /// Release wrapped matrix
+    pub fn into_matrix(self) -> Matrix<T> {
+        return self.matrix;
+    }
+
+    /// Forget types of dimensions, which are then only checked at runtime
+    pub fn into_dyn(self) -> TypedMatrix<T, Dyn, Dyn> {
+        return TypedMatrix {
+            nb_rows: Dyn(self.nb_rows.value()),
+            nb_cols: Dyn(self.nb_cols.value()),
+            matrix: self.matrix,
+        };
+    }
+
+    /// Give fixed dimensions to matrix, which must have NR rows and NC columns
+    pub fn try_into_fixed<const NR: usize, const NC: usize>(
+        self,
+    ) -> Result<TypedMatrix<T, Const<NR>, Const<NC>>, ShapeError> {
+        return TypedMatrix::new(self.matrix);
+    }
+
+    /// Compute matrix product by other, whose number of rows has the type of number of columns
+    /// of matrix. Mismatch of fixed dimensions does not compile, and mismatch of dynamic ones
+    /// panics like operators on matrices
+    pub fn mul<K: Dim>(&self, other: &TypedMatrix<T, C, K>) -> TypedMatrix<T, R, K>
+    where
+        T: Copy + Zero + Add<Output = T> + Mul<Output = T>,
+    {
+        let product: Matrix<T> = matmul(&self.matrix.full_view(), &other.matrix.full_view())
+            .unwrap_or_else(|error| panic!("cannot multiply matrices: {}", error));
+
+        return TypedMatrix {
+            nb_rows: self.nb_rows,
+            nb_cols: other.nb_cols,
+            matrix: product,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_typed_matrix_new() {
+        let matrix: Matrix<f64> = Matrix::zeros(2, 3);
+        let typed: TypedMatrix<f64, Const<2>, Dyn> = TypedMatrix::new(matrix).unwrap();
+
+        assert_eq!(typed.nb_rows(), 2);
+        assert_eq!(typed.nb_cols(), 3);
+
+        let error: Option<ShapeError> =
+            TypedMatrix::<f64, Const<3>, Const<3>>::new(Matrix::zeros(2, 3)).err();
+        assert_eq!(
+            error,
+            Some(ShapeError::DimensionMismatch {
+                expected: (3, 3),
+                actual: (2, 3)
+            })
+        );
+    }
+
+    #[test]
+    fn test_typed_matrix_mul() {
+        let a: TypedMatrix<i32, Const<2>, Const<3>> =
+            TypedMatrix::new(Matrix::from_rows(&[&[1, 2, 3], &[4, 5, 6]]).unwrap()).unwrap();
+        let b: TypedMatrix<i32, Const<3>, Const<2>> =
+            TypedMatrix::new(Matrix::from_cols(&[&[7, 9, 11], &[8, 10, 12]]).unwrap()).unwrap();
+
+        let product: TypedMatrix<i32, Const<2>, Const<2>> = a.mul(&b);
+        assert_eq!(
+            *product.as_matrix(),
+            Matrix::from_rows(&[&[58, 64], &[139, 154]]).unwrap()
+        );
+
+        // Dynamic dimensions are checked when product is computed
+        let dynamic: TypedMatrix<i32, Dyn, Dyn> = a.into_dyn();
+        let product: TypedMatrix<i32, Dyn, Dyn> = dynamic.mul(&b.into_dyn());
+        assert_eq!((product.nb_rows(), product.nb_cols()), (2, 2));
+
+        let fixed: TypedMatrix<i32, Const<2>, Const<2>> = product.try_into_fixed().unwrap();
+        assert_eq!(fixed.into_matrix()[(1, 1)], 154);
+    }
+
+    #[test]
+    fn test_typed_matrix_try_into_fixed() {
+        let dynamic: TypedMatrix<f64, Dyn, Dyn> = TypedMatrix::new(Matrix::zeros(4, 1)).unwrap();
+
+        assert_eq!(
+            dynamic.try_into_fixed::<1, 4>().err(),
+            Some(ShapeError::DimensionMismatch {
+                expected: (1, 4),
+                actual: (4, 1)
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot multiply matrices")]
+    fn test_typed_matrix_mul_dynamic_mismatch() {
+        let a: TypedMatrix<f64, Dyn, Dyn> = TypedMatrix::new(Matrix::zeros(2, 3)).unwrap();
+        let b: TypedMatrix<f64, Dyn, Dyn> = TypedMatrix::new(Matrix::zeros(2, 3)).unwrap();
+
+        a.mul(&b);
+    }
+}
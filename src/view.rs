@@ -1,4 +1,13 @@
-use std::ops::{Index, IndexMut};
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Index, IndexMut, Mul, Sub};
+
+use super::error::{ArithError, ShapeError, SolveError};
+use super::lu::{lu_factorize, lu_solve};
+use super::matrix::Matrix;
+use super::num::{Float, One, Zero};
+use super::sorting::{apply_permutation, compare_nan_last, compare_nan_last_descending};
+use super::trace::traced;
 
 /// Accessor
 /// This structure define how we access to memory location from matrix indexes (i, j).
@@ -39,10 +48,251 @@ impl Accessor {
         };
     }
 
+    /// Create an accessor with same strides where the first element is moved
+    /// by offset_row rows and offset_col columns
+    pub fn shift(&self, offset_row: usize, offset_col: usize) -> Self {
+        return Self {
+            stride_row: self.stride_row,
+            stride_col: self.stride_col,
+            offset: self.index(offset_row, offset_col),
+        };
+    }
+
+    /// Create an accessor where consecutive row or column indexes step along the diagonal
+    /// starting at (offset_row, offset_col), so a view with one row on it visits the diagonal
+    pub fn diagonal(&self, offset_row: usize, offset_col: usize) -> Self {
+        let stride: usize = self.stride_row + self.stride_col;
+
+        return Self {
+            stride_row: stride,
+            stride_col: stride,
+            offset: self.index(offset_row, offset_col),
+        };
+    }
+
+    /// Create an accessor of transposed matrix by exchanging strides along row and column
+    pub fn transpose(&self) -> Self {
+        return Self {
+            stride_row: self.stride_col,
+            stride_col: self.stride_row,
+            offset: self.offset,
+        };
+    }
+
+    /// Get memory location of first element and storage order of elements of a matrix
+    /// with given dimensions when they fill a block of memory without gap
+    /// Return None if elements are not contiguous
+    pub(crate) fn contiguous_start(
+        &self,
+        nb_rows: usize,
+        nb_cols: usize,
+    ) -> Option<(usize, StorageOrder)> {
+        if nb_rows * nb_cols == 0 {
+            return Some((0, StorageOrder::RowMajor));
+        }
+
+        if Layout::classify(nb_rows, nb_cols, self) != Layout::Contiguous {
+            return None;
+        }
+
+        let row_major: bool =
+            (nb_rows <= 1 || self.stride_row == nb_cols) && (nb_cols <= 1 || self.stride_col == 1);
+        let order: StorageOrder = if row_major {
+            StorageOrder::RowMajor
+        } else {
+            StorageOrder::ColumnMajor
+        };
+
+        return Some((self.index(0, 0), order));
+    }
+
+    /// Check that each element of a matrix with given dimensions has its own memory location
+    pub(crate) fn is_injective(&self, nb_rows: usize, nb_cols: usize) -> bool {
+        let ((small, small_dim), (big, _)): ((usize, usize), (usize, usize)) =
+            self.sorted_axes(nb_rows, nb_cols);
+
+        if small_dim <= 1 {
+            return true;
+        }
+
+        return small > 0
+            && small
+                .checked_mul(small_dim - 1)
+                .and_then(|last| last.checked_add(1))
+                .is_some_and(|end| big >= end);
+    }
+
+    /// Check that memory location is the one of an element of a matrix with given dimensions
+    /// Accessor must be injective for these dimensions
+    pub(crate) fn contains(&self, nb_rows: usize, nb_cols: usize, index: usize) -> bool {
+        if nb_rows * nb_cols == 0 || index < self.offset {
+            return false;
+        }
+
+        let distance: usize = index - self.offset;
+        let ((small, small_dim), (big, big_dim)): ((usize, usize), (usize, usize)) =
+            self.sorted_axes(nb_rows, nb_cols);
+
+        if small_dim <= 1 {
+            return distance == 0;
+        }
+
+        let (quotient, remainder): (usize, usize) = if big_dim <= 1 {
+            (0, distance)
+        } else {
+            (distance / big, distance % big)
+        };
+
+        return quotient < big_dim && remainder % small == 0 && remainder / small < small_dim;
+    }
+
+    /// Get (stride, dimension) of axes of a matrix with given dimensions sorted by stride,
+    /// where an axis with one element is put last since its stride is never used
+    fn sorted_axes(&self, nb_rows: usize, nb_cols: usize) -> ((usize, usize), (usize, usize)) {
+        let mut axes: [(usize, usize); 2] =
+            [(self.stride_row, nb_rows), (self.stride_col, nb_cols)];
+
+        for axis in axes.iter_mut() {
+            if axis.1 <= 1 {
+                *axis = (usize::MAX, 1);
+            }
+        }
+
+        axes.sort();
+        return (axes[0], axes[1]);
+    }
+
     /// Compute memory location in vector from row index and colunm index
     pub fn index(&self, row_id: usize, col_id: usize) -> usize {
         return row_id * self.stride_row + col_id * self.stride_col + self.offset;
     }
+
+    /// Compute memory location in vector from row index and colunm index
+    /// Return None if memory location overflows usize
+    pub(crate) fn checked_index(&self, row_id: usize, col_id: usize) -> Option<usize> {
+        return row_id
+            .checked_mul(self.stride_row)?
+            .checked_add(col_id.checked_mul(self.stride_col)?)?
+            .checked_add(self.offset);
+    }
+}
+
+/// Storage order
+/// This enumeration defines how elements of matrix are laid out in memory,
+/// row after row for row-major order and column after column for column-major order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StorageOrder {
+    RowMajor,
+    ColumnMajor,
+}
+
+/// Memory layout
+/// This enumeration classifies how elements of a view are laid out in memory
+/// - Contiguous when elements fill a block of memory without gap, in any storage order
+/// - RowStrided when elements of each row are adjacent but rows are separated by a gap
+/// - ColStrided when elements of each column are adjacent but columns are separated by a gap
+/// - General when neither rows nor columns have adjacent elements
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    Contiguous,
+    RowStrided,
+    ColStrided,
+    General,
+}
+
+impl Layout {
+    /// Classify layout of elements of a view from its dimensions and its accessor
+    fn classify(nb_rows: usize, nb_cols: usize, accessor: &Accessor) -> Self {
+        let row_unit: bool = nb_cols <= 1 || accessor.stride_col == 1;
+        let col_unit: bool = nb_rows <= 1 || accessor.stride_row == 1;
+
+        if (row_unit && (nb_rows <= 1 || accessor.stride_row == nb_cols))
+            || (col_unit && (nb_cols <= 1 || accessor.stride_col == nb_rows))
+        {
+            return Layout::Contiguous;
+        } else if row_unit {
+            return Layout::RowStrided;
+        } else if col_unit {
+            return Layout::ColStrided;
+        } else {
+            return Layout::General;
+        }
+    }
+
+    /// Check that layout allows fast paths, which need adjacent elements along rows or columns
+    pub fn has_fast_path(&self) -> bool {
+        return *self != Layout::General;
+    }
+}
+
+/// Layout report
+/// This structure describes memory layout of a view to diagnose slow accesses,
+/// with its strides, its layout classification, whether fast paths apply
+/// and the number of bytes spanned in memory between its first and last elements
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LayoutReport {
+    pub stride_row: usize,
+    pub stride_col: usize,
+    pub layout: Layout,
+    pub fast_path: bool,
+    pub span_bytes: usize,
+}
+
+impl LayoutReport {
+    /// Create layout report of a view with elements of type T from its dimensions and its accessor
+    fn new<T>(nb_rows: usize, nb_cols: usize, accessor: &Accessor) -> Self {
+        let layout: Layout = Layout::classify(nb_rows, nb_cols, accessor);
+
+        let span_bytes: usize = if nb_rows == 0 || nb_cols == 0 {
+            0
+        } else {
+            let span: usize = accessor.index(nb_rows - 1, nb_cols - 1) - accessor.index(0, 0) + 1;
+            span * std::mem::size_of::<T>()
+        };
+
+        return Self {
+            stride_row: accessor.stride_row,
+            stride_col: accessor.stride_col,
+            layout,
+            fast_path: layout.has_fast_path(),
+            span_bytes,
+        };
+    }
+}
+
+/// Assert in debug builds that view or mutable view has a layout allowing fast paths
+#[macro_export]
+macro_rules! debug_assert_fast_path {
+    ($view:expr) => {
+        debug_assert!(
+            $view.layout().has_fast_path(),
+            "view with layout {:?} has no fast path",
+            $view.layout()
+        );
+    };
+}
+
+/// Check that (index of row, index of column) is inside view dimensions
+/// It panics with the faulty index and view dimensions otherwise
+fn check_bounds(nb_rows: usize, nb_cols: usize, index: (usize, usize)) {
+    assert!(
+        index.0 < nb_rows && index.1 < nb_cols,
+        "index ({}, {}) is out of bounds of view with dimensions {}x{}",
+        index.0,
+        index.1,
+        nb_rows,
+        nb_cols
+    );
+}
+
+/// Check that dimensions of an operand are the expected ones
+fn check_dimensions(expected: (usize, usize), actual: (usize, usize)) -> Result<(), ShapeError> {
+    if expected != actual {
+        return Err(ShapeError::DimensionMismatch { expected, actual });
+    }
+
+    return Ok(());
 }
 
 /// View
@@ -76,200 +326,2642 @@ impl<'a, T> View<'a, T> {
     pub fn nb_cols(&self) -> usize {
         return self.nb_cols;
     }
-}
 
-impl<'a, T> Index<(usize, usize)> for View<'a, T> {
-    type Output = T;
+    /// Get layout classification of elements of view in memory
+    pub fn layout(&self) -> Layout {
+        return Layout::classify(self.nb_rows, self.nb_cols, &self.accessor);
+    }
 
-    /// This allows to read the view element at (index of row, index of column) position
-    /// like this let element: f32 = view[(0, 2)];
-    fn index(&self, index: (usize, usize)) -> &Self::Output {
-        let id: usize = self.accessor.index(index.0, index.1);
-        return self.data.index(id);
+    /// Get report on memory layout of view
+    pub fn layout_report(&self) -> LayoutReport {
+        return LayoutReport::new::<T>(self.nb_rows, self.nb_cols, &self.accessor);
     }
-}
 
-/// Mutable View
-/// This struture is a mutable view on part of matrix, so it does not own data.
-/// It contains number of rows and number of columns of view, an accessor
-/// to get memory position of elements in contiguous memory slice and a mutable slice on data owned by matrix
-pub struct ViewMut<'a, T> {
-    nb_rows: usize,
-    nb_cols: usize,
-    accessor: Accessor,
-    data: &'a mut [T],
-}
+    /// Get slice on elements of row of view when they are adjacent in memory
+    /// Return None if row index is out of view or if elements of rows are not adjacent
+    pub(crate) fn row_slice(&self, row_id: usize) -> Option<&'a [T]> {
+        if row_id >= self.nb_rows || (self.nb_cols > 1 && self.accessor.stride_col != 1) {
+            return None;
+        }
 
-impl<'a, T> ViewMut<'a, T> {
-    /// Create a mutable view from number of rows, number of columns, an accessor and a mutable slice
-    pub fn new(nb_rows: usize, nb_cols: usize, accessor: Accessor, data: &'a mut [T]) -> Self {
-        return Self {
-            nb_rows,
-            nb_cols,
-            accessor,
-            data,
-        };
+        let start: usize = self.accessor.index(row_id, 0);
+        return Some(&self.data[start..start + self.nb_cols]);
     }
 
-    /// Get number of rows of mutable view
-    pub fn nb_rows(&self) -> usize {
-        return self.nb_rows;
-    }
+    /// Get slice on elements of contiguous view with storage order in which they are laid out
+    /// Return None if view is not contiguous
+    pub(crate) fn contiguous_slice(&self) -> Option<(&'a [T], StorageOrder)> {
+        let (start, order): (usize, StorageOrder) =
+            self.accessor.contiguous_start(self.nb_rows, self.nb_cols)?;
 
-    /// Get number of columns of mutable view
-    pub fn nb_cols(&self) -> usize {
-        return self.nb_cols;
+        return Some((
+            &self.data[start..start + self.nb_rows * self.nb_cols],
+            order,
+        ));
     }
-}
 
-impl<'a, T> Index<(usize, usize)> for ViewMut<'a, T> {
-    type Output = T;
+    /// Get element at (index of row, index of column) position
+    /// Return None if one of indexes is out of view dimensions
+    pub fn get(&self, row_id: usize, col_id: usize) -> Option<&T> {
+        if row_id >= self.nb_rows || col_id >= self.nb_cols {
+            return None;
+        }
 
-    /// This allows to read the view element at (index of row, index of column) position
-    /// like this let element: f32 = view[(0, 2)];
-    fn index(&self, index: (usize, usize)) -> &Self::Output {
-        let id: usize = self.accessor.index(index.0, index.1);
-        return self.data.index(id);
+        return self.data.get(self.accessor.index(row_id, col_id));
     }
-}
 
-impl<'a, T> IndexMut<(usize, usize)> for ViewMut<'a, T> {
-    /// This allows to write an value in matrix at (index of row, index of column) position
-    /// like this matrix[(0, 2)] = 3.1415;
-    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
-        let id: usize = self.accessor.index(index.0, index.1);
-        return self.data.index_mut(id);
+    /// Get transposed view sharing same data, without any copy
+    pub fn transpose(&self) -> View<'a, T> {
+        return View::new(
+            self.nb_cols,
+            self.nb_rows,
+            self.accessor.transpose(),
+            self.data,
+        );
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::cmp::Ordering;
+    /// Get an iterator on elements of view in row-major logical order
+    pub fn iter(&self) -> ViewIter<'a, T> {
+        return ViewIter {
+            nb_rows: self.nb_rows,
+            nb_cols: self.nb_cols,
+            accessor: self.accessor,
+            data: self.data,
+            position: 0,
+        };
+    }
 
-    #[test]
-    fn test_accessor_new() {
-        let stride_row: usize = 2;
-        let stride_col: usize = 3;
+    /// Create a new matrix with same dimensions where each element is f applied on
+    /// the element at same position. The result has the storage order of view, and its
+    /// elements are visited in memory order, directly over the slice when view is contiguous
+    pub fn map<U, F>(&self, mut f: F) -> Matrix<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        if let Some((data, order)) = self.contiguous_slice() {
+            let data: Vec<U> = data.iter().map(f).collect();
 
-        let accessor = Accessor::new(stride_row, stride_col);
-        assert_eq!(accessor.stride_row, stride_row);
-        assert_eq!(accessor.stride_col, stride_col);
-        assert_eq!(accessor.offset, 0);
-    }
+            return match order {
+                StorageOrder::RowMajor => {
+                    Matrix::from_vec_row_major(self.nb_rows, self.nb_cols, data)
+                }
+                StorageOrder::ColumnMajor => {
+                    Matrix::from_vec_column_major(self.nb_rows, self.nb_cols, data)
+                }
+            }
+            .expect("mapped data has one element per element of view");
+        }
 
-    #[test]
-    fn test_accessor_new_with_offset() {
-        let stride_row: usize = 2;
-        let stride_col: usize = 3;
-        let offset_row: usize = 1;
-        let offset_col: usize = 1;
+        if self.accessor.stride_col <= self.accessor.stride_row {
+            return Matrix::from_fn_row_major(self.nb_rows, self.nb_cols, |row_id, col_id| {
+                return f(&self[(row_id, col_id)]);
+            });
+        } else {
+            return Matrix::from_fn_column_major(self.nb_rows, self.nb_cols, |row_id, col_id| {
+                return f(&self[(row_id, col_id)]);
+            });
+        }
+    }
 
-        let accessor = Accessor::new_with_offset(stride_row, stride_col, offset_row, offset_col);
-        assert_eq!(accessor.stride_row, stride_row);
-        assert_eq!(accessor.stride_col, stride_col);
+    /// Get view with one row on row of view at given index, sharing same data
+    /// It panics if index is out of view dimensions
+    pub fn row(&self, row_id: usize) -> View<'a, T> {
+        assert!(
+            row_id < self.nb_rows,
+            "row index {} is out of bounds of view with dimensions {}x{}",
+            row_id,
+            self.nb_rows,
+            self.nb_cols
+        );
 
-        let offset_ref: usize = stride_row * offset_row + stride_col * offset_col;
-        assert_eq!(accessor.offset, offset_ref);
+        return View::new(1, self.nb_cols, self.accessor.shift(row_id, 0), self.data);
     }
 
-    #[test]
-    fn test_accessor_index() {
-        let stride_row: usize = 3;
-        let stride_col: usize = 3;
+    /// Get view with one column on column of view at given index, sharing same data
+    /// It panics if index is out of view dimensions
+    pub fn col(&self, col_id: usize) -> View<'a, T> {
+        assert!(
+            col_id < self.nb_cols,
+            "column index {} is out of bounds of view with dimensions {}x{}",
+            col_id,
+            self.nb_rows,
+            self.nb_cols
+        );
 
-        let mut accessor = Accessor::new(stride_row, 1);
-        assert_eq!(accessor.index(1, 2), stride_row + 2);
+        return View::new(self.nb_rows, 1, self.accessor.shift(0, col_id), self.data);
+    }
 
-        accessor = Accessor::new(1, stride_col);
-        assert_eq!(accessor.index(2, 1), 2 + stride_col);
+    /// Get an iterator on rows of view, each row being a view with one row
+    pub fn rows(&self) -> RowIter<'a, T> {
+        return RowIter {
+            nb_rows: self.nb_rows,
+            nb_cols: self.nb_cols,
+            accessor: self.accessor,
+            data: self.data,
+            row_id: 0,
+        };
     }
 
-    #[test]
-    fn test_accessor_index_with_offset() {
-        let stride_row: usize = 4;
-        let stride_col: usize = 4;
-        let offset_row: usize = 1;
-        let offset_col: usize = 1;
+    /// Get an iterator on columns of view, each column being a view with one column
+    pub fn cols(&self) -> ColIter<'a, T> {
+        return ColIter {
+            nb_rows: self.nb_rows,
+            accessor: self.accessor,
+            data: self.data,
+            front: 0,
+            back: self.nb_cols,
+        };
+    }
 
-        let mut accessor = Accessor::new_with_offset(stride_row, 1, offset_row, offset_col);
-        assert_eq!(accessor.index(1, 2), stride_row + 7);
+    /// Compute lower and upper bandwidths of view, that is the farthest diagonals
+    /// below and above the main diagonal which contain an element that is not zero
+    pub fn bandwidth<F>(&self, is_zero: F) -> (usize, usize)
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut lower: usize = 0;
+        let mut upper: usize = 0;
 
-        accessor = Accessor::new_with_offset(1, stride_col, offset_row, offset_col);
-        assert_eq!(accessor.index(2, 1), 7 + stride_col);
+        for row_id in 0..self.nb_rows {
+            for col_id in 0..self.nb_cols {
+                if !is_zero(&self[(row_id, col_id)]) {
+                    if row_id > col_id {
+                        lower = lower.max(row_id - col_id);
+                    } else {
+                        upper = upper.max(col_id - row_id);
+                    }
+                }
+            }
+        }
+
+        return (lower, upper);
     }
 
-    #[test]
-    fn test_view_new() {
-        let nb_rows: usize = 3;
-        let nb_cols: usize = 3;
-        let data: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+    /// Pack elements of view inside the band of kl subdiagonals and ku superdiagonals
+    /// in LAPACK band storage, that is a column-major matrix with kl + ku + 1 rows and
+    /// as many columns as the view, where element (i, j) is stored at row ku + i - j of column j.
+    /// Elements outside the band are dropped and unused positions are set to default value
+    pub fn to_banded(&self, kl: usize, ku: usize) -> Vec<T>
+    where
+        T: Clone + Default,
+    {
+        let nb_band_rows: usize = kl + ku + 1;
+        let mut banded: Vec<T> = vec![T::default(); nb_band_rows * self.nb_cols];
 
-        let view: View<i32> =
-            View::new(nb_rows, nb_cols, Accessor::new(nb_cols, 1), data.as_slice());
+        for col_id in 0..self.nb_cols {
+            let first_row: usize = col_id.saturating_sub(ku);
+            let last_row: usize = (col_id + kl + 1).min(self.nb_rows);
 
-        assert_eq!(view.nb_rows, nb_rows);
-        assert_eq!(view.nb_cols, nb_cols);
+            for row_id in first_row..last_row {
+                banded[col_id * nb_band_rows + ku + row_id - col_id] =
+                    self[(row_id, col_id)].clone();
+            }
+        }
 
-        match view.data.partial_cmp(data.as_slice()) {
-            Some(result) => assert_eq!(result, Ordering::Equal),
-            None => assert!(false),
+        return banded;
+    }
+
+    /// Convert square adjacency matrix of a graph to its adjacency list
+    /// For each row, it gives indexes of columns where is_edge holds
+    pub fn to_adjacency_list<F>(&self, is_edge: F) -> Result<Vec<Vec<usize>>, ShapeError>
+    where
+        F: Fn(&T) -> bool,
+    {
+        if self.nb_rows != self.nb_cols {
+            return Err(ShapeError::NotSquare {
+                nb_rows: self.nb_rows,
+                nb_cols: self.nb_cols,
+            });
         }
+
+        return Ok((0..self.nb_rows)
+            .map(|row_id| {
+                return (0..self.nb_cols)
+                    .filter(|&col_id| is_edge(&self[(row_id, col_id)]))
+                    .collect();
+            })
+            .collect());
     }
 
-    #[test]
-    fn test_view_dimensions_access() {
-        let nb_rows: usize = 3;
-        let nb_cols: usize = 3;
-        let data: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+    /// Compute row-major matrix of Jaccard similarity between each pair of rows of binary view,
+    /// where non-zero elements are members of the set of their row.
+    /// Row without non-zero element has similarity 1 with itself and 0 with other rows
+    pub fn row_jaccard_matrix(&self) -> Matrix<f64>
+    where
+        T: PartialEq + Zero,
+    {
+        let zero: T = T::zero();
+        let members: Vec<Vec<bool>> = self
+            .rows()
+            .map(|row| row.iter().map(|element| *element != zero).collect())
+            .collect();
 
-        let view: View<i32> =
-            View::new(nb_rows, nb_cols, Accessor::new(nb_cols, 1), data.as_slice());
+        return Matrix::from_fn_row_major(self.nb_rows, self.nb_rows, |first, second| {
+            if first == second {
+                return 1.0;
+            }
 
-        assert_eq!(view.nb_rows(), nb_rows);
-        assert_eq!(view.nb_cols(), nb_cols);
+            let mut intersection: usize = 0;
+            let mut union: usize = 0;
+
+            for (&a, &b) in members[first].iter().zip(members[second].iter()) {
+                if a && b {
+                    intersection += 1;
+                }
+
+                if a || b {
+                    union += 1;
+                }
+            }
+
+            if union == 0 {
+                return 0.0;
+            }
+
+            return (intersection as f64) / (union as f64);
+        });
     }
 
-    #[test]
-    fn test_view_data_access() {
-        let nb_rows: usize = 3;
-        let nb_cols: usize = 3;
-        let data: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+    /// Compute trace of square view, that is the sum of elements on its main diagonal
+    pub fn trace(&self) -> Result<T, ShapeError>
+    where
+        T: Copy + Default + Add<Output = T>,
+    {
+        if self.nb_rows != self.nb_cols {
+            return Err(ShapeError::NotSquare {
+                nb_rows: self.nb_rows,
+                nb_cols: self.nb_cols,
+            });
+        }
 
-        let view: View<i32> =
-            View::new(nb_rows, nb_cols, Accessor::new(1, nb_rows), data.as_slice());
+        return Ok((0..self.nb_rows).fold(T::default(), |acc: T, index| {
+            return acc + self[(index, index)];
+        }));
+    }
 
-        assert_eq!(view[(0, 0)], data[0]);
-        assert_eq!(view[(1, 0)], data[1]);
-        assert_eq!(view[(2, 0)], data[2]);
-        assert_eq!(view[(0, 1)], data[3]);
-        assert_eq!(view[(1, 1)], data[4]);
-        assert_eq!(view[(2, 1)], data[5]);
-        assert_eq!(view[(0, 2)], data[6]);
-        assert_eq!(view[(1, 2)], data[7]);
-        assert_eq!(view[(2, 2)], data[8]);
+    /// Compute row-major symmetric matrix of dot products between each pair of rows of view,
+    /// which is view multiplied by its transpose. Only lower triangle is computed then mirrored
+    pub fn row_gram(&self) -> Matrix<T>
+    where
+        T: Copy + Default + Add<Output = T> + Mul<Output = T>,
+    {
+        let mut gram: Matrix<T> = Matrix::new_row_major(self.nb_rows, self.nb_rows);
+
+        for first in 0..self.nb_rows {
+            for second in 0..=first {
+                let mut sum: T = T::default();
+
+                for col_id in 0..self.nb_cols {
+                    sum = sum + self[(first, col_id)] * self[(second, col_id)];
+                }
+
+                gram[(first, second)] = sum;
+            }
+        }
+
+        gram.mirror_lower_to_upper().expect("gram matrix is square");
+
+        return gram;
     }
 
-    #[test]
-    fn test_view_data_access_with_offset() {
-        let nb_rows: usize = 3;
-        let nb_cols: usize = 3;
-        let data: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+    /// Count elements of view where mask is true
+    /// Mask must have the same dimensions as view
+    pub fn count_where(&self, mask: &View<bool>) -> Result<usize, ShapeError> {
+        check_dimensions(
+            (self.nb_rows, self.nb_cols),
+            (mask.nb_rows(), mask.nb_cols()),
+        )?;
 
-        let view: View<i32> = View::new(
-            nb_rows - 1,
-            nb_cols - 1,
-            Accessor::new_with_offset(1, nb_rows, 1, 1),
-            data.as_slice(),
-        );
+        let mut count: usize = 0;
 
-        assert_eq!(view[(0, 0)], data[4]);
-        assert_eq!(view[(1, 0)], data[5]);
-        assert_eq!(view[(0, 1)], data[7]);
-        assert_eq!(view[(1, 1)], data[8]);
+        for row_id in 0..self.nb_rows {
+            for col_id in 0..self.nb_cols {
+                if mask[(row_id, col_id)] {
+                    count += 1;
+                }
+            }
+        }
+
+        return Ok(count);
     }
 
-    #[test]
+    /// Copy elements of view where mask is true, in row-major order
+    /// Mask must have the same dimensions as view
+    pub fn select_where(&self, mask: &View<bool>) -> Result<Vec<T>, ShapeError>
+    where
+        T: Clone,
+    {
+        check_dimensions(
+            (self.nb_rows, self.nb_cols),
+            (mask.nb_rows(), mask.nb_cols()),
+        )?;
+
+        let mut selection: Vec<T> = Vec::new();
+
+        for row_id in 0..self.nb_rows {
+            for col_id in 0..self.nb_cols {
+                if mask[(row_id, col_id)] {
+                    selection.push(self[(row_id, col_id)].clone());
+                }
+            }
+        }
+
+        return Ok(selection);
+    }
+
+    /// Tile view into non-overlapping blocks of block_rows rows and block_cols columns,
+    /// and reduce each block to one element of a row-major matrix with f, as done in pooling.
+    /// Blocks on last rows and columns are truncated when dimensions are not multiples of block ones
+    pub fn block_reduce<F>(&self, block_rows: usize, block_cols: usize, f: F) -> Matrix<T>
+    where
+        F: Fn(&View<T>) -> T,
+    {
+        assert!(
+            block_rows > 0 && block_cols > 0,
+            "blocks must have at least one row and one column"
+        );
+
+        return Matrix::from_fn_row_major(
+            self.nb_rows.div_ceil(block_rows),
+            self.nb_cols.div_ceil(block_cols),
+            |row_id, col_id| {
+                let start_row: usize = row_id * block_rows;
+                let start_col: usize = col_id * block_cols;
+
+                let block: View<T> = View::new(
+                    block_rows.min(self.nb_rows - start_row),
+                    block_cols.min(self.nb_cols - start_col),
+                    self.accessor.shift(start_row, start_col),
+                    self.data,
+                );
+
+                return f(&block);
+            },
+        );
+    }
+}
+
+impl<'a, T> View<'a, T>
+where
+    T: Copy,
+{
+    /// Copy elements of view in a vector following the given logical order,
+    /// whatever the storage order of the underlying data
+    pub fn to_vec_in_order(&self, order: StorageOrder) -> Vec<T> {
+        let mut elements: Vec<T> = Vec::with_capacity(self.nb_rows * self.nb_cols);
+
+        match order {
+            StorageOrder::RowMajor => {
+                for row_id in 0..self.nb_rows {
+                    for col_id in 0..self.nb_cols {
+                        elements.push(self[(row_id, col_id)]);
+                    }
+                }
+            }
+            StorageOrder::ColumnMajor => {
+                for col_id in 0..self.nb_cols {
+                    for row_id in 0..self.nb_rows {
+                        elements.push(self[(row_id, col_id)]);
+                    }
+                }
+            }
+        }
+
+        return elements;
+    }
+
+    /// Create a row-major matrix whose element at (i, j) position is element of view
+    /// at (row_indices[i], col_indices[j]) position. Indices can be repeated and in any order
+    pub fn select(
+        &self,
+        row_indices: &[usize],
+        col_indices: &[usize],
+    ) -> Result<Matrix<T>, ShapeError> {
+        if let Some(&row_id) = row_indices.iter().find(|&&row_id| row_id >= self.nb_rows) {
+            return Err(ShapeError::RowOutOfBounds {
+                row_id,
+                nb_rows: self.nb_rows,
+            });
+        }
+
+        if let Some(&col_id) = col_indices.iter().find(|&&col_id| col_id >= self.nb_cols) {
+            return Err(ShapeError::ColumnOutOfBounds {
+                col_id,
+                nb_cols: self.nb_cols,
+            });
+        }
+
+        return Ok(Matrix::from_fn_row_major(
+            row_indices.len(),
+            col_indices.len(),
+            |row_id, col_id| self[(row_indices[row_id], col_indices[col_id])],
+        ));
+    }
+}
+
+impl<'a, T> View<'a, T>
+where
+    T: PartialOrd,
+{
+    /// Get for each row the permutation of column indexes which sorts the row in ascending order
+    /// The sort is stable and values not comparable with themselves, like NaN, are put at the end
+    pub fn argsort_rows(&self) -> Matrix<usize> {
+        let mut indexes: Vec<usize> = Vec::with_capacity(self.nb_rows * self.nb_cols);
+
+        for row_id in 0..self.nb_rows {
+            let mut permutation: Vec<usize> = (0..self.nb_cols).collect();
+
+            permutation.sort_by(|&a, &b| {
+                return compare_nan_last(&self[(row_id, a)], &self[(row_id, b)]);
+            });
+
+            indexes.extend(permutation);
+        }
+
+        return Matrix::from_fn_row_major(self.nb_rows, self.nb_cols, |row_id, col_id| {
+            return indexes[row_id * self.nb_cols + col_id];
+        });
+    }
+
+    /// Get for each row the column index of its largest value, the first one on ties
+    /// Values not comparable with themselves, like NaN, are selected only in rows without other values
+    /// Panic if view has rows but no column
+    pub fn argmax_rows(&self) -> Vec<usize> {
+        return self.arg_best_rows(compare_nan_last_descending);
+    }
+
+    /// Get for each row the column index of its smallest value, the first one on ties
+    /// Values not comparable with themselves, like NaN, are selected only in rows without other values
+    /// Panic if view has rows but no column
+    pub fn argmin_rows(&self) -> Vec<usize> {
+        return self.arg_best_rows(compare_nan_last);
+    }
+
+    /// Get for each row the column index of first value placed before all others by compare
+    fn arg_best_rows<F>(&self, compare: F) -> Vec<usize>
+    where
+        F: Fn(&T, &T) -> Ordering,
+    {
+        assert!(
+            self.nb_rows == 0 || self.nb_cols > 0,
+            "rows of view must have at least one column"
+        );
+
+        return (0..self.nb_rows)
+            .map(|row_id| {
+                let mut best_id: usize = 0;
+
+                for col_id in 1..self.nb_cols {
+                    if compare(&self[(row_id, col_id)], &self[(row_id, best_id)]) == Ordering::Less
+                    {
+                        best_id = col_id;
+                    }
+                }
+
+                return best_id;
+            })
+            .collect();
+    }
+
+    /// Get for each row the k largest values, in descending order, and their column indexes
+    /// Values are found by partial selection, so rows are not entirely sorted.
+    /// Equal values are ordered by column index, values not comparable with themselves, like NaN,
+    /// are selected only after all other values, and k is clamped to the number of columns
+    pub fn top_k_per_row(&self, k: usize) -> (Matrix<T>, Matrix<usize>)
+    where
+        T: Clone,
+    {
+        let k: usize = k.min(self.nb_cols);
+        let mut indexes: Vec<usize> = Vec::with_capacity(self.nb_rows * k);
+
+        for row_id in 0..self.nb_rows {
+            let compare = |a: &usize, b: &usize| {
+                return compare_nan_last_descending(&self[(row_id, *a)], &self[(row_id, *b)])
+                    .then(a.cmp(b));
+            };
+
+            let mut candidates: Vec<usize> = (0..self.nb_cols).collect();
+
+            if k > 0 && k < self.nb_cols {
+                candidates.select_nth_unstable_by(k - 1, compare);
+            }
+
+            candidates.truncate(k);
+            candidates.sort_by(compare);
+
+            indexes.extend(candidates);
+        }
+
+        let top_indexes: Matrix<usize> = Matrix::from_fn_row_major(self.nb_rows, k, |row_id, i| {
+            return indexes[row_id * k + i];
+        });
+
+        let top_values: Matrix<T> = Matrix::from_fn_row_major(self.nb_rows, k, |row_id, i| {
+            return self[(row_id, top_indexes[(row_id, i)])].clone();
+        });
+
+        return (top_values, top_indexes);
+    }
+
+    /// Create a row-major binary matrix with one where element of view
+    /// is greater than or equal to given value and zero elsewhere
+    pub fn threshold(&self, value: T) -> Matrix<T>
+    where
+        T: Copy + Zero + One,
+    {
+        return Matrix::from_fn_row_major(self.nb_rows, self.nb_cols, |row_id, col_id| {
+            if self[(row_id, col_id)] >= value {
+                return T::one();
+            } else {
+                return T::zero();
+            }
+        });
+    }
+}
+
+impl<'a, T> View<'a, T>
+where
+    T: Float,
+{
+    /// Check that view and other have same dimensions and that their elements at each position
+    /// are equal or differ by at most the largest of abs_tol and rel_tol times the largest magnitude
+    /// NaN is never approximately equal to anything, infinities only to themselves
+    pub fn approx_eq(&self, other: &View<T>, abs_tol: T, rel_tol: T) -> bool {
+        if self.nb_rows != other.nb_rows || self.nb_cols != other.nb_cols {
+            return false;
+        }
+
+        return self.iter().zip(other.iter()).all(|(&a, &b)| {
+            if a == b {
+                return true;
+            }
+
+            if !a.is_finite() || !b.is_finite() {
+                return false;
+            }
+
+            let magnitude: T = if a.abs() > b.abs() { a.abs() } else { b.abs() };
+            let relative: T = rel_tol * magnitude;
+            let tolerance: T = if abs_tol > relative {
+                abs_tol
+            } else {
+                relative
+            };
+
+            return (a - b).abs() <= tolerance;
+        });
+    }
+}
+
+impl<'a, T> View<'a, T>
+where
+    T: Copy + Into<f64>,
+{
+    /// Copy elements of view as floats in row-major order
+    pub(crate) fn to_row_major_f64(&self) -> Vec<f64> {
+        let mut data: Vec<f64> = Vec::with_capacity(self.nb_rows * self.nb_cols);
+
+        for row_id in 0..self.nb_rows {
+            for col_id in 0..self.nb_cols {
+                data.push(self[(row_id, col_id)].into());
+            }
+        }
+
+        return data;
+    }
+
+    /// Compute sign of determinant of square view, that is -1, 0 or 1
+    /// It is obtained from LU factorization with partial pivoting as the parity
+    /// of row permutation times the signs of pivots, without computing determinant magnitude
+    pub fn determinant_sign(&self) -> Result<i32, ShapeError> {
+        if self.nb_rows != self.nb_cols {
+            return Err(ShapeError::NotSquare {
+                nb_rows: self.nb_rows,
+                nb_cols: self.nb_cols,
+            });
+        }
+
+        let n: usize = self.nb_rows;
+
+        return Ok(traced(
+            "determinant_sign",
+            (n, n, n),
+            "partial_pivoting",
+            || {
+                let mut lu: Vec<f64> = self.to_row_major_f64();
+
+                let permutation: Vec<usize> = match lu_factorize(&mut lu, n) {
+                    Some(permutation) => permutation,
+                    None => return 0,
+                };
+
+                let mut sign: i32 = permutation_sign(&permutation);
+
+                for k in 0..n {
+                    if lu[k * n + k] < 0.0 {
+                        sign = -sign;
+                    }
+                }
+
+                return sign;
+            },
+        ));
+    }
+
+    /// Compute ratio of largest to smallest absolute value of diagonal elements of square view
+    /// It is a cheap heuristic of conditioning to check before solving a system
+    pub fn diagonal_ratio(&self) -> Result<f64, ShapeError> {
+        if self.nb_rows != self.nb_cols {
+            return Err(ShapeError::NotSquare {
+                nb_rows: self.nb_rows,
+                nb_cols: self.nb_cols,
+            });
+        }
+
+        if self.nb_rows == 0 {
+            return Err(ShapeError::Empty);
+        }
+
+        let mut min: f64 = f64::INFINITY;
+        let mut max: f64 = 0.0;
+
+        for index in 0..self.nb_rows {
+            let value: f64 = self[(index, index)].into().abs();
+
+            if value == 0.0 {
+                return Err(ShapeError::ZeroDiagonal { index });
+            }
+
+            min = min.min(value);
+            max = max.max(value);
+        }
+
+        return Ok(max / min);
+    }
+
+    /// Compute element-wise reciprocal of view as a row-major float matrix
+    /// An error is returned at first zero element found in row-major order
+    pub fn reciprocal(&self) -> Result<Matrix<f64>, ArithError> {
+        let mut data: Vec<f64> = Vec::with_capacity(self.nb_rows * self.nb_cols);
+
+        for row_id in 0..self.nb_rows {
+            for col_id in 0..self.nb_cols {
+                let value: f64 = self[(row_id, col_id)].into();
+
+                if value == 0.0 {
+                    return Err(ArithError::DivisionByZero { row_id, col_id });
+                }
+
+                data.push(1.0 / value);
+            }
+        }
+
+        return Ok(Matrix::from_fn_row_major(
+            self.nb_rows,
+            self.nb_cols,
+            |row_id, col_id| data[row_id * self.nb_cols + col_id],
+        ));
+    }
+
+    /// Estimate spectral norm of view, that is its largest singular value, by power iteration
+    /// on A^T * A. Iterations stop when relative change of dominant eigenvalue is below tolerance
+    pub fn spectral_norm(&self, tol: f64, max_iter: usize) -> Result<f64, SolveError> {
+        // Compute A^T * (A * vector)
+        let gram_product = |vector: &[f64]| -> Vec<f64> {
+            let product: Vec<f64> = (0..self.nb_rows)
+                .map(|row_id| {
+                    return (0..self.nb_cols)
+                        .map(|col_id| self[(row_id, col_id)].into() * vector[col_id])
+                        .sum();
+                })
+                .collect();
+
+            return (0..self.nb_cols)
+                .map(|col_id| {
+                    return (0..self.nb_rows)
+                        .map(|row_id| self[(row_id, col_id)].into() * product[row_id])
+                        .sum();
+                })
+                .collect();
+        };
+
+        let eigenvalue: f64 = dominant_eigenvalue(self.nb_cols, gram_product, tol, max_iter)?;
+        return Ok(eigenvalue.sqrt());
+    }
+
+    /// Estimate 2-norm condition number of square symmetric view, that is the ratio of
+    /// largest to smallest absolute eigenvalues. Largest is found by power iteration on A^2 and
+    /// smallest by inverse power iteration on A^2 with an LU factorization, without forming inverse.
+    /// Singular view has an infinite condition number
+    pub fn condition_estimate(&self, tol: f64, max_iter: usize) -> Result<f64, SolveError> {
+        if self.nb_rows != self.nb_cols {
+            return Err(SolveError::NotSquare {
+                nb_rows: self.nb_rows,
+                nb_cols: self.nb_cols,
+            });
+        }
+
+        let n: usize = self.nb_rows;
+        let mut lu: Vec<f64> = self.to_row_major_f64();
+
+        let product = |vector: &[f64]| -> Vec<f64> {
+            return (0..n)
+                .map(|row_id| {
+                    return (0..n)
+                        .map(|col_id| self[(row_id, col_id)].into() * vector[col_id])
+                        .sum();
+                })
+                .collect();
+        };
+
+        // Eigenvalues of A^2 are squares of the ones of A, so +-lambda do not cancel out
+        let largest: f64 =
+            dominant_eigenvalue(n, |vector| product(&product(vector)), tol, max_iter)?.sqrt();
+
+        let permutation: Vec<usize> = match lu_factorize(&mut lu, n) {
+            Some(permutation) => permutation,
+            None => return Ok(f64::INFINITY),
+        };
+
+        let solve = |vector: &[f64]| -> Vec<f64> {
+            return lu_solve(&lu, &permutation, n, vector);
+        };
+
+        let smallest_inverse: f64 =
+            dominant_eigenvalue(n, |vector| solve(&solve(vector)), tol, max_iter)?.sqrt();
+
+        return Ok(largest * smallest_inverse);
+    }
+}
+
+/// Compute dominant eigenvalue of symmetric positive semi-definite linear map of size n
+/// by power iteration. Norm of image of unit vector increases towards it, and iterations stop
+/// when its relative change is below tolerance. If map sends start vector to zero,
+/// vectors of canonical basis are tried before concluding that map is zero
+fn dominant_eigenvalue<F>(n: usize, apply: F, tol: f64, max_iter: usize) -> Result<f64, SolveError>
+where
+    F: Fn(&[f64]) -> Vec<f64>,
+{
+    let basis = (0..n).map(|k| (0..n).map(|i| if i == k { 1.0 } else { 0.0 }).collect());
+
+    'starts: for start in std::iter::once(start_vector(n)).chain(basis) {
+        let mut vector: Vec<f64> = start;
+        let mut eigenvalue: f64 = 0.0;
+
+        for _ in 0..max_iter {
+            let next: Vec<f64> = apply(&vector);
+            let next_eigenvalue: f64 = next.iter().map(|x| x * x).sum::<f64>().sqrt();
+
+            if next_eigenvalue == 0.0 {
+                continue 'starts;
+            }
+
+            // First estimate is compared with zero, so it never stops iterations
+            if (next_eigenvalue - eigenvalue).abs() <= tol * next_eigenvalue {
+                return Ok(next_eigenvalue);
+            }
+
+            eigenvalue = next_eigenvalue;
+            vector = next.iter().map(|x| x / next_eigenvalue).collect();
+        }
+
+        return Err(SolveError::NotConverged {
+            nb_iterations: max_iter,
+        });
+    }
+
+    return Ok(0.0);
+}
+
+/// Get unit start vector of size n for power iteration, with irregular components of both signs
+/// so it is not an eigenvector or a null vector of common matrices
+fn start_vector(n: usize) -> Vec<f64> {
+    let golden_ratio: f64 = 0.5 * (1.0 + 5.0_f64.sqrt());
+    let vector: Vec<f64> = (1..=n)
+        .map(|i| (i as f64 * golden_ratio).fract() - 0.5)
+        .collect();
+    let norm: f64 = vector.iter().map(|x| x * x).sum::<f64>().sqrt();
+
+    return vector.iter().map(|x| x / norm).collect();
+}
+
+/// Compute sign of permutation, 1 if it is a product of an even number of swaps and -1 otherwise
+fn permutation_sign(permutation: &[usize]) -> i32 {
+    let mut visited: Vec<bool> = vec![false; permutation.len()];
+    let mut sign: i32 = 1;
+
+    for start in 0..permutation.len() {
+        let mut index: usize = start;
+        let mut cycle_len: usize = 0;
+
+        while !visited[index] {
+            visited[index] = true;
+            index = permutation[index];
+            cycle_len += 1;
+        }
+
+        // A cycle of length l is a product of l - 1 swaps
+        if cycle_len > 0 && cycle_len.is_multiple_of(2) {
+            sign = -sign;
+        }
+    }
+
+    return sign;
+}
+
+impl<'a, T> Index<(usize, usize)> for View<'a, T> {
+    type Output = T;
+
+    /// This allows to read the view element at (index of row, index of column) position
+    /// like this let element: f32 = view[(0, 2)];
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        check_bounds(self.nb_rows, self.nb_cols, index);
+        let id: usize = self.accessor.index(index.0, index.1);
+        return self.data.index(id);
+    }
+}
+
+impl<'a, 'b, T> PartialEq<View<'b, T>> for View<'a, T>
+where
+    T: PartialEq,
+{
+    /// Views are equal when they have same dimensions and same elements
+    /// at each logical position, whatever the storage order of their data
+    fn eq(&self, other: &View<'b, T>) -> bool {
+        return self.nb_rows == other.nb_rows
+            && self.nb_cols == other.nb_cols
+            && self.iter().eq(other.iter());
+    }
+}
+
+/// Largest number of rows or columns printed in full by Display
+const DISPLAY_THRESHOLD: usize = 12;
+
+/// Number of first and last rows or columns printed by Display when the others are elided
+const DISPLAY_EDGE_ITEMS: usize = 4;
+
+/// Get indexes printed by Display among count rows or columns, None standing for elided ones
+fn displayed_indexes(count: usize) -> Vec<Option<usize>> {
+    if count <= DISPLAY_THRESHOLD {
+        return (0..count).map(Some).collect();
+    }
+
+    return (0..DISPLAY_EDGE_ITEMS)
+        .map(Some)
+        .chain(std::iter::once(None))
+        .chain(((count - DISPLAY_EDGE_ITEMS)..count).map(Some))
+        .collect();
+}
+
+impl<'a, T> fmt::Display for View<'a, T>
+where
+    T: fmt::Display,
+{
+    /// Print view row by row in logical order, one row per line,
+    /// where elements of each column are right-aligned on the widest one.
+    /// Precision is forwarded to elements and width is the minimum width of columns.
+    /// Views with more than DISPLAY_THRESHOLD rows or columns only show the first and last ones
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let row_ids: Vec<Option<usize>> = displayed_indexes(self.nb_rows);
+        let col_ids: Vec<Option<usize>> = displayed_indexes(self.nb_cols);
+
+        let cells: Vec<Vec<String>> = row_ids
+            .iter()
+            .map(|row_id| {
+                return col_ids
+                    .iter()
+                    .map(|col_id| match (row_id, col_id) {
+                        (Some(row_id), Some(col_id)) => match f.precision() {
+                            Some(precision) => {
+                                format!("{:.*}", precision, self[(*row_id, *col_id)])
+                            }
+                            None => self[(*row_id, *col_id)].to_string(),
+                        },
+                        _ => String::from("..."),
+                    })
+                    .collect();
+            })
+            .collect();
+
+        let widths: Vec<usize> = (0..col_ids.len())
+            .map(|col_id| {
+                return cells
+                    .iter()
+                    .map(|row| row[col_id].chars().count())
+                    .max()
+                    .unwrap_or(0)
+                    .max(f.width().unwrap_or(0));
+            })
+            .collect();
+
+        for (row_id, row) in cells.iter().enumerate() {
+            if row_id > 0 {
+                writeln!(f)?;
+            }
+
+            for (col_id, width) in widths.iter().enumerate() {
+                if col_id > 0 {
+                    write!(f, " ")?;
+                }
+
+                write!(f, "{:>width$}", row[col_id], width = width)?;
+            }
+        }
+
+        return Ok(());
+    }
+}
+
+impl<'a, T> fmt::Debug for View<'a, T>
+where
+    T: fmt::Debug,
+{
+    /// Print dimensions, strides and elements of view in logical order
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let elements: Vec<Vec<&T>> = (0..self.nb_rows)
+            .map(|row_id| {
+                return (0..self.nb_cols)
+                    .map(|col_id| &self[(row_id, col_id)])
+                    .collect();
+            })
+            .collect();
+
+        return f
+            .debug_struct("View")
+            .field("nb_rows", &self.nb_rows)
+            .field("nb_cols", &self.nb_cols)
+            .field(
+                "strides",
+                &(self.accessor.stride_row, self.accessor.stride_col),
+            )
+            .field("elements", &elements)
+            .finish();
+    }
+}
+
+/// View iterator
+/// This structure iterates over elements of a view in row-major logical order,
+/// memory location of each element is given by accessor, so only elements of view are visited
+pub struct ViewIter<'a, T> {
+    nb_rows: usize,
+    nb_cols: usize,
+    accessor: Accessor,
+    data: &'a [T],
+    position: usize,
+}
+
+impl<'a, T> Iterator for ViewIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.position >= self.nb_rows * self.nb_cols {
+            return None;
+        }
+
+        let row_id: usize = self.position / self.nb_cols;
+        let col_id: usize = self.position % self.nb_cols;
+
+        self.position += 1;
+        return Some(&self.data[self.accessor.index(row_id, col_id)]);
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining: usize = self.nb_rows * self.nb_cols - self.position;
+        return (remaining, Some(remaining));
+    }
+}
+
+impl<'a, T> ExactSizeIterator for ViewIter<'a, T> {}
+
+/// Row iterator
+/// This structure iterates over rows of a view, from the first one to the last one,
+/// yielding for each row a view with one row which shares data of the iterated view
+pub struct RowIter<'a, T> {
+    nb_rows: usize,
+    nb_cols: usize,
+    accessor: Accessor,
+    data: &'a [T],
+    row_id: usize,
+}
+
+impl<'a, T> Iterator for RowIter<'a, T> {
+    type Item = View<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.row_id >= self.nb_rows {
+            return None;
+        }
+
+        let row: View<'a, T> = View::new(
+            1,
+            self.nb_cols,
+            self.accessor.shift(self.row_id, 0),
+            self.data,
+        );
+
+        self.row_id += 1;
+        return Some(row);
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining: usize = self.nb_rows - self.row_id;
+        return (remaining, Some(remaining));
+    }
+}
+
+impl<'a, T> ExactSizeIterator for RowIter<'a, T> {}
+
+/// Column iterator
+/// This structure iterates over columns of a view, from both ends,
+/// yielding for each column a view with one column which shares data of the iterated view
+pub struct ColIter<'a, T> {
+    nb_rows: usize,
+    accessor: Accessor,
+    data: &'a [T],
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T> ColIter<'a, T> {
+    /// Get view on column at given index
+    fn col(&self, col_id: usize) -> View<'a, T> {
+        return View::new(self.nb_rows, 1, self.accessor.shift(0, col_id), self.data);
+    }
+}
+
+impl<'a, T> Iterator for ColIter<'a, T> {
+    type Item = View<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        let col: View<'a, T> = self.col(self.front);
+
+        self.front += 1;
+        return Some(col);
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining: usize = self.back - self.front;
+        return (remaining, Some(remaining));
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for ColIter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        return Some(self.col(self.back));
+    }
+}
+
+impl<'a, T> ExactSizeIterator for ColIter<'a, T> {}
+
+/// Mutable View
+/// This struture is a mutable view on part of matrix, so it does not own data.
+/// It contains number of rows and number of columns of view, an accessor
+/// to get memory position of elements in contiguous memory slice and a mutable slice on data owned by matrix
+pub struct ViewMut<'a, T> {
+    nb_rows: usize,
+    nb_cols: usize,
+    accessor: Accessor,
+    data: &'a mut [T],
+}
+
+impl<'a, T> ViewMut<'a, T> {
+    /// Create a mutable view from number of rows, number of columns, an accessor and a mutable slice
+    pub fn new(nb_rows: usize, nb_cols: usize, accessor: Accessor, data: &'a mut [T]) -> Self {
+        return Self {
+            nb_rows,
+            nb_cols,
+            accessor,
+            data,
+        };
+    }
+
+    /// Get number of rows of mutable view
+    pub fn nb_rows(&self) -> usize {
+        return self.nb_rows;
+    }
+
+    /// Get number of columns of mutable view
+    pub fn nb_cols(&self) -> usize {
+        return self.nb_cols;
+    }
+
+    /// Get layout classification of elements of mutable view in memory
+    pub fn layout(&self) -> Layout {
+        return Layout::classify(self.nb_rows, self.nb_cols, &self.accessor);
+    }
+
+    /// Get report on memory layout of mutable view
+    pub fn layout_report(&self) -> LayoutReport {
+        return LayoutReport::new::<T>(self.nb_rows, self.nb_cols, &self.accessor);
+    }
+
+    /// Get mutable slice on elements of contiguous view, whatever their storage order
+    /// Return None if view is not contiguous
+    pub(crate) fn contiguous_slice_mut(&mut self) -> Option<&mut [T]> {
+        let (start, _): (usize, StorageOrder) =
+            self.accessor.contiguous_start(self.nb_rows, self.nb_cols)?;
+
+        return Some(&mut self.data[start..start + self.nb_rows * self.nb_cols]);
+    }
+
+    /// Get element at (index of row, index of column) position
+    /// Return None if one of indexes is out of view dimensions
+    pub fn get(&self, row_id: usize, col_id: usize) -> Option<&T> {
+        if row_id >= self.nb_rows || col_id >= self.nb_cols {
+            return None;
+        }
+
+        return self.data.get(self.accessor.index(row_id, col_id));
+    }
+
+    /// Get mutable element at (index of row, index of column) position
+    /// Return None if one of indexes is out of view dimensions
+    pub fn get_mut(&mut self, row_id: usize, col_id: usize) -> Option<&mut T> {
+        if row_id >= self.nb_rows || col_id >= self.nb_cols {
+            return None;
+        }
+
+        return self.data.get_mut(self.accessor.index(row_id, col_id));
+    }
+
+    /// Exchange rows at indexes a and b of view element by element
+    /// It panics if one of indexes is out of view
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        for row_id in [a, b] {
+            assert!(
+                row_id < self.nb_rows,
+                "row index {} is out of bounds of view with {} rows",
+                row_id,
+                self.nb_rows
+            );
+        }
+
+        if a == b {
+            return;
+        }
+
+        for col_id in 0..self.nb_cols {
+            self.data.swap(
+                self.accessor.index(a, col_id),
+                self.accessor.index(b, col_id),
+            );
+        }
+    }
+
+    /// Exchange columns at indexes a and b of view element by element
+    /// It panics if one of indexes is out of view
+    pub fn swap_cols(&mut self, a: usize, b: usize) {
+        for col_id in [a, b] {
+            assert!(
+                col_id < self.nb_cols,
+                "column index {} is out of bounds of view with {} columns",
+                col_id,
+                self.nb_cols
+            );
+        }
+
+        if a == b {
+            return;
+        }
+
+        for row_id in 0..self.nb_rows {
+            self.data.swap(
+                self.accessor.index(row_id, a),
+                self.accessor.index(row_id, b),
+            );
+        }
+    }
+
+    /// Set all elements of view to given value
+    /// Only elements inside view are modified, not the whole underlying slice
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.apply(|element| *element = value.clone());
+    }
+
+    /// Set elements of view where mask is true to given value
+    /// Mask must have the same dimensions as view
+    pub fn fill_where(&mut self, mask: &View<bool>, value: T) -> Result<(), ShapeError>
+    where
+        T: Clone,
+    {
+        check_dimensions(
+            (self.nb_rows, self.nb_cols),
+            (mask.nb_rows(), mask.nb_cols()),
+        )?;
+
+        for row_id in 0..self.nb_rows {
+            for col_id in 0..self.nb_cols {
+                if mask[(row_id, col_id)] {
+                    self[(row_id, col_id)] = value.clone();
+                }
+            }
+        }
+
+        return Ok(());
+    }
+
+    /// Apply f on each element of view
+    /// Only elements inside view are visited, not the whole underlying slice
+    pub fn apply<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        for row_id in 0..self.nb_rows {
+            for col_id in 0..self.nb_cols {
+                f(&mut self.data[self.accessor.index(row_id, col_id)]);
+            }
+        }
+    }
+
+    /// Apply f on each element of view where predicate holds
+    pub fn apply_if<P, F>(&mut self, pred: P, mut f: F)
+    where
+        P: Fn(&T) -> bool,
+        F: FnMut(&mut T),
+    {
+        self.apply(|element| {
+            if pred(element) {
+                f(element);
+            }
+        });
+    }
+}
+
+impl<'a, T> ViewMut<'a, T>
+where
+    T: Copy,
+{
+    /// Add row vector, a view with one row and as many columns as view, to each row of view
+    pub fn add_row_vector(&mut self, vector: &View<T>) -> Result<(), ShapeError>
+    where
+        T: Add<Output = T>,
+    {
+        return self.broadcast_row(vector, |element, value| *element = *element + value);
+    }
+
+    /// Subtract row vector, a view with one row and as many columns as view, from each row of view
+    pub fn sub_row_vector(&mut self, vector: &View<T>) -> Result<(), ShapeError>
+    where
+        T: Sub<Output = T>,
+    {
+        return self.broadcast_row(vector, |element, value| *element = *element - value);
+    }
+
+    /// Add column vector, a view with one column and as many rows as view, to each column of view
+    pub fn add_col_vector(&mut self, vector: &View<T>) -> Result<(), ShapeError>
+    where
+        T: Add<Output = T>,
+    {
+        return self.broadcast_col(vector, |element, value| *element = *element + value);
+    }
+
+    /// Multiply each row of view by its factor given in column vector,
+    /// a view with one column and as many rows as view
+    pub fn mul_col_vector(&mut self, vector: &View<T>) -> Result<(), ShapeError>
+    where
+        T: Mul<Output = T>,
+    {
+        return self.broadcast_col(vector, |element, value| *element = *element * value);
+    }
+
+    /// Add element-wise product of a and b to view, in one pass without temporary matrix
+    pub fn add_hadamard(&mut self, a: &View<T>, b: &View<T>) -> Result<(), ShapeError>
+    where
+        T: Add<Output = T> + Mul<Output = T>,
+    {
+        self.check_operand(a)?;
+        self.check_operand(b)?;
+
+        self.apply_indexed(|row_id, col_id, element| {
+            *element = *element + a[(row_id, col_id)] * b[(row_id, col_id)];
+        });
+
+        return Ok(());
+    }
+
+    /// Add a multiplied by alpha to view, in one pass without temporary matrix
+    pub fn add_scaled(&mut self, alpha: T, a: &View<T>) -> Result<(), ShapeError>
+    where
+        T: Add<Output = T> + Mul<Output = T>,
+    {
+        self.check_operand(a)?;
+
+        self.apply_indexed(|row_id, col_id, element| {
+            *element = *element + alpha * a[(row_id, col_id)];
+        });
+
+        return Ok(());
+    }
+
+    /// Add element-wise quotient of a by b multiplied by alpha to view,
+    /// in one pass without temporary matrix
+    pub fn addcdiv(&mut self, a: &View<T>, b: &View<T>, alpha: T) -> Result<(), ShapeError>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Div<Output = T>,
+    {
+        self.check_operand(a)?;
+        self.check_operand(b)?;
+
+        self.apply_indexed(|row_id, col_id, element| {
+            *element = *element + alpha * (a[(row_id, col_id)] / b[(row_id, col_id)]);
+        });
+
+        return Ok(());
+    }
+
+    /// Check that operand has same dimensions as view
+    fn check_operand(&self, operand: &View<T>) -> Result<(), ShapeError> {
+        return check_dimensions(
+            (self.nb_rows, self.nb_cols),
+            (operand.nb_rows, operand.nb_cols),
+        );
+    }
+
+    /// Apply f on each element of view with element of row vector at same column
+    fn broadcast_row<F>(&mut self, vector: &View<T>, mut f: F) -> Result<(), ShapeError>
+    where
+        F: FnMut(&mut T, T),
+    {
+        check_dimensions((1, self.nb_cols), (vector.nb_rows, vector.nb_cols))?;
+        self.apply_indexed(|_, col_id, element| f(element, vector[(0, col_id)]));
+
+        return Ok(());
+    }
+
+    /// Apply f on each element of view with element of column vector at same row
+    fn broadcast_col<F>(&mut self, vector: &View<T>, mut f: F) -> Result<(), ShapeError>
+    where
+        F: FnMut(&mut T, T),
+    {
+        check_dimensions((self.nb_rows, 1), (vector.nb_rows, vector.nb_cols))?;
+        self.apply_indexed(|row_id, _, element| f(element, vector[(row_id, 0)]));
+
+        return Ok(());
+    }
+
+    /// Apply f on each element of view with its row and column indexes
+    /// Elements are visited in storage order of view, so memory is accessed contiguously
+    fn apply_indexed<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize, usize, &mut T),
+    {
+        if self.accessor.stride_col <= self.accessor.stride_row {
+            for row_id in 0..self.nb_rows {
+                for col_id in 0..self.nb_cols {
+                    f(
+                        row_id,
+                        col_id,
+                        &mut self.data[self.accessor.index(row_id, col_id)],
+                    );
+                }
+            }
+        } else {
+            for col_id in 0..self.nb_cols {
+                for row_id in 0..self.nb_rows {
+                    f(
+                        row_id,
+                        col_id,
+                        &mut self.data[self.accessor.index(row_id, col_id)],
+                    );
+                }
+            }
+        }
+    }
+}
+
+impl<'a, T> ViewMut<'a, T>
+where
+    T: Copy + Mul<Output = T>,
+{
+    /// Multiply each element of view by given factor, same as view *= factor
+    /// Only elements inside view are modified, not the whole underlying slice
+    pub fn scale(&mut self, factor: T) {
+        *self *= factor;
+    }
+}
+
+impl<'a, T> ViewMut<'a, T>
+where
+    T: Float,
+{
+    /// Scale view in place so its Frobenius norm does not exceed max_norm, as done
+    /// in gradient clipping. View with a norm lower than or equal to max_norm is unchanged
+    pub fn clip_to_norm(&mut self, max_norm: f64) {
+        let mut squared: T = T::zero();
+
+        for row_id in 0..self.nb_rows {
+            for col_id in 0..self.nb_cols {
+                squared = squared + self[(row_id, col_id)] * self[(row_id, col_id)];
+            }
+        }
+
+        let norm: T = squared.sqrt();
+        let max_norm: T = T::from_f64(max_norm);
+
+        if norm > max_norm {
+            let factor: T = max_norm / norm;
+            self.apply(|element| *element = *element * factor);
+        }
+    }
+}
+
+impl<'a, T> ViewMut<'a, T>
+where
+    T: PartialOrd,
+{
+    /// Sort elements of each row independently in ascending order
+    /// The sort is stable and values not comparable with themselves, like NaN, are put at the end
+    pub fn sort_each_row(&mut self) {
+        for row_id in 0..self.nb_rows {
+            let mut permutation: Vec<usize> = (0..self.nb_cols).collect();
+
+            permutation.sort_by(|&a, &b| {
+                return compare_nan_last(
+                    &self.data[self.accessor.index(row_id, a)],
+                    &self.data[self.accessor.index(row_id, b)],
+                );
+            });
+
+            let accessor: Accessor = self.accessor;
+            let data: &mut [T] = self.data;
+
+            apply_permutation(&permutation, |a, b| {
+                data.swap(accessor.index(row_id, a), accessor.index(row_id, b));
+            });
+        }
+    }
+}
+
+impl<'a, T> Index<(usize, usize)> for ViewMut<'a, T> {
+    type Output = T;
+
+    /// This allows to read the view element at (index of row, index of column) position
+    /// like this let element: f32 = view[(0, 2)];
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        check_bounds(self.nb_rows, self.nb_cols, index);
+        let id: usize = self.accessor.index(index.0, index.1);
+        return self.data.index(id);
+    }
+}
+
+impl<'a, T> IndexMut<(usize, usize)> for ViewMut<'a, T> {
+    /// This allows to write an value in matrix at (index of row, index of column) position
+    /// like this matrix[(0, 2)] = 3.1415;
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        check_bounds(self.nb_rows, self.nb_cols, index);
+        let id: usize = self.accessor.index(index.0, index.1);
+        return self.data.index_mut(id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing;
+
+    #[test]
+    fn test_accessor_new() {
+        let stride_row: usize = 2;
+        let stride_col: usize = 3;
+
+        let accessor = Accessor::new(stride_row, stride_col);
+        assert_eq!(accessor.stride_row, stride_row);
+        assert_eq!(accessor.stride_col, stride_col);
+        assert_eq!(accessor.offset, 0);
+    }
+
+    #[test]
+    fn test_accessor_new_with_offset() {
+        let stride_row: usize = 2;
+        let stride_col: usize = 3;
+        let offset_row: usize = 1;
+        let offset_col: usize = 1;
+
+        let accessor = Accessor::new_with_offset(stride_row, stride_col, offset_row, offset_col);
+        assert_eq!(accessor.stride_row, stride_row);
+        assert_eq!(accessor.stride_col, stride_col);
+
+        let offset_ref: usize = stride_row * offset_row + stride_col * offset_col;
+        assert_eq!(accessor.offset, offset_ref);
+    }
+
+    #[test]
+    fn test_accessor_index() {
+        let stride_row: usize = 3;
+        let stride_col: usize = 3;
+
+        let mut accessor = Accessor::new(stride_row, 1);
+        assert_eq!(accessor.index(1, 2), stride_row + 2);
+
+        accessor = Accessor::new(1, stride_col);
+        assert_eq!(accessor.index(2, 1), 2 + stride_col);
+    }
+
+    #[test]
+    fn test_accessor_index_with_offset() {
+        let stride_row: usize = 4;
+        let stride_col: usize = 4;
+        let offset_row: usize = 1;
+        let offset_col: usize = 1;
+
+        let mut accessor = Accessor::new_with_offset(stride_row, 1, offset_row, offset_col);
+        assert_eq!(accessor.index(1, 2), stride_row + 7);
+
+        accessor = Accessor::new_with_offset(1, stride_col, offset_row, offset_col);
+        assert_eq!(accessor.index(2, 1), 7 + stride_col);
+    }
+
+    #[test]
+    fn test_accessor_shift() {
+        let accessor = Accessor::new_with_offset(4, 1, 1, 1);
+        let shifted = accessor.shift(1, 2);
+
+        assert_eq!(shifted.stride_row, 4);
+        assert_eq!(shifted.stride_col, 1);
+        assert_eq!(shifted.index(0, 0), accessor.index(1, 2));
+        assert_eq!(shifted.index(1, 1), accessor.index(2, 3));
+    }
+
+    #[test]
+    fn test_accessor_is_injective_and_contains() {
+        let accessor = Accessor::new_with_offset(4, 1, 1, 1);
+        assert!(accessor.is_injective(2, 3));
+        assert!(!accessor.is_injective(2, 5));
+
+        let members: Vec<usize> = (0..12)
+            .filter(|&index| accessor.contains(2, 3, index))
+            .collect();
+        assert_eq!(members, vec![5, 6, 7, 9, 10, 11]);
+
+        // Stride of an axis with one element is never used
+        assert!(Accessor::new(0, 1).is_injective(1, 3));
+        assert!(!Accessor::new(0, 1).is_injective(2, 3));
+        assert!(Accessor::new(1, 2).is_injective(2, 3));
+        assert!(!Accessor::new(2, 1).is_injective(3, 3));
+
+        // Memory locations wrapping around usize are not distinct
+        assert!(!Accessor::new(1 << (usize::BITS - 1), 1).is_injective(3, 1));
+        assert_eq!(
+            Accessor::new(1 << (usize::BITS - 1), 1).checked_index(2, 0),
+            None
+        );
+    }
+
+    #[test]
+    fn test_view_new() {
+        let nb_rows: usize = 3;
+        let nb_cols: usize = 3;
+        let data: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+        let view: View<i32> =
+            View::new(nb_rows, nb_cols, Accessor::new(nb_cols, 1), data.as_slice());
+
+        assert_eq!(view.nb_rows, nb_rows);
+        assert_eq!(view.nb_cols, nb_cols);
+
+        match view.data.partial_cmp(data.as_slice()) {
+            Some(result) => assert_eq!(result, Ordering::Equal),
+            None => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_view_dimensions_access() {
+        let nb_rows: usize = 3;
+        let nb_cols: usize = 3;
+        let data: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+        let view: View<i32> =
+            View::new(nb_rows, nb_cols, Accessor::new(nb_cols, 1), data.as_slice());
+
+        assert_eq!(view.nb_rows(), nb_rows);
+        assert_eq!(view.nb_cols(), nb_cols);
+    }
+
+    #[test]
+    fn test_view_data_access() {
+        let nb_rows: usize = 3;
+        let nb_cols: usize = 3;
+        let data: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+        let view: View<i32> =
+            View::new(nb_rows, nb_cols, Accessor::new(1, nb_rows), data.as_slice());
+
+        assert_eq!(view[(0, 0)], data[0]);
+        assert_eq!(view[(1, 0)], data[1]);
+        assert_eq!(view[(2, 0)], data[2]);
+        assert_eq!(view[(0, 1)], data[3]);
+        assert_eq!(view[(1, 1)], data[4]);
+        assert_eq!(view[(2, 1)], data[5]);
+        assert_eq!(view[(0, 2)], data[6]);
+        assert_eq!(view[(1, 2)], data[7]);
+        assert_eq!(view[(2, 2)], data[8]);
+    }
+
+    #[test]
+    fn test_view_data_access_with_offset() {
+        let nb_rows: usize = 3;
+        let nb_cols: usize = 3;
+        let data: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+        let view: View<i32> = View::new(
+            nb_rows - 1,
+            nb_cols - 1,
+            Accessor::new_with_offset(1, nb_rows, 1, 1),
+            data.as_slice(),
+        );
+
+        assert_eq!(view[(0, 0)], data[4]);
+        assert_eq!(view[(1, 0)], data[5]);
+        assert_eq!(view[(0, 1)], data[7]);
+        assert_eq!(view[(1, 1)], data[8]);
+    }
+
+    #[test]
+    fn test_view_checked_data_access() {
+        let nb_rows: usize = 3;
+        let nb_cols: usize = 3;
+        let data: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+        let view: View<i32> = View::new(
+            nb_rows - 1,
+            nb_cols - 1,
+            Accessor::new_with_offset(nb_cols, 1, 0, 0),
+            data.as_slice(),
+        );
+
+        assert_eq!(view.get(0, 0), Some(&data[0]));
+        assert_eq!(view.get(1, 1), Some(&data[4]));
+
+        // (0, 2) is mapped on an existing element of data but it is out of view
+        assert_eq!(view.get(0, 2), None);
+        assert_eq!(view.get(2, 0), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "index (0, 2) is out of bounds of view with dimensions 2x2")]
+    fn test_view_data_access_out_of_bounds() {
+        let data: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let view: View<i32> = View::new(2, 2, Accessor::new(3, 1), data.as_slice());
+
+        let _ = view[(0, 2)];
+    }
+
+    #[test]
+    fn test_view_display() {
+        let data: Vec<i32> = vec![1, -20, 3, 400, 5, 6];
+
+        let view: View<i32> = View::new(2, 3, Accessor::new(1, 2), data.as_slice());
+        assert_eq!(view.to_string(), "  1   3 5\n-20 400 6");
+
+        let view: View<i32> =
+            View::new(2, 2, Accessor::new_with_offset(3, 1, 0, 1), data.as_slice());
+        assert_eq!(view.to_string(), "-20 3\n  5 6");
+    }
+
+    #[test]
+    fn test_view_display_format_specifiers() {
+        let data: Vec<f64> = vec![1.0, -2.5, 0.125, 10.0];
+        let view: View<f64> = View::new(2, 2, Accessor::new(2, 1), data.as_slice());
+
+        assert_eq!(format!("{:.2}", view), "1.00 -2.50\n0.12 10.00");
+        assert_eq!(format!("{:5}", view), "    1  -2.5\n0.125    10");
+        assert_eq!(format!("{:6.1}", view), "   1.0   -2.5\n   0.1   10.0");
+    }
+
+    #[test]
+    fn test_view_display_elided() {
+        let data: Vec<usize> = (0..300).collect();
+
+        let view: View<usize> = View::new(20, 15, Accessor::new(15, 1), data.as_slice());
+        let text: String = view.to_string();
+        let lines: Vec<&str> = text.lines().collect();
+
+        assert_eq!(lines.len(), 2 * DISPLAY_EDGE_ITEMS + 1);
+        assert_eq!(lines[0], "  0   1   2   3 ...  11  12  13  14");
+        assert_eq!(lines[4], "... ... ... ... ... ... ... ... ...");
+        assert_eq!(lines[8], "285 286 287 288 ... 296 297 298 299");
+
+        let view: View<usize> = View::new(12, 2, Accessor::new(2, 1), data.as_slice());
+        assert_eq!(view.to_string().lines().count(), 12);
+    }
+
+    #[test]
+    fn test_view_debug() {
+        let data: Vec<i32> = vec![1, 2, 3, 4, 5, 6];
+        let view: View<i32> =
+            View::new(2, 2, Accessor::new_with_offset(1, 2, 0, 1), data.as_slice());
+
+        assert_eq!(
+            format!("{:?}", view),
+            "View { nb_rows: 2, nb_cols: 2, strides: (1, 2), elements: [[3, 5], [4, 6]] }"
+        );
+    }
+
+    #[test]
+    fn test_view_partial_eq() {
+        let row_major: Vec<i32> = vec![1, 2, 3, 4, 5, 6];
+        let column_major: Vec<i32> = vec![1, 4, 2, 5, 3, 6];
+
+        let view: View<i32> = View::new(2, 3, Accessor::new(3, 1), row_major.as_slice());
+        let other: View<i32> = View::new(2, 3, Accessor::new(1, 2), column_major.as_slice());
+        assert!(view == other);
+
+        let transposed: View<i32> = View::new(3, 2, Accessor::new(2, 1), row_major.as_slice());
+        assert!(view != transposed);
+
+        let shifted: View<i32> = View::new(2, 3, Accessor::new(1, 2), &column_major[1..]);
+        assert!(view != shifted);
+    }
+
+    #[test]
+    fn test_view_block_reduce() {
+        let data: Vec<f64> = (1..=16).map(|x| x as f64).collect();
+        let view: View<f64> = View::new(4, 4, Accessor::new(4, 1), data.as_slice());
+
+        let mean = |block: &View<f64>| {
+            return block.iter().sum::<f64>() / ((block.nb_rows() * block.nb_cols()) as f64);
+        };
+
+        let pooled: Matrix<f64> = view.block_reduce(2, 2, mean);
+        let expected: Matrix<f64> = Matrix::from_rows(&[&[3.5, 5.5], &[11.5, 13.5]]).unwrap();
+        assert_eq!(pooled, expected);
+
+        // Edge blocks are truncated
+        let view: View<f64> = View::new(3, 4, Accessor::new(4, 1), data.as_slice());
+        let pooled: Matrix<f64> = view.block_reduce(2, 3, |block| block.iter().sum());
+        let expected: Matrix<f64> = Matrix::from_rows(&[
+            &[1.0 + 2.0 + 3.0 + 5.0 + 6.0 + 7.0, 4.0 + 8.0],
+            &[9.0 + 10.0 + 11.0, 12.0],
+        ])
+        .unwrap();
+        assert_eq!(pooled, expected);
+    }
+
+    #[test]
+    fn test_view_select() {
+        let data: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let view: View<i32> = View::new(3, 3, Accessor::new(3, 1), data.as_slice());
+
+        let selection: Matrix<i32> = view.select(&[2, 0], &[1, 1, 0]).unwrap();
+
+        assert_eq!(selection.nb_rows(), 2);
+        assert_eq!(selection.nb_cols(), 3);
+        assert_eq!(
+            selection
+                .rows()
+                .map(|row| row.iter().copied().collect())
+                .collect::<Vec<Vec<i32>>>(),
+            vec![vec![8, 8, 7], vec![2, 2, 1]]
+        );
+
+        assert_eq!(
+            view.select(&[0, 3], &[0]).err(),
+            Some(ShapeError::RowOutOfBounds {
+                row_id: 3,
+                nb_rows: 3
+            })
+        );
+
+        assert_eq!(
+            view.select(&[0], &[5]).err(),
+            Some(ShapeError::ColumnOutOfBounds {
+                col_id: 5,
+                nb_cols: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_view_to_vec_in_order() {
+        let data: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+
+        // 2x3 view with offset on column-major 3x4 matrix
+        let view: View<i32> =
+            View::new(2, 3, Accessor::new_with_offset(1, 3, 1, 1), data.as_slice());
+
+        assert_eq!(
+            view.to_vec_in_order(StorageOrder::RowMajor),
+            vec![5, 8, 11, 6, 9, 12]
+        );
+        assert_eq!(
+            view.to_vec_in_order(StorageOrder::ColumnMajor),
+            vec![5, 6, 8, 9, 11, 12]
+        );
+    }
+
+    #[test]
+    fn test_view_iter() {
+        let data: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+
+        // 2x2 view with offset on column-major 4x4 matrix
+        let view: View<i32> =
+            View::new(2, 2, Accessor::new_with_offset(1, 4, 1, 1), data.as_slice());
+
+        let mut iter: ViewIter<i32> = view.iter();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next(), Some(&6));
+        assert_eq!(iter.len(), 3);
+
+        let elements: Vec<i32> = view.iter().copied().collect();
+        assert_eq!(elements, vec![6, 10, 7, 11]);
+        assert_eq!(view.iter().copied().sum::<i32>(), 34);
+    }
+
+    #[test]
+    fn test_view_rows() {
+        let data: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+
+        // 3x2 view with offset on column-major 4x3 matrix
+        let view: View<i32> =
+            View::new(3, 2, Accessor::new_with_offset(1, 4, 1, 1), data.as_slice());
+
+        let mut rows: RowIter<i32> = view.rows();
+        assert_eq!(rows.len(), 3);
+
+        let first: View<i32> = rows.next().unwrap();
+        assert_eq!((first.nb_rows(), first.nb_cols()), (1, 2));
+        assert_eq!((first[(0, 0)], first[(0, 1)]), (6, 10));
+        assert_eq!(rows.size_hint(), (2, Some(2)));
+
+        let remaining: Vec<(i32, i32)> = rows.map(|row| (row[(0, 0)], row[(0, 1)])).collect();
+        assert_eq!(remaining, vec![(7, 11), (8, 12)]);
+    }
+
+    #[test]
+    fn test_view_cols() {
+        let data: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+
+        // 2x3 view with offset on row-major 3x4 matrix
+        let view: View<i32> =
+            View::new(2, 3, Accessor::new_with_offset(4, 1, 1, 1), data.as_slice());
+
+        let mut cols: ColIter<i32> = view.cols();
+        assert_eq!(cols.len(), 3);
+
+        let last: View<i32> = cols.next_back().unwrap();
+        assert_eq!((last.nb_rows(), last.nb_cols()), (2, 1));
+        assert_eq!((last[(0, 0)], last[(1, 0)]), (8, 12));
+
+        let first: View<i32> = cols.next().unwrap();
+        assert_eq!((first[(0, 0)], first[(1, 0)]), (6, 10));
+
+        assert_eq!(cols.size_hint(), (1, Some(1)));
+
+        let middle: View<i32> = cols.next_back().unwrap();
+        assert_eq!((middle[(0, 0)], middle[(1, 0)]), (7, 11));
+
+        assert!(cols.next().is_none());
+        assert!(cols.next_back().is_none());
+    }
+
+    #[test]
+    fn test_view_bandwidth() {
+        let tridiagonal: Vec<f64> = vec![
+            2.0, -1.0, 0.0, 0.0, -1.0, 2.0, -1.0, 0.0, 0.0, -1.0, 2.0, -1.0, 0.0, 0.0, -1.0, 2.0,
+        ];
+        let view: View<f64> = View::new(4, 4, Accessor::new(4, 1), tridiagonal.as_slice());
+        assert_eq!(view.bandwidth(|&x| x == 0.0), (1, 1));
+
+        let dense: Vec<f64> = (1..=16).map(|x| x as f64).collect();
+        let view: View<f64> = View::new(4, 4, Accessor::new(1, 4), dense.as_slice());
+        assert_eq!(view.bandwidth(|&x| x == 0.0), (3, 3));
+
+        // Upper triangular with only the first superdiagonal
+        let upper: Vec<f64> = vec![1.0, 2.0, 0.0, 0.0, 3.0, 4.0, 0.0, 0.0, 5.0];
+        let view: View<f64> = View::new(3, 3, Accessor::new(3, 1), upper.as_slice());
+        assert_eq!(view.bandwidth(|&x| x == 0.0), (0, 1));
+    }
+
+    #[test]
+    fn test_view_to_adjacency_list() {
+        // Directed graph with edges 0 -> 1, 0 -> 2, 1 -> 2, 2 -> 0 and 3 -> 3
+        let data: Vec<u8> = vec![0, 1, 1, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 1];
+        let view: View<u8> = View::new(4, 4, Accessor::new(4, 1), data.as_slice());
+
+        let adjacency: Vec<Vec<usize>> = view.to_adjacency_list(|&x| x != 0).unwrap();
+
+        assert_eq!(adjacency, vec![vec![1, 2], vec![2], vec![0], vec![3]]);
+
+        let view: View<u8> = View::new(2, 4, Accessor::new(4, 1), data.as_slice());
+
+        assert_eq!(
+            view.to_adjacency_list(|&x| x != 0),
+            Err(ShapeError::NotSquare {
+                nb_rows: 2,
+                nb_cols: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_view_trace() {
+        let data: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+        let view: View<i32> = View::new(3, 3, Accessor::new(3, 1), data.as_slice());
+        assert_eq!(view.trace(), Ok(15));
+
+        let view: View<i32> = View::new(3, 3, Accessor::new(1, 3), data.as_slice());
+        assert_eq!(view.trace(), Ok(15));
+
+        // Square sub-view on last two rows and columns
+        let view: View<i32> =
+            View::new(2, 2, Accessor::new_with_offset(3, 1, 1, 1), data.as_slice());
+        assert_eq!(view.trace(), Ok(14));
+
+        let view: View<i32> = View::new(2, 3, Accessor::new(3, 1), data.as_slice());
+        assert_eq!(
+            view.trace(),
+            Err(ShapeError::NotSquare {
+                nb_rows: 2,
+                nb_cols: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_view_row_gram() {
+        let data: Vec<i32> = vec![1, 2, 3, 4, 5, 6];
+        let view: View<i32> = View::new(3, 2, Accessor::new(2, 1), data.as_slice());
+
+        let gram: Matrix<i32> = view.row_gram();
+        let expected: Matrix<i32> = crate::arithmetic::matmul(&view, &view.transpose()).unwrap();
+
+        assert_eq!(gram, expected);
+        assert_eq!(gram.full_view(), gram.transpose_view());
+        assert_eq!(gram[(2, 0)], 17);
+    }
+
+    #[test]
+    fn test_view_row_jaccard_matrix() {
+        let data: Vec<u8> = vec![1, 1, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1, 1, 0, 0];
+        let view: View<u8> = View::new(4, 4, Accessor::new(4, 1), data.as_slice());
+
+        let similarity: Matrix<f64> = view.row_jaccard_matrix();
+
+        assert_eq!(similarity.nb_rows(), 4);
+        assert_eq!(similarity.nb_cols(), 4);
+        assert_eq!(similarity[(0, 1)], 1.0 / 3.0);
+        assert_eq!(similarity[(1, 0)], 1.0 / 3.0);
+        assert_eq!(similarity[(0, 3)], 1.0);
+        assert_eq!(similarity[(0, 2)], 0.0);
+        assert_eq!(similarity[(2, 2)], 1.0);
+
+        for row_id in 0..4 {
+            assert_eq!(similarity[(row_id, row_id)], 1.0);
+        }
+    }
+
+    #[test]
+    fn test_view_argsort_rows() {
+        let data: Vec<f64> = vec![3.0, 1.0, 2.0, 1.0, f64::NAN, 0.5, 2.0, 0.5];
+        let view: View<f64> = View::new(2, 4, Accessor::new(4, 1), data.as_slice());
+
+        let permutations: Matrix<usize> = view.argsort_rows();
+
+        assert_eq!(permutations.nb_rows(), 2);
+        assert_eq!(permutations.nb_cols(), 4);
+
+        let expected: Vec<usize> = vec![1, 3, 2, 0, 1, 3, 2, 0];
+
+        for i in 0..2 {
+            for j in 0..4 {
+                assert_eq!(permutations[(i, j)], expected[4 * i + j]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_view_argmax_and_argmin_rows() {
+        let nan: f64 = f64::NAN;
+        let data: Vec<f64> = vec![
+            0.1, 2.0, -1.0, 2.0, 5.0, 4.0, 3.0, 2.0, nan, -3.0, 7.0, -3.0,
+        ];
+        let view: View<f64> = View::new(3, 4, Accessor::new(4, 1), data.as_slice());
+
+        assert_eq!(view.argmax_rows(), vec![1, 0, 2]);
+        assert_eq!(view.argmin_rows(), vec![2, 3, 1]);
+
+        let column_major: Matrix<f64> = Matrix::from_fn_column_major(3, 4, |i, j| view[(i, j)]);
+        assert_eq!(column_major.full_view().argmax_rows(), vec![1, 0, 2]);
+
+        let nans: Vec<f64> = vec![f64::NAN, f64::NAN];
+        let view: View<f64> = View::new(1, 2, Accessor::new(2, 1), nans.as_slice());
+        assert_eq!(view.argmax_rows(), vec![0]);
+        assert_eq!(view.argmin_rows(), vec![0]);
+
+        let empty: View<f64> = View::new(0, 0, Accessor::new(0, 1), nans.as_slice());
+        assert_eq!(empty.argmax_rows(), Vec::<usize>::new());
+        assert_eq!(empty.argmin_rows(), Vec::<usize>::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one column")]
+    fn test_view_argmax_rows_without_column() {
+        let data: Vec<f64> = Vec::new();
+        let view: View<f64> = View::new(2, 0, Accessor::new(0, 1), data.as_slice());
+
+        view.argmax_rows();
+    }
+
+    #[test]
+    fn test_view_approx_eq() {
+        let data: Vec<f64> = vec![1.0, 1e6, -2.0, 0.0];
+        let view: View<f64> = View::new(2, 2, Accessor::new(2, 1), data.as_slice());
+
+        let close: Vec<f64> = vec![1.0 + 1e-13, 1e6 + 1e-4, -2.0, 1e-13];
+        let other: View<f64> = View::new(2, 2, Accessor::new(2, 1), close.as_slice());
+
+        assert!(view.approx_eq(&other, 1e-12, 1e-9));
+        assert!(!view.approx_eq(&other, 1e-12, 0.0));
+        assert!(!view.approx_eq(&other, 0.0, 1e-9));
+
+        let transposed: View<f64> = view.transpose();
+        assert!(!view.approx_eq(&transposed, 1.0, 1.0));
+
+        let row: View<f64> = View::new(1, 4, Accessor::new(4, 1), data.as_slice());
+        assert!(!view.approx_eq(&row, 1.0, 1.0));
+
+        let nan: Vec<f64> = vec![f64::NAN];
+        let view: View<f64> = View::new(1, 1, Accessor::new(1, 1), nan.as_slice());
+        assert!(!view.approx_eq(&view, 1.0, 1.0));
+        let view: View<f32> = View::new(1, 1, Accessor::new(1, 1), &[f32::INFINITY]);
+        assert!(view.approx_eq(&view, 0.0, 0.0));
+
+        let v_inf: View<f64> = View::new(1, 1, Accessor::new(1, 1), &[f64::INFINITY]);
+        let v_one: View<f64> = View::new(1, 1, Accessor::new(1, 1), &[1.0]);
+        let v_neg_inf: View<f64> = View::new(1, 1, Accessor::new(1, 1), &[f64::NEG_INFINITY]);
+        assert!(!v_inf.approx_eq(&v_one, 1e-12, 1e-9));
+        assert!(!v_one.approx_eq(&v_inf, 1e-12, 1e-9));
+        assert!(!v_inf.approx_eq(&v_neg_inf, 1e-12, 1e-9));
+    }
+
+    #[test]
+    fn test_view_threshold() {
+        let data: Vec<f64> = vec![0.1, 0.5, 0.9, 0.7, 0.4, 0.5, f64::NAN, 1.0, 0.0];
+        let view: View<f64> = View::new(3, 3, Accessor::new(1, 3), data.as_slice());
+
+        let expected: Matrix<f64> =
+            Matrix::from_rows(&[&[0.0, 1.0, 0.0], &[1.0, 0.0, 1.0], &[1.0, 1.0, 0.0]]).unwrap();
+
+        assert_eq!(view.threshold(0.5), expected);
+    }
+
+    #[test]
+    fn test_view_top_k_per_row() {
+        let data: Vec<f64> = vec![
+            0.1,
+            0.9,
+            0.3,
+            0.9,
+            0.5,
+            0.0, //
+            f64::NAN,
+            1.0,
+            -2.0,
+            f64::NAN,
+            3.0,
+            2.0,
+        ];
+        let view: View<f64> = View::new(2, 6, Accessor::new(6, 1), data.as_slice());
+
+        let (values, indexes): (Matrix<f64>, Matrix<usize>) = view.top_k_per_row(3);
+
+        assert_eq!(values.nb_rows(), 2);
+        assert_eq!(values.nb_cols(), 3);
+
+        // Ties are ordered by column index
+        assert_eq!(
+            (indexes[(0, 0)], indexes[(0, 1)], indexes[(0, 2)]),
+            (1, 3, 4)
+        );
+        assert_eq!(
+            (values[(0, 0)], values[(0, 1)], values[(0, 2)]),
+            (0.9, 0.9, 0.5)
+        );
+
+        // NaN are never preferred to numbers
+        assert_eq!(
+            (indexes[(1, 0)], indexes[(1, 1)], indexes[(1, 2)]),
+            (4, 5, 1)
+        );
+        assert_eq!(
+            (values[(1, 0)], values[(1, 1)], values[(1, 2)]),
+            (3.0, 2.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn test_view_top_k_per_row_clamped() {
+        let data: Vec<i32> = vec![2, 7, 5];
+        let view: View<i32> = View::new(1, 3, Accessor::new(3, 1), data.as_slice());
+
+        let (values, indexes): (Matrix<i32>, Matrix<usize>) = view.top_k_per_row(10);
+
+        assert_eq!(values.nb_cols(), 3);
+        assert_eq!((values[(0, 0)], values[(0, 1)], values[(0, 2)]), (7, 5, 2));
+        assert_eq!(
+            (indexes[(0, 0)], indexes[(0, 1)], indexes[(0, 2)]),
+            (1, 2, 0)
+        );
+
+        let (values, indexes): (Matrix<i32>, Matrix<usize>) = view.top_k_per_row(0);
+
+        assert_eq!(values.nb_cols(), 0);
+        assert_eq!(indexes.nb_cols(), 0);
+    }
+
+    #[test]
+    fn test_view_determinant_sign() {
+        let positive: Vec<f64> = vec![2.0, 1.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0, 4.0];
+        let view: View<f64> = View::new(3, 3, Accessor::new(3, 1), positive.as_slice());
+        assert_eq!(view.determinant_sign(), Ok(1));
+
+        let negative: Vec<f64> = vec![0.0, 1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 5.0];
+        let view: View<f64> = View::new(3, 3, Accessor::new(3, 1), negative.as_slice());
+        assert_eq!(view.determinant_sign(), Ok(-1));
+
+        // Pivoting permutes rows along a cycle of length 3, which is an even permutation
+        let cycle: Vec<f64> = vec![0.0, 0.0, 1.0, 2.0, 0.0, 0.0, 0.0, -3.0, 0.0];
+        let view: View<f64> = View::new(3, 3, Accessor::new(3, 1), cycle.as_slice());
+        assert_eq!(view.determinant_sign(), Ok(-1));
+
+        let zero: Vec<f64> = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
+        let view: View<f64> = View::new(3, 3, Accessor::new(3, 1), zero.as_slice());
+        assert_eq!(view.determinant_sign(), Ok(0));
+
+        // Hilbert and Pascal matrices are positive definite, Hilbert one being ill-conditioned
+        assert_eq!(testing::hilbert(6).full_view().determinant_sign(), Ok(1));
+        assert_eq!(testing::pascal(8).full_view().determinant_sign(), Ok(1));
+        assert_eq!(
+            testing::random_spd(10, 7).full_view().determinant_sign(),
+            Ok(1)
+        );
+
+        let view: View<f64> = View::new(2, 3, Accessor::new(3, 1), zero.as_slice());
+        assert_eq!(
+            view.determinant_sign(),
+            Err(ShapeError::NotSquare {
+                nb_rows: 2,
+                nb_cols: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_view_condition_estimate() {
+        // Eigenvalues of [[2, 1], [1, 2]] are 3 and 1
+        let data: Vec<f64> = vec![2.0, 1.0, 1.0, 2.0];
+        let view: View<f64> = View::new(2, 2, Accessor::new(2, 1), data.as_slice());
+
+        let condition: f64 = view.condition_estimate(1e-14, 1000).unwrap();
+        assert!((condition - 3.0).abs() < 1e-10);
+
+        // Eigenvalues of [[1, 2], [2, 1]] are 3 and -1, and pivoting is needed
+        let data: Vec<f64> = vec![1.0, 2.0, 2.0, 1.0];
+        let view: View<f64> = View::new(2, 2, Accessor::new(2, 1), data.as_slice());
+
+        let condition: f64 = view.condition_estimate(1e-14, 1000).unwrap();
+        assert!((condition - 3.0).abs() < 1e-10);
+
+        // Eigenvalues of [[0, 2], [2, 0]] are 2 and -2, whose Rayleigh quotients cancel out
+        let data: Vec<f64> = vec![0.0, 2.0, 2.0, 0.0];
+        let view: View<f64> = View::new(2, 2, Accessor::new(2, 1), data.as_slice());
+
+        let condition: f64 = view.condition_estimate(1e-14, 1000).unwrap();
+        assert!((condition - 1.0).abs() < 1e-10);
+
+        // Eigenvalues of [[8.2, -3.6], [-3.6, 2.8]] are 10 and 1, with eigenvectors (2, -1) and (1, 2)
+        let data: Vec<f64> = vec![8.2, -3.6, -3.6, 2.8];
+        let view: View<f64> = View::new(2, 2, Accessor::new(2, 1), data.as_slice());
+
+        let condition: f64 = view.condition_estimate(1e-14, 1000).unwrap();
+        assert!((condition - 10.0).abs() < 1e-9);
+
+        let singular: Vec<f64> = vec![1.0, 2.0, 2.0, 4.0];
+        let view: View<f64> = View::new(2, 2, Accessor::new(2, 1), singular.as_slice());
+        assert_eq!(view.condition_estimate(1e-14, 1000), Ok(f64::INFINITY));
+
+        let view: View<f64> = View::new(1, 2, Accessor::new(2, 1), singular.as_slice());
+        assert_eq!(
+            view.condition_estimate(1e-14, 1000),
+            Err(SolveError::NotSquare {
+                nb_rows: 1,
+                nb_cols: 2
+            })
+        );
+
+        // Condition number of symmetric positive definite matrix is bounded below
+        // by ratio of its largest to smallest diagonal elements
+        let spd: Matrix<f64> = testing::random_spd(5, 3);
+        let condition: f64 = spd.full_view().condition_estimate(1e-12, 10000).unwrap();
+        assert!(condition >= spd.full_view().diagonal_ratio().unwrap());
+    }
+
+    #[test]
+    fn test_view_spectral_norm() {
+        // Singular values of [[3, 0], [4, 5]] are 3 * sqrt(5) and sqrt(5)
+        let data: Vec<f64> = vec![3.0, 0.0, 4.0, 5.0];
+        let view: View<f64> = View::new(2, 2, Accessor::new(2, 1), data.as_slice());
+
+        let norm: f64 = view.spectral_norm(1e-14, 1000).unwrap();
+        assert!((norm - 3.0 * 5.0_f64.sqrt()).abs() < 1e-10);
+
+        assert_eq!(
+            view.spectral_norm(1e-14, 1),
+            Err(SolveError::NotConverged { nb_iterations: 1 })
+        );
+
+        let zeros: Vec<f64> = vec![0.0; 4];
+        let view: View<f64> = View::new(2, 2, Accessor::new(2, 1), zeros.as_slice());
+        assert_eq!(view.spectral_norm(1e-14, 10), Ok(0.0));
+
+        // Uniform vector is in null space of [[1, -1], [1, -1]] whose singular values are 2 and 0
+        let data: Vec<f64> = vec![1.0, -1.0, 1.0, -1.0];
+        let view: View<f64> = View::new(2, 2, Accessor::new(2, 1), data.as_slice());
+
+        let norm: f64 = view.spectral_norm(1e-14, 1000).unwrap();
+        assert!((norm - 2.0).abs() < 1e-10);
+
+        // Start vector is in null space of this matrix, so a vector of canonical basis is used
+        let start: Vec<f64> = start_vector(2);
+        let data: Vec<f64> = vec![start[1], -start[0]];
+        let view: View<f64> = View::new(1, 2, Accessor::new(2, 1), data.as_slice());
+
+        let norm: f64 = view.spectral_norm(1e-14, 1000).unwrap();
+        assert!((norm - 1.0).abs() < 1e-10);
+
+        // Spectral norm of symmetric matrix is bounded below by largest diagonal element
+        // and above by Frobenius norm
+        let spd: Matrix<f64> = testing::random_spd(6, 11);
+        let norm: f64 = spd.full_view().spectral_norm(1e-12, 1000).unwrap();
+        let frobenius: f64 = spd.full_view().iter().map(|x| x * x).sum::<f64>().sqrt();
+        let max_diagonal: f64 = spd
+            .diagonal()
+            .iter()
+            .fold(0.0, |acc: f64, x: &f64| acc.max(*x));
+
+        assert!(max_diagonal <= norm && norm <= frobenius);
+    }
+
+    #[test]
+    fn test_view_layout() {
+        let data: Vec<f64> = vec![0.0; 24];
+
+        // Full row-major and column-major views, and their transposes
+        let full: View<f64> = View::new(4, 6, Accessor::new(6, 1), data.as_slice());
+        assert_eq!(full.layout(), Layout::Contiguous);
+        assert_eq!(full.transpose().layout(), Layout::Contiguous);
+
+        let full: View<f64> = View::new(4, 6, Accessor::new(1, 4), data.as_slice());
+        assert_eq!(full.layout(), Layout::Contiguous);
+
+        // Blocks with offset inside a row-major and a column-major 4x6 matrix
+        let block: View<f64> =
+            View::new(2, 3, Accessor::new_with_offset(6, 1, 1, 2), data.as_slice());
+        assert_eq!(block.layout(), Layout::RowStrided);
+        assert_eq!(block.transpose().layout(), Layout::ColStrided);
+
+        let block: View<f64> =
+            View::new(2, 3, Accessor::new_with_offset(1, 4, 1, 2), data.as_slice());
+        assert_eq!(block.layout(), Layout::ColStrided);
+
+        // Full rows of a block are contiguous
+        let rows: View<f64> =
+            View::new(2, 6, Accessor::new_with_offset(6, 1, 1, 0), data.as_slice());
+        assert_eq!(rows.layout(), Layout::Contiguous);
+
+        // Every other row and every other column
+        let strided: View<f64> = View::new(2, 3, Accessor::new(12, 2), data.as_slice());
+        assert_eq!(strided.layout(), Layout::General);
+        assert!(!strided.layout().has_fast_path());
+
+        // Single row and single column
+        let row: View<f64> = View::new(1, 6, Accessor::new(6, 1), data.as_slice());
+        assert_eq!(row.layout(), Layout::Contiguous);
+        let col: View<f64> = View::new(4, 1, Accessor::new(6, 1), data.as_slice());
+        assert_eq!(col.layout(), Layout::RowStrided);
+    }
+
+    #[test]
+    fn test_view_layout_report() {
+        let data: Vec<f64> = vec![0.0; 24];
+        let block: View<f64> =
+            View::new(2, 3, Accessor::new_with_offset(6, 1, 1, 2), data.as_slice());
+
+        assert_eq!(
+            block.layout_report(),
+            LayoutReport {
+                stride_row: 6,
+                stride_col: 1,
+                layout: Layout::RowStrided,
+                fast_path: true,
+                span_bytes: 9 * 8,
+            }
+        );
+
+        let empty: View<f64> = View::new(0, 3, Accessor::new(3, 1), data.as_slice());
+        assert_eq!(empty.layout_report().span_bytes, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "has no fast path")]
+    #[cfg(debug_assertions)]
+    fn test_debug_assert_fast_path() {
+        let mut data: Vec<f64> = vec![0.0; 24];
+
+        let view: View<f64> = View::new(4, 6, Accessor::new(6, 1), data.as_slice());
+        debug_assert_fast_path!(view);
+
+        let view: ViewMut<f64> = ViewMut::new(2, 3, Accessor::new(12, 2), data.as_mut_slice());
+        debug_assert_fast_path!(view);
+    }
+
+    #[test]
+    fn test_view_row_and_col() {
+        let data: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+
+        // Sub-view of rows 1..3 and columns 1..4 of a 3x4 row-major matrix
+        let view: View<i32> =
+            View::new(2, 3, Accessor::new_with_offset(4, 1, 1, 1), data.as_slice());
+
+        let row: View<i32> = view.row(1);
+        assert_eq!((row.nb_rows(), row.nb_cols()), (1, 3));
+        assert_eq!(row.iter().copied().collect::<Vec<i32>>(), vec![10, 11, 12]);
+
+        let col: View<i32> = view.col(2);
+        assert_eq!((col.nb_rows(), col.nb_cols()), (2, 1));
+        assert_eq!(col.iter().copied().collect::<Vec<i32>>(), vec![8, 12]);
+
+        // Same elements in a column-major matrix
+        let view: View<i32> =
+            View::new(2, 3, Accessor::new_with_offset(1, 3, 1, 1), data.as_slice());
+
+        assert_eq!(
+            view.row(0).iter().copied().collect::<Vec<i32>>(),
+            vec![5, 8, 11]
+        );
+        assert_eq!(
+            view.col(0).iter().copied().collect::<Vec<i32>>(),
+            vec![5, 6]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "column index 3 is out of bounds of view with dimensions 2x3")]
+    fn test_view_col_out_of_bounds() {
+        let data: Vec<i32> = vec![0; 6];
+        let view: View<i32> = View::new(2, 3, Accessor::new(3, 1), data.as_slice());
+        let _ = view.col(3);
+    }
+
+    #[test]
+    fn test_view_map() {
+        let data: Vec<i32> = vec![1, -2, 3, -4, 5, -6];
+
+        // Contiguous column-major view
+        let view: View<i32> = View::new(2, 3, Accessor::new(1, 2), data.as_slice());
+        let mapped: Matrix<f64> = view.map(|&x| x.abs() as f64);
+
+        assert_eq!(mapped.storage_order(), StorageOrder::ColumnMajor);
+        assert_eq!(
+            mapped,
+            Matrix::from_rows(&[&[1.0, 3.0, 5.0], &[2.0, 4.0, 6.0]]).unwrap()
+        );
+
+        // Strided row-major view visits elements in memory order
+        let view: View<i32> = View::new(2, 2, Accessor::new(3, 1), data.as_slice());
+        let mut visited: Vec<i32> = Vec::new();
+        let mapped: Matrix<i32> = view.map(|&x| {
+            visited.push(x);
+            return 2 * x;
+        });
+
+        assert_eq!(visited, vec![1, -2, -4, 5]);
+        assert_eq!(mapped.storage_order(), StorageOrder::RowMajor);
+        assert_eq!(mapped, Matrix::from_rows(&[&[2, -4], &[-8, 10]]).unwrap());
+
+        // Strided column-major view
+        let view: View<i32> = View::new(2, 2, Accessor::new(1, 3), data.as_slice());
+        assert_eq!(view.map(|&x| x).storage_order(), StorageOrder::ColumnMajor);
+    }
+
+    #[test]
+    fn test_view_transpose() {
+        let data: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        let view: View<i32> =
+            View::new(2, 3, Accessor::new_with_offset(4, 1, 1, 1), data.as_slice());
+
+        let transposed: View<i32> = view.transpose();
+        assert_eq!(transposed.nb_rows(), 3);
+        assert_eq!(transposed.nb_cols(), 2);
+
+        for row_id in 0..view.nb_rows() {
+            for col_id in 0..view.nb_cols() {
+                assert_eq!(transposed[(col_id, row_id)], view[(row_id, col_id)]);
+            }
+        }
+
+        assert_eq!(transposed.transpose()[(1, 2)], 12);
+    }
+
+    #[test]
+    fn test_view_diagonal_ratio() {
+        let data: Vec<i32> = vec![2, 7, 7, 7, -8, 7, 7, 7, 4];
+        let view: View<i32> = View::new(3, 3, Accessor::new(3, 1), data.as_slice());
+        assert_eq!(view.diagonal_ratio(), Ok(4.0));
+
+        let data: Vec<f64> = vec![1.0, 2.0, 3.0, 4.0, 0.0, 6.0, 7.0, 8.0, 9.0];
+        let view: View<f64> = View::new(3, 3, Accessor::new(3, 1), data.as_slice());
+        assert_eq!(
+            view.diagonal_ratio(),
+            Err(ShapeError::ZeroDiagonal { index: 1 })
+        );
+
+        let view: View<f64> = View::new(2, 3, Accessor::new(3, 1), data.as_slice());
+        assert_eq!(
+            view.diagonal_ratio(),
+            Err(ShapeError::NotSquare {
+                nb_rows: 2,
+                nb_cols: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_view_reciprocal() {
+        // Column-major view of [[1, 2, 4], [-5, 8, 10]]
+        let data: Vec<i32> = vec![1, -5, 2, 8, 4, 10];
+        let view: View<i32> = View::new(2, 3, Accessor::new(1, 2), data.as_slice());
+
+        let reciprocal: Matrix<f64> = view.reciprocal().unwrap();
+        assert_eq!(reciprocal.nb_rows(), 2);
+        assert_eq!(reciprocal.nb_cols(), 3);
+        assert_eq!(reciprocal[(0, 0)], 1.0);
+        assert_eq!(reciprocal[(0, 1)], 0.5);
+        assert_eq!(reciprocal[(0, 2)], 0.25);
+        assert_eq!(reciprocal[(1, 0)], -0.2);
+        assert_eq!(reciprocal[(1, 1)], 0.125);
+        assert_eq!(reciprocal[(1, 2)], 0.1);
+    }
+
+    #[test]
+    fn test_view_reciprocal_of_zero() {
+        let data: Vec<f64> = vec![1.0, 2.0, 3.0, 0.0, 5.0, 0.0];
+        let view: View<f64> = View::new(2, 3, Accessor::new(3, 1), data.as_slice());
+
+        assert_eq!(
+            view.reciprocal().err(),
+            Some(ArithError::DivisionByZero {
+                row_id: 1,
+                col_id: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_mutable_view_row_vector() {
+        let vector_data: Vec<i32> = vec![10, 20, 30];
+        let vector: View<i32> = View::new(1, 3, Accessor::new(3, 1), vector_data.as_slice());
+
+        for accessor in [Accessor::new(3, 1), Accessor::new(1, 2)] {
+            let mut data: Vec<i32> = vec![1, 2, 3, 4, 5, 6];
+            let mut view: ViewMut<i32> = ViewMut::new(2, 3, accessor, data.as_mut_slice());
+
+            view.add_row_vector(&vector).unwrap();
+            assert_eq!(view[(0, 0)], 11);
+            assert_eq!(view[(1, 2)], 36);
+
+            view.sub_row_vector(&vector).unwrap();
+            view.sub_row_vector(&vector).unwrap();
+            assert_eq!(view[(0, 1)], accessor.index(0, 1) as i32 + 1 - 20);
+            assert_eq!(view[(1, 0)], accessor.index(1, 0) as i32 + 1 - 10);
+        }
+
+        let mut data: Vec<i32> = vec![1, 2, 3, 4, 5, 6];
+        let mut view: ViewMut<i32> = ViewMut::new(2, 2, Accessor::new(3, 1), data.as_mut_slice());
+        assert_eq!(
+            view.add_row_vector(&vector),
+            Err(ShapeError::DimensionMismatch {
+                expected: (1, 2),
+                actual: (1, 3)
+            })
+        );
+    }
+
+    #[test]
+    fn test_mutable_view_col_vector() {
+        // Column-major sub-view on the last two rows and columns of a 3x3 matrix
+        let mut data: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let vector_data: Vec<i32> = vec![2, 10];
+        let vector: View<i32> = View::new(2, 1, Accessor::new(1, 2), vector_data.as_slice());
+
+        {
+            let mut view: ViewMut<i32> = ViewMut::new(
+                2,
+                2,
+                Accessor::new_with_offset(1, 3, 1, 1),
+                data.as_mut_slice(),
+            );
+
+            view.mul_col_vector(&vector).unwrap();
+            view.add_col_vector(&vector).unwrap();
+
+            assert_eq!(
+                view.add_col_vector(&vector.transpose()),
+                Err(ShapeError::DimensionMismatch {
+                    expected: (2, 1),
+                    actual: (1, 2)
+                })
+            );
+        }
+
+        assert_eq!(data, vec![1, 2, 3, 4, 12, 70, 7, 18, 100]);
+    }
+
+    #[test]
+    fn test_mutable_view_fused_updates() {
+        let a: Matrix<f64> = Matrix::from_rows(&[&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]]).unwrap();
+        let b: Matrix<f64> =
+            Matrix::from_vec_column_major(2, 3, vec![2.0, 8.0, 4.0, 10.0, 6.0, 12.0]).unwrap();
+        let c: Matrix<f64> = Matrix::from_fn_column_major(2, 3, |i, j| (i + 2 * j) as f64);
+        let alpha: f64 = 0.5;
+
+        // Compositional formulations with temporary matrices
+        let hadamard: Matrix<f64> = Matrix::from_fn_row_major(2, 3, |i, j| a[(i, j)] * b[(i, j)]);
+        let quotient: Matrix<f64> = Matrix::from_fn_row_major(2, 3, |i, j| a[(i, j)] / b[(i, j)]);
+        let scaled: Matrix<f64> = a.map(|x| alpha * x);
+
+        let mut result: Matrix<f64> = c.clone();
+        result
+            .full_view_mut()
+            .add_hadamard(&a.full_view(), &b.full_view())
+            .unwrap();
+        assert_eq!(
+            result,
+            Matrix::from_fn_row_major(2, 3, |i, j| c[(i, j)] + hadamard[(i, j)])
+        );
+
+        let mut result: Matrix<f64> = c.clone();
+        result
+            .full_view_mut()
+            .add_scaled(alpha, &a.full_view())
+            .unwrap();
+        assert_eq!(
+            result,
+            Matrix::from_fn_row_major(2, 3, |i, j| c[(i, j)] + scaled[(i, j)])
+        );
+
+        let mut result: Matrix<f64> = c.clone();
+        result
+            .full_view_mut()
+            .addcdiv(&a.full_view(), &b.full_view(), alpha)
+            .unwrap();
+        assert_eq!(
+            result,
+            Matrix::from_fn_row_major(2, 3, |i, j| c[(i, j)] + alpha * quotient[(i, j)])
+        );
+    }
+
+    #[test]
+    fn test_mutable_view_fused_updates_dimension_mismatch() {
+        let a: Matrix<f64> = Matrix::ones(2, 3);
+        let mut c: Matrix<f64> = Matrix::zeros(2, 3);
+        let mut view: ViewMut<f64> = c.full_view_mut();
+
+        let error: ShapeError = ShapeError::DimensionMismatch {
+            expected: (2, 3),
+            actual: (3, 2),
+        };
+
+        assert_eq!(
+            view.add_hadamard(&a.full_view(), &a.transpose_view()),
+            Err(error.clone())
+        );
+        assert_eq!(
+            view.add_scaled(2.0, &a.transpose_view()),
+            Err(error.clone())
+        );
+        assert_eq!(
+            view.addcdiv(&a.transpose_view(), &a.full_view(), 2.0),
+            Err(error)
+        );
+        assert_eq!(c, Matrix::zeros(2, 3));
+    }
+
+    #[test]
     fn test_mutable_view_data_access() {
         let nb_rows: usize = 3;
         let nb_cols: usize = 3;
@@ -299,6 +2991,40 @@ mod tests {
         assert_eq!(data[5], new_value);
     }
 
+    #[test]
+    fn test_mutable_view_checked_data_access() {
+        let mut data: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+        let mut view: ViewMut<i32> = ViewMut::new(
+            2,
+            2,
+            Accessor::new_with_offset(3, 1, 1, 1),
+            data.as_mut_slice(),
+        );
+
+        assert_eq!(view.get(0, 0), Some(&5));
+        assert_eq!(view.get(0, 2), None);
+        assert_eq!(view.get_mut(2, 1), None);
+
+        let new_value: i32 = 17;
+
+        match view.get_mut(1, 1) {
+            Some(element) => *element = new_value,
+            None => unreachable!(),
+        }
+
+        assert_eq!(data[8], new_value);
+    }
+
+    #[test]
+    #[should_panic(expected = "index (2, 0) is out of bounds of view with dimensions 2x2")]
+    fn test_mutable_view_data_access_out_of_bounds() {
+        let mut data: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let mut view: ViewMut<i32> = ViewMut::new(2, 2, Accessor::new(3, 1), data.as_mut_slice());
+
+        view[(2, 0)] = 0;
+    }
+
     #[test]
     fn test_mutable_view_data_access_with_offset() {
         let nb_rows: usize = 3;
@@ -323,4 +3049,146 @@ mod tests {
         assert_eq!(view[(1, 0)], new_value);
         assert_eq!(data[7], new_value);
     }
+
+    #[test]
+    fn test_mutable_view_apply_if() {
+        let mut data: Vec<i32> = vec![1, -2, 3, -4, 5, -6, 7, -8, 9];
+
+        {
+            let mut view: ViewMut<i32> =
+                ViewMut::new(3, 3, Accessor::new(3, 1), data.as_mut_slice());
+
+            view.apply_if(|&x| x < 0, |x| *x *= 2);
+        }
+
+        assert_eq!(data, vec![1, -4, 3, -8, 5, -12, 7, -16, 9]);
+
+        // Only elements of sub-view are considered
+        {
+            let mut view: ViewMut<i32> = ViewMut::new(
+                2,
+                2,
+                Accessor::new_with_offset(3, 1, 1, 1),
+                data.as_mut_slice(),
+            );
+
+            view.apply_if(|&x| x < 0, |x| *x = 0);
+        }
+
+        assert_eq!(data, vec![1, -4, 3, -8, 5, 0, 7, 0, 9]);
+    }
+
+    #[test]
+    fn test_mutable_view_clip_to_norm() {
+        let mut data: Vec<f64> = vec![6.0, 8.0, 0.0, 0.0];
+
+        {
+            let mut view: ViewMut<f64> =
+                ViewMut::new(2, 2, Accessor::new(2, 1), data.as_mut_slice());
+            view.clip_to_norm(5.0);
+        }
+
+        assert_eq!(data, vec![3.0, 4.0, 0.0, 0.0]);
+
+        let mut data: Vec<f32> = vec![0.3, -0.4];
+
+        {
+            let mut view: ViewMut<f32> =
+                ViewMut::new(1, 2, Accessor::new(2, 1), data.as_mut_slice());
+            view.clip_to_norm(1.0);
+        }
+
+        assert_eq!(data, vec![0.3, -0.4]);
+    }
+
+    #[test]
+    fn test_mutable_view_sort_each_row() {
+        let mut data: Vec<f64> = vec![
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+            3.0,
+            1.0,
+            2.0,
+            0.0,
+            f64::NAN,
+            -1.0,
+            5.0,
+            0.0,
+            2.0,
+            2.0,
+            1.0,
+        ];
+
+        {
+            let mut view: ViewMut<f64> = ViewMut::new(
+                3,
+                3,
+                Accessor::new_with_offset(4, 1, 1, 1),
+                data.as_mut_slice(),
+            );
+
+            view.sort_each_row();
+        }
+
+        assert_eq!(data[..5], [0.0, 0.0, 0.0, 0.0, 0.0]);
+        assert_eq!(data[5..8], [1.0, 2.0, 3.0]);
+        assert_eq!(data[8], 0.0);
+        assert_eq!(data[9..11], [-1.0, 5.0]);
+        assert!(data[11].is_nan());
+        assert_eq!(data[12..], [0.0, 1.0, 2.0, 2.0]);
+    }
+
+    #[test]
+    fn test_view_select_and_count_where() {
+        let data: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let mask_data: Vec<bool> = vec![true, false, false, true];
+
+        let view: View<i32> =
+            View::new(2, 2, Accessor::new_with_offset(3, 1, 1, 1), data.as_slice());
+        let mask: View<bool> = View::new(2, 2, Accessor::new(2, 1), mask_data.as_slice());
+
+        assert_eq!(view.count_where(&mask), Ok(2));
+        assert_eq!(view.select_where(&mask), Ok(vec![5, 9]));
+
+        let wrong_mask: View<bool> = View::new(1, 4, Accessor::new(4, 1), mask_data.as_slice());
+        let error: ShapeError = ShapeError::DimensionMismatch {
+            expected: (2, 2),
+            actual: (1, 4),
+        };
+
+        assert_eq!(view.count_where(&wrong_mask), Err(error.clone()));
+        assert_eq!(view.select_where(&wrong_mask), Err(error));
+    }
+
+    #[test]
+    fn test_mutable_view_fill_where() {
+        let mut data: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let mask_data: Vec<bool> = vec![false, true, true, false];
+        let mask: View<bool> = View::new(2, 2, Accessor::new(2, 1), mask_data.as_slice());
+
+        {
+            let mut view: ViewMut<i32> = ViewMut::new(
+                2,
+                2,
+                Accessor::new_with_offset(1, 3, 1, 1),
+                data.as_mut_slice(),
+            );
+
+            assert_eq!(view.fill_where(&mask, 0), Ok(()));
+
+            let wrong_mask: View<bool> = View::new(2, 1, Accessor::new(1, 2), mask_data.as_slice());
+            assert_eq!(
+                view.fill_where(&wrong_mask, 0),
+                Err(ShapeError::DimensionMismatch {
+                    expected: (2, 2),
+                    actual: (2, 1)
+                })
+            );
+        }
+
+        assert_eq!(data, vec![1, 2, 3, 4, 5, 0, 7, 0, 9]);
+    }
 }